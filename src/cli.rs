@@ -1,8 +1,13 @@
-use clap::{Parser, Subcommand};
+use crate::model::SortKey;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(name = "postit", version, about = "Terminal sticky-note kanban board")]
 pub struct Cli {
+    /// Preview changes without writing the board
+    #[arg(long, global = true)]
+    pub dry_run: bool,
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -14,12 +19,71 @@ pub enum Command {
         /// Optional board name
         #[arg(long)]
         name: Option<String>,
+        /// Replace an existing board with a fresh one (the old one is backed up)
+        #[arg(long)]
+        force: bool,
     },
     /// List notes in the current board
     List {
         /// Filter by column id
         #[arg(long)]
         column: Option<String>,
+        /// Only notes due at or after this time (YYYY.MM.DD@hh:mm, YYYY.MM.DD, or ISO 8601)
+        #[arg(long)]
+        due_after: Option<String>,
+        /// Only notes due at or before this time (YYYY.MM.DD@hh:mm, YYYY.MM.DD, or ISO 8601)
+        #[arg(long)]
+        due_before: Option<String>,
+        /// Only notes changed since a time (YYYY.MM.DD@hh:mm, today, yesterday, or an age like 3d)
+        #[arg(long)]
+        since: Option<String>,
+        /// Sort notes within each column (title, due, created, updated)
+        #[arg(long)]
+        sort: Option<SortKey>,
+        /// Only notes past their due date, outside the done column
+        #[arg(long)]
+        overdue: bool,
+        /// Group notes by column (default) or under each of their tags
+        #[arg(long, value_enum)]
+        group_by: Option<ListGroup>,
+    },
+    /// Print which board would be used from here and which boards exist
+    Which,
+    /// Print version, board, and config details for bug reports
+    Info,
+    /// Print note counts, completion, and overdue notes for the board
+    Stats {
+        /// Print the metrics as one JSON object
+        #[arg(long)]
+        json: bool,
+        /// Also append the metrics with a timestamp to stats.jsonl next to the board
+        #[arg(long)]
+        snapshot: bool,
+    },
+    /// List notes finished recently (changed in the done column), for standups
+    Finished {
+        /// How far back to look (an age like 1d or 2w, today, yesterday, or YYYY.MM.DD@hh:mm)
+        #[arg(long, default_value = "1d")]
+        since: String,
+        /// Print the notes as one JSON object
+        #[arg(long)]
+        json: bool,
+    },
+    /// Find notes whose title, body, or tags match a query
+    Search {
+        /// Text to look for, ignoring case
+        query: String,
+        /// Treat the query as a regular expression
+        #[arg(long)]
+        regex: bool,
+    },
+    /// Show a single note
+    Show {
+        /// Note id to show
+        note_id: String,
+        /// Include the note's change history
+        #[arg(long)]
+        history: bool,
     },
     /// Add a new note
     Add {
@@ -31,12 +95,27 @@ pub enum Command {
         /// Tags for the note (repeatable)
         #[arg(long = "tag", short = 't')]
         tags: Vec<String>,
-        /// Column id to place the note (defaults to first column)
+        /// Column id to place the note (defaults to the inbox, else the first column)
         #[arg(long)]
         column: Option<String>,
-        /// Due date in YYYY.MM.DD@hh:mm format
+        /// Due date: YYYY.MM.DD@hh:mm, YYYY.MM.DD, or ISO 8601 (2025-01-31T17:00:00Z)
         #[arg(long)]
         due: Option<String>,
+        /// Attach a file path or URL (repeatable)
+        #[arg(long = "attach")]
+        attachments: Vec<String>,
+        /// Put the note at the top of the column instead of the bottom
+        #[arg(long)]
+        top: bool,
+        /// Also tag the note with the current git branch
+        #[arg(long)]
+        branch_tag: bool,
+    },
+    /// Quickly capture a note with just a title (added to the inbox or first column)
+    Q {
+        /// Title words, joined with spaces
+        #[arg(required = true)]
+        title: Vec<String>,
     },
     /// Move a note to a different column
     Move {
@@ -44,6 +123,16 @@ pub enum Command {
         note_id: String,
         /// Destination column id
         column_id: String,
+        /// Create the destination column if it doesn't exist
+        #[arg(long)]
+        create_column: bool,
+    },
+    /// Move every note carrying a tag to a column
+    MoveTag {
+        /// Tag to match
+        tag: String,
+        /// Destination column id
+        column_id: String,
     },
     /// Edit an existing note
     Edit {
@@ -55,6 +144,9 @@ pub enum Command {
         /// New body
         #[arg(long)]
         body: Option<String>,
+        /// Append a line to the existing body
+        #[arg(long, conflicts_with = "body")]
+        append_body: Option<String>,
         /// Replace tags (repeatable)
         #[arg(long = "tag", short = 't')]
         tags: Vec<String>,
@@ -64,13 +156,156 @@ pub enum Command {
         /// Move to column id
         #[arg(long)]
         column: Option<String>,
-        /// Set due date (YYYY.MM.DD@hh:mm)
+        /// Set due date (YYYY.MM.DD@hh:mm, YYYY.MM.DD, or ISO 8601)
         #[arg(long)]
         due: Option<String>,
         /// Clear due date
         #[arg(long)]
         clear_due: bool,
+        /// Shift the existing due date by an offset such as +3d, -1w, or 12h
+        #[arg(long, allow_hyphen_values = true, conflicts_with_all = ["due", "clear_due"])]
+        due_shift: Option<String>,
+        /// With --due-shift, count from now when the note has no due date
+        #[arg(long, requires = "due_shift")]
+        from_now: bool,
+        /// Add an attachment path or URL (repeatable)
+        #[arg(long = "attach")]
+        attach: Vec<String>,
+        /// Remove an attachment (repeatable)
+        #[arg(long = "detach")]
+        detach: Vec<String>,
+        /// Record what the note is waiting on
+        #[arg(long)]
+        waiting_on: Option<String>,
+        /// Clear what the note is waiting on
+        #[arg(long, conflicts_with = "waiting_on")]
+        clear_waiting_on: bool,
+    },
+    /// Copy a note to the project or global board, leaving the original in place
+    Copy {
+        /// Note id to copy
+        note_id: String,
+        /// Board to copy to
+        #[arg(value_enum)]
+        to: BoardTarget,
+        /// Destination column id (defaults to that board's inbox, else its first column)
+        #[arg(long)]
+        column: Option<String>,
+    },
+    /// Delete a note
+    Delete {
+        /// Note id to delete
+        note_id: String,
+    },
+    /// Merge one note into another, deleting the absorbed note
+    Merge {
+        /// Note id that survives
+        keep: String,
+        /// Note id folded into `keep` and deleted
+        absorb: String,
+    },
+    /// Replace the current board with a board YAML file, after showing the changes
+    Import {
+        /// Board file to import
+        file: PathBuf,
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Trim titles and bodies and clean up tags (case, blanks, repeats) on every note
+    Normalize {
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// List board backups, or restore one over the current board
+    Restore {
+        /// Backup timestamp to restore (as shown in the listing)
+        timestamp: Option<String>,
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Show or change settings stored in the board file
+    Settings {
+        /// Setting to show or change (done_column, default_column, timezone)
+        key: Option<String>,
+        /// New value for the setting
+        value: Option<String>,
+        /// Remove the setting so the default applies again
+        #[arg(long, conflicts_with = "value", requires = "key")]
+        unset: bool,
+    },
+    /// Manage board columns
+    Column {
+        #[command(subcommand)]
+        action: ColumnCommand,
+    },
+    /// Bump a note's updated timestamp without changing its content
+    Touch {
+        /// Note id to touch
+        note_id: String,
     },
     /// Launch the interactive TUI
     Tui,
+    /// Launch the TUI focused on a note or column
+    Open {
+        /// Note id to select
+        note_id: Option<String>,
+        /// Column id to select
+        #[arg(long)]
+        column: Option<String>,
+        /// View to start in (defaults to the config's default_view, else board)
+        #[arg(long, value_enum)]
+        view: Option<StartView>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ColumnCommand {
+    /// Set a column's accent color (omit the color to restore the default)
+    SetColor {
+        /// Column id
+        column_id: String,
+        /// Color name (e.g. red, lightblue) or hex (#rrggbb)
+        color: Option<String>,
+    },
+    /// Show an emoji or glyph before a column's name (omit the icon to remove it)
+    SetIcon {
+        /// Column id
+        column_id: String,
+        /// A short emoji or symbol, e.g. 🚧
+        icon: Option<String>,
+    },
+    /// Describe what a column is for (omit the text to clear it)
+    Describe {
+        /// Column id
+        column_id: String,
+        /// Description text
+        description: Option<String>,
+    },
+    /// Mark a column as the inbox for new notes (omit the id to clear it)
+    SetInbox {
+        /// Column id
+        column_id: Option<String>,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BoardTarget {
+    Project,
+    Global,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ListGroup {
+    Column,
+    Tag,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StartView {
+    Board,
+    Timeline,
+    Project,
 }
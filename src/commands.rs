@@ -1,94 +1,1060 @@
-use crate::model::{BoardError, Note};
-use crate::storage::{init_project_board, load_board, locate_board, save_board, BoardLocation};
+use crate::config::{config_path, load_config, TagCase};
+use crate::date::{
+    due_precision, format_due, format_due_as, mistyped_due_reason, parse_due, parse_due_noting,
+    parse_shift, parse_since, parse_timezone, resolve_timezone, Zone,
+};
+use crate::diff::{diff_boards, BoardDiff};
+use crate::events::{append_event, LogEvent, LogOp};
+use crate::git;
+use crate::ids::generate_id;
+use crate::model::{
+    attachment_missing, bucket_by_tag, normalized_note, Board, BoardError, BoardSettings,
+    DuePrecision, Note, SortKey,
+};
+use crate::stats::{append_snapshot, board_stats, finished_since, stats_log_path};
+use crate::storage::{
+    backup_current_board, backup_dir, board_location, default_board, find_project_board,
+    global_board_path, init_project_board, list_backups, load_board, load_board_readonly,
+    load_focus, locate_board, read_board_file, save_board, BoardLocation, BoardScope, InitOutcome,
+};
 use crate::ui;
 use anyhow::{anyhow, bail, Context, Result};
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
-use rand::{distributions::Alphanumeric, Rng};
+use chrono::{DateTime, Utc};
+use crossterm::terminal;
+use regex::Regex;
 use std::env;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
 
-pub fn init(name: Option<String>) -> Result<()> {
-    let location = init_project_board(name)?;
-    println!("Initialized board at {}", location.path.display());
+pub fn init(name: Option<String>, force: bool, dry_run: bool) -> Result<()> {
+    let (location, outcome) = init_project_board(name, force, dry_run)?;
+    match outcome {
+        InitOutcome::Created if dry_run => {
+            println!("Would initialize board at {}", location.path.display())
+        }
+        InitOutcome::Reset if dry_run => println!(
+            "Would reinitialize {} (existing board would be backed up)",
+            location.path.display()
+        ),
+        InitOutcome::Created => println!("Initialized board at {}", location.path.display()),
+        InitOutcome::Exists => println!(
+            "Board already exists at {}; nothing changed (use --force to start over)",
+            location.path.display()
+        ),
+        InitOutcome::Reset => println!(
+            "Reinitialized board at {}; the previous board was backed up to {}",
+            location.path.display(),
+            backup_dir(&location).display()
+        ),
+    }
     Ok(())
 }
 
-pub fn list(column: Option<String>) -> Result<()> {
-    let (board, location) = load_current_board()?;
+pub fn list(
+    column: Option<String>,
+    due_after: Option<String>,
+    due_before: Option<String>,
+    since: Option<String>,
+    sort: Option<SortKey>,
+    overdue: bool,
+    by_tag: bool,
+) -> Result<()> {
+    let (board, location) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let due_time = load_config().unwrap_or_default().due_time();
+    let after = parse_due(due_after.as_deref().unwrap_or_default(), due_time, zone)?;
+    let before = parse_due(due_before.as_deref().unwrap_or_default(), due_time, zone)?;
+    let since = since
+        .map(|s| {
+            parse_since(&s, Utc::now(), zone).with_context(|| format!("invalid --since {:?}", s))
+        })
+        .transpose()?;
+    let filtered = after.is_some() || before.is_some() || since.is_some() || overdue;
+    let now = Utc::now();
+    let done = board.done_column_id();
     println!(
-        "Board: {} ({})",
+        "Board: {} ({}) - {:.0}% done",
         board.name,
-        match location.scope {
-            crate::storage::BoardScope::Project => "project",
-            crate::storage::BoardScope::Global => "global",
-        }
+        location.scope.label(),
+        board.completion_ratio() * 100.0
     );
-    for col in board.columns {
+    if by_tag {
+        let mut notes = Vec::new();
+        for (col_idx, col) in board.columns.iter().enumerate() {
+            if column.as_ref().is_some_and(|filter| filter != &col.id)
+                || (overdue && col.id == done)
+            {
+                continue;
+            }
+            let ids = match sort {
+                Some(sort) => board.note_ids_sorted_by(col_idx, Some(sort)),
+                None => board.ordered_note_ids(col_idx),
+            };
+            notes.extend(
+                ids.into_iter()
+                    .filter_map(|id| board.notes.get_key_value(id))
+                    .filter(|(_, note)| {
+                        due_in_range(note.due, after, before)
+                            && since.is_none_or(|s| note.updated_at >= s)
+                            && (!overdue || note.is_overdue(now))
+                    }),
+            );
+        }
+        let buckets = bucket_by_tag(notes);
+        if buckets.is_empty() {
+            println!("  (empty)");
+        }
+        for (tag, notes) in buckets {
+            println!("{} ({})", tag, notes.len());
+            for (_, note) in notes {
+                print_note(note, zone);
+            }
+            println!();
+        }
+        return Ok(());
+    }
+    for (col_idx, (col, (_, ratio))) in board.columns.iter().zip(board.column_ratios()).enumerate()
+    {
         if let Some(ref filter) = column {
             if &col.id != filter {
                 continue;
             }
         }
-        println!("{}", col.id);
-        if col.note_ids.is_empty() {
-            println!("  (empty)");
+        if overdue && col.id == done {
+            continue;
+        }
+        println!(
+            "{} ({}, {:.0}% of notes)",
+            col.id,
+            col.note_ids.len(),
+            ratio * 100.0
+        );
+        if let Some(description) = &col.description {
+            println!("  ({})", description);
         }
-        for id in col.note_ids {
-            if let Some(note) = board.notes.get(&id) {
-                print_note(note);
-            } else {
-                println!("  - {} (missing)", id);
+        let mut shown = 0;
+        let ids = match sort {
+            Some(sort) => board.note_ids_sorted_by(col_idx, Some(sort)),
+            None => board.ordered_note_ids(col_idx),
+        };
+        for id in ids {
+            match board.notes.get(id) {
+                Some(note)
+                    if due_in_range(note.due, after, before)
+                        && since.is_none_or(|s| note.updated_at >= s)
+                        && (!overdue || note.is_overdue(now)) =>
+                {
+                    print_note(note, zone);
+                    shown += 1;
+                }
+                Some(_) => {}
+                None if !filtered => {
+                    println!("  - {} (missing)", id);
+                    shown += 1;
+                }
+                None => {}
             }
         }
+        if shown == 0 {
+            println!("  (empty)");
+        }
         println!();
     }
     Ok(())
 }
 
+/// Print notes whose title, body, or tags match `query`, grouped by column.
+/// Plain queries match case-insensitively; `regex` treats the query as a
+/// regular expression.
+pub fn search(query: String, regex: bool) -> Result<()> {
+    let (board, _) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let is_match: Box<dyn Fn(&str) -> bool> = if regex {
+        let re = Regex::new(&query).with_context(|| format!("invalid regex {:?}", query))?;
+        Box::new(move |text| re.is_match(text))
+    } else {
+        let needle = query.to_lowercase();
+        Box::new(move |text| text.to_lowercase().contains(&needle))
+    };
+    let mut found = 0;
+    for (col_idx, col) in board.columns.iter().enumerate() {
+        let hits: Vec<&Note> = board
+            .ordered_note_ids(col_idx)
+            .into_iter()
+            .filter_map(|id| board.notes.get(id))
+            .filter(|note| note.matches(&is_match))
+            .collect();
+        if hits.is_empty() {
+            continue;
+        }
+        println!("{}", col.id);
+        for note in &hits {
+            print_note(note, zone);
+        }
+        println!();
+        found += hits.len();
+    }
+    if found == 0 {
+        println!("No notes match {:?}", query);
+    }
+    Ok(())
+}
+
+/// Open-ended range check; with no bounds every note matches, otherwise undated notes never do.
+fn due_in_range(
+    due: Option<DateTime<Utc>>,
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+) -> bool {
+    if after.is_none() && before.is_none() {
+        return true;
+    }
+    match due {
+        Some(due) => after.is_none_or(|a| due >= a) && before.is_none_or(|b| due <= b),
+        None => false,
+    }
+}
+
+pub fn which() -> Result<()> {
+    let cwd = env::current_dir()?;
+    let location = locate_board(&cwd)?;
+    println!(
+        "Using {} board: {}",
+        location.scope.label(),
+        location.path.display()
+    );
+    match find_project_board(&cwd) {
+        Some(path) => println!("Project board: {}", path.display()),
+        None => println!("Project board: none (run `postit init` to create one)"),
+    }
+    let global = global_board_path()?;
+    println!(
+        "Global board: {} ({})",
+        global.display(),
+        if global.exists() { "exists" } else { "missing" }
+    );
+    Ok(())
+}
+
+pub fn info() -> Result<()> {
+    println!("postit {}", env!("CARGO_PKG_VERSION"));
+    let cwd = env::current_dir()?;
+    let location = locate_board(&cwd)?;
+    println!(
+        "Board: {} ({})",
+        location.path.display(),
+        location.scope.label()
+    );
+    match std::fs::metadata(&location.path) {
+        Ok(meta) => {
+            println!("  size: {} bytes", meta.len());
+            board_summary(&location);
+        }
+        Err(_) => println!("  missing (run `postit init` to create one)"),
+    }
+    let config = config_path()?;
+    println!(
+        "Config: {} ({})",
+        config.display(),
+        if config.exists() { "exists" } else { "missing" }
+    );
+    Ok(())
+}
+
+/// Best-effort details of an existing board file; parse problems are printed, not returned.
+fn board_summary(location: &BoardLocation) {
+    let schema = std::fs::read_to_string(&location.path)
+        .ok()
+        .and_then(|data| serde_yaml::from_str::<serde_yaml::Value>(&data).ok())
+        .and_then(|value| value.get("version").and_then(|v| v.as_u64()));
+    match schema {
+        Some(version) => println!("  schema: {}", version),
+        None => println!("  schema: unversioned"),
+    }
+    match load_board_readonly(location) {
+        Ok(board) => {
+            println!("  name: {}", board.name);
+            println!("  columns: {}", board.columns.len());
+            println!("  notes: {}", board.notes.len());
+        }
+        Err(err) => println!("  unreadable: {:#}", err),
+    }
+}
+
+pub fn show(note_id: String, history: bool) -> Result<()> {
+    let (board, _) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let note = board
+        .notes
+        .get(&note_id)
+        .ok_or_else(|| BoardError::NoteNotFound(note_id.clone()))?;
+    let mut lines = vec![note_column_id(&board, &note_id)];
+    lines.extend(note_lines(note, zone));
+    for target in &note.attachments {
+        if attachment_missing(target) {
+            lines.push(format!("    attachment: {} (missing)", target));
+        } else {
+            lines.push(format!("    attachment: {}", target));
+        }
+    }
+    lines.push(format!(
+        "    created: {}",
+        format_due(&note.created_at, zone)
+    ));
+    lines.push(format!(
+        "    updated: {}",
+        format_due(&note.updated_at, zone)
+    ));
+    if history {
+        lines.push("    history:".to_string());
+        if note.history.is_empty() {
+            lines.push("      (none)".to_string());
+        }
+        for event in &note.history {
+            lines.push(format!(
+                "      {} {}",
+                format_due(&event.at, zone),
+                event.kind
+            ));
+        }
+    }
+    page(&lines.join("\n"))
+}
+
+/// Prints `text`, through `$PAGER` (default `less`) when stdout is a terminal
+/// and the text is taller than it, the way `git log` does. Piped output is
+/// never paged.
+fn page(text: &str) -> Result<()> {
+    let rows = terminal::size().map_or(usize::MAX, |(_, rows)| rows as usize);
+    if !io::stdout().is_terminal() || text.lines().count() < rows {
+        println!("{}", text);
+        return Ok(());
+    }
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less".to_string());
+    let mut words = pager.split_whitespace();
+    let Some(program) = words.next() else {
+        println!("{}", text);
+        return Ok(());
+    };
+    let mut command = Command::new(program);
+    command.args(words).stdin(Stdio::piped());
+    if env::var_os("LESS").is_none() {
+        command.env("LESS", "FRX");
+    }
+    match command.spawn() {
+        Ok(mut child) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                // Quitting the pager early closes the pipe; that's fine.
+                let _ = writeln!(stdin, "{}", text);
+            }
+            child.wait().context("waiting for the pager")?;
+        }
+        Err(_) => println!("{}", text),
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn add(
     title: String,
     body: Option<String>,
     tags: Vec<String>,
     column: Option<String>,
     due: Option<String>,
+    attachments: Vec<String>,
+    top: bool,
+    branch_tag: bool,
+    dry_run: bool,
 ) -> Result<()> {
-    let (mut board, location) = load_current_board()?;
+    let mut tags = tags;
+    if branch_tag {
+        match git::current_branch(&env::current_dir()?) {
+            Ok(branch) if !tags.contains(&branch) => tags.push(branch),
+            Ok(_) => {}
+            Err(err) => eprintln!("warning: not tagging the git branch: {:#}", err),
+        }
+    }
+    if let Some((id, column_id)) =
+        create_note(title, body, tags, column, due, attachments, top, dry_run)?
+    {
+        println!("Added note {} to {}", id, column_id);
+    }
+    Ok(())
+}
+
+/// Title-only capture into the default column; prints just the new id.
+pub fn quick(words: Vec<String>, dry_run: bool) -> Result<()> {
+    let title = words.join(" ");
+    if title.trim().is_empty() {
+        bail!("a title is required");
+    }
+    if let Some((id, _)) = create_note(
+        title,
+        None,
+        Vec::new(),
+        None,
+        None,
+        Vec::new(),
+        false,
+        dry_run,
+    )? {
+        println!("{}", id);
+    }
+    Ok(())
+}
+
+/// Parses a `--due` value, warning when a daylight saving change moved it.
+fn parse_due_warning(due: Option<&str>, zone: Zone) -> Result<Option<DateTime<Utc>>> {
+    let Some(due) = due else {
+        return Ok(None);
+    };
+    let due_time = load_config().unwrap_or_default().due_time();
+    let (due, note) = parse_due_noting(due, due_time, zone)?;
+    if let Some(note) = note {
+        eprintln!("warning: {}", note);
+    }
+    Ok(due)
+}
+
+/// Warns, without refusing, when the note's due date looks mistyped.
+fn warn_mistyped_due(note: &Note, zone: Zone) {
+    let Some(due) = note.due else {
+        return;
+    };
+    let max_past_days = load_config().unwrap_or_default().past_due_warning_days;
+    if let Some(reason) = mistyped_due_reason(due, note.created_at, Utc::now(), max_past_days, zone)
+    {
+        eprintln!("warning: {}", reason);
+    }
+}
+
+/// Shared by `add` and `quick`; returns the new id and column unless this was a dry run.
+#[allow(clippy::too_many_arguments)]
+fn create_note(
+    title: String,
+    body: Option<String>,
+    tags: Vec<String>,
+    column: Option<String>,
+    due: Option<String>,
+    attachments: Vec<String>,
+    top: bool,
+    dry_run: bool,
+) -> Result<Option<(String, String)>> {
+    let (mut board, location) = if dry_run {
+        load_board_or_default()?
+    } else {
+        load_current_board()?
+    };
+    let zone = board_zone(&board);
     let column_id = column
-        .or_else(|| board.columns.first().map(|c| c.id.clone()))
+        .or_else(|| board.default_column_id().map(str::to_string))
         .ok_or_else(|| anyhow!("board has no columns"))?;
-    let due_dt = parse_due(due.as_deref())?;
-    let id = generate_id();
-    let note = Note::new(id.clone(), title, body, tags, due_dt);
-    board
-        .add_note(note, &column_id)
-        .with_context(|| format!("adding note to column {}", column_id))?;
+    ensure_column(&board, &column_id)?;
+    let due_dt = parse_due_warning(due.as_deref(), zone)?;
+    let duplicates = board.notes_with_title(&title);
+    if !duplicates.is_empty() {
+        eprintln!(
+            "warning: same title as existing note {}",
+            duplicates.join(", ")
+        );
+    }
+    let id = new_note_id(&board);
+    let mut note = Note::new(id.clone(), title, body, tags, due_dt);
+    note.due_precision = due_precision(due.as_deref().unwrap_or_default());
+    note.attachments = attachments;
+    warn_mistyped_due(&note, zone);
+    let added = if top {
+        if let Some(sort) = board
+            .find_column_index(&column_id)
+            .and_then(|idx| board.columns[idx].sort)
+        {
+            eprintln!(
+                "warning: column {} is sorted by {}; the note shows in sort order",
+                column_id, sort
+            );
+        }
+        board.add_note_on_top(note, &column_id)
+    } else {
+        board.add_note(note, &column_id)
+    };
+    added.with_context(|| format!("adding note to column {}", column_id))?;
+    if dry_run {
+        println!("Would add note {} to {}", id, column_id);
+        if let Some(note) = board.notes.get(&id) {
+            print_note(note, zone);
+        }
+        return Ok(None);
+    }
     save_board(&location, &board)?;
-    println!("Added note {} to {}", id, column_id);
-    Ok(())
+    let title = board
+        .notes
+        .get(&id)
+        .map(|n| n.title.clone())
+        .unwrap_or_default();
+    record_event(
+        &location,
+        LogEvent::new(
+            &id,
+            LogOp::Add {
+                column: column_id.clone(),
+                title,
+            },
+        ),
+    );
+    Ok(Some((id, column_id)))
 }
 
-pub fn move_note(note_id: String, column_id: String) -> Result<()> {
-    let (mut board, location) = load_current_board()?;
+pub fn move_note(
+    note_id: String,
+    column_id: String,
+    create_column: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let from = note_column_id(&board, &note_id);
+    let created = create_column && board.find_column_index(&column_id).is_none();
+    if created {
+        if !board.notes.contains_key(&note_id) {
+            return Err(BoardError::NoteNotFound(note_id).into());
+        }
+        board.add_column(column_id.clone(), None)?;
+    }
+    ensure_column(&board, &column_id)?;
     board
         .move_note(&note_id, &column_id)
         .with_context(|| format!("moving note {} to {}", note_id, column_id))?;
+    if dry_run {
+        if created {
+            println!("Would create column {}", column_id);
+        }
+        println!("Would move note {}: {} -> {}", note_id, from, column_id);
+        return Ok(());
+    }
     save_board(&location, &board)?;
+    if created {
+        println!("Created column {}", column_id);
+    }
+    record_event(
+        &location,
+        LogEvent::new(
+            &note_id,
+            LogOp::Move {
+                from: from.clone(),
+                to: column_id.clone(),
+            },
+        ),
+    );
     println!("Moved note {} to {}", note_id, column_id);
+    hint_stale_waiting(&board, &note_id, &from);
+    Ok(())
+}
+
+/// Reminds the user to clear `waiting_on` after a note leaves the waiting column.
+fn hint_stale_waiting(board: &Board, note_id: &str, from: &str) {
+    if let Some(text) = board.stale_waiting_on(note_id, from) {
+        println!(
+            "  still waiting on {:?}; clear it with `postit edit {} --clear-waiting-on`",
+            text, note_id
+        );
+    }
+}
+
+pub fn move_tag(tag: String, column_id: String, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let report = board
+        .move_notes_with_tag(&tag, &column_id)
+        .with_context(|| format!("moving notes tagged {} to {}", tag, column_id))?;
+    let verb = if dry_run { "Would move" } else { "Moved" };
+    println!(
+        "{} {} note(s) tagged {} to {} ({} already there)",
+        verb,
+        report.moved.len(),
+        tag,
+        column_id,
+        report.unchanged.len()
+    );
+    for (id, err) in &report.failed {
+        eprintln!("  failed {}: {}", id, err);
+    }
+    if !dry_run && !report.moved.is_empty() {
+        save_board(&location, &board)?;
+        for (id, from) in report.moved {
+            record_event(
+                &location,
+                LogEvent::new(
+                    &id,
+                    LogOp::Move {
+                        from,
+                        to: column_id.clone(),
+                    },
+                ),
+            );
+        }
+    }
+    if !report.failed.is_empty() {
+        bail!("{} note(s) could not be moved", report.failed.len());
+    }
+    Ok(())
+}
+
+pub fn delete(note_id: String, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let from = note_column_id(&board, &note_id);
+    let note = board
+        .remove_note(&note_id)
+        .with_context(|| format!("deleting note {}", note_id))?;
+    if dry_run {
+        println!("Would delete note {} from {}", note_id, from);
+        print_note(&note, zone);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    record_event(
+        &location,
+        LogEvent::new(
+            &note_id,
+            LogOp::Delete {
+                column: from,
+                title: note.title,
+            },
+        ),
+    );
+    println!("Deleted note {}", note_id);
+    Ok(())
+}
+
+pub fn merge(keep: String, absorb: String, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let from = note_column_id(&board, &absorb);
+    let absorbed_title = board
+        .notes
+        .get(&absorb)
+        .map(|n| n.title.clone())
+        .unwrap_or_default();
+    let merged = board
+        .merge_notes(&keep, &absorb)
+        .with_context(|| format!("merging {} into {}", absorb, keep))?;
+    if dry_run {
+        println!("Would merge {} into {}", absorb, keep);
+        print_note(&merged, zone);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    record_event(
+        &location,
+        LogEvent::new(
+            &absorb,
+            LogOp::Delete {
+                column: from,
+                title: absorbed_title,
+            },
+        ),
+    );
+    record_event(
+        &location,
+        LogEvent::new(
+            &keep,
+            LogOp::Edit {
+                title: merged.title.clone(),
+            },
+        ),
+    );
+    println!("Merged {} into {}", absorb, keep);
+    print_note(&merged, zone);
+    Ok(())
+}
+
+pub fn stats(json: bool, snapshot: bool, dry_run: bool) -> Result<()> {
+    let (board, location) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let stats = board_stats(&board, Utc::now(), zone);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+    } else {
+        println!("Board: {}", stats.board);
+        println!(
+            "Notes: {} ({} done, {:.0}%)",
+            stats.notes,
+            stats.done,
+            stats.completion * 100.0
+        );
+        println!("Overdue: {}", stats.overdue);
+        println!("Due today: {}", stats.due_today);
+        println!("Untagged: {}", stats.untagged);
+        if stats.unfiled > 0 {
+            println!("Unfiled: {}", stats.unfiled);
+        }
+        for column in &stats.columns {
+            println!("  {}: {}", column.id, column.notes);
+        }
+    }
+    if snapshot {
+        let path = stats_log_path(&location);
+        if dry_run {
+            eprintln!("Would append snapshot to {}", path.display());
+        } else if let Err(err) = append_snapshot(&location, &stats) {
+            eprintln!("warning: could not write stats snapshot: {:#}", err);
+        }
+    }
+    Ok(())
+}
+
+pub fn finished(since: String, json: bool) -> Result<()> {
+    let (board, _) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let cutoff = parse_since(&since, Utc::now(), zone)
+        .with_context(|| format!("invalid --since {:?}", since))?;
+    let report = finished_since(&board, cutoff);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    let Some(column) = &report.column else {
+        println!(
+            "No {} column on this board, so nothing counts as finished",
+            board.done_column_id()
+        );
+        return Ok(());
+    };
+    if report.notes.is_empty() {
+        println!(
+            "Nothing finished in {} since {}",
+            column,
+            format_due(&cutoff, zone)
+        );
+        return Ok(());
+    }
+    println!("Finished since {}:", format_due(&cutoff, zone));
+    for note in &report.notes {
+        let tags: String = note.tags.iter().map(|t| format!(" #{}", t)).collect();
+        println!("- {}{} ({})", note.title, tags, note.id);
+    }
+    Ok(())
+}
+
+pub fn settings(
+    key: Option<String>,
+    value: Option<String>,
+    unset: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let Some(key) = key else {
+        for key in BoardSettings::KEYS {
+            let value = board.settings.get(key).cloned().flatten();
+            println!("{}: {}", key, value.as_deref().unwrap_or("(unset)"));
+        }
+        return Ok(());
+    };
+    let current = board.settings.get(&key).cloned().ok_or_else(|| {
+        anyhow!(
+            "unknown setting {} (expected {})",
+            key,
+            BoardSettings::KEYS.join(", ")
+        )
+    })?;
+    if value.is_none() && !unset {
+        println!("{}", current.as_deref().unwrap_or("(unset)"));
+        return Ok(());
+    }
+    let value = value.map(|v| v.trim().to_string());
+    if let Some(value) = &value {
+        match key.as_str() {
+            "timezone" => {
+                parse_timezone(value)?;
+            }
+            _ => ensure_column(&board, value)?,
+        }
+    }
+    if let Some(slot) = board.settings.get_mut(&key) {
+        *slot = value.clone();
+    }
+    let shown = value.unwrap_or_else(|| "(unset)".to_string());
+    if dry_run {
+        println!("Would set {} to {}", key, shown);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    println!("Set {} to {}", key, shown);
+    Ok(())
+}
+
+pub fn import(file: PathBuf, yes: bool, dry_run: bool) -> Result<()> {
+    let incoming = read_board_file(&file)?;
+    let cwd = env::current_dir()?;
+    let location = locate_board(&cwd)?;
+    let current = if location.path.exists() {
+        load_board_readonly(&location)?
+    } else {
+        Board {
+            name: incoming.name.clone(),
+            columns: Vec::new(),
+            notes: Default::default(),
+            settings: Default::default(),
+            extra: Default::default(),
+        }
+    };
+    let diff = diff_boards(&current, &incoming);
+    if diff.is_empty() {
+        println!(
+            "No changes; {} already matches {}",
+            location.path.display(),
+            file.display()
+        );
+        return Ok(());
+    }
+    print_diff(&diff, &current, &incoming);
+    if dry_run {
+        println!("Would replace {}", location.path.display());
+        return Ok(());
+    }
+    if !yes && !confirm(&format!("Replace {}?", location.path.display()))? {
+        println!("Import canceled");
+        return Ok(());
+    }
+    backup_current_board(&location)?;
+    save_board(&location, &incoming)?;
+    println!(
+        "Imported {} into {}",
+        file.display(),
+        location.path.display()
+    );
+    Ok(())
+}
+
+pub fn normalize(yes: bool, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let lowercase = load_config().unwrap_or_default().tag_case == TagCase::Lower;
+    let changed: Vec<Note> = board
+        .notes
+        .values()
+        .map(|note| normalized_note(note, lowercase))
+        .filter(|clean| board.notes.get(&clean.id) != Some(clean))
+        .collect();
+    if changed.is_empty() {
+        println!(
+            "Nothing to normalize; all {} note(s) are clean",
+            board.notes.len()
+        );
+        return Ok(());
+    }
+    for note in &changed {
+        println!("  {}: {}", note.id, note.title);
+    }
+    let summary = format!("{} of {} note(s)", changed.len(), board.notes.len());
+    if dry_run {
+        println!("Would normalize {}", summary);
+        return Ok(());
+    }
+    if !yes && !confirm(&format!("Normalize {}?", summary))? {
+        println!("Normalize canceled");
+        return Ok(());
+    }
+    for clean in &changed {
+        board.update_note(&clean.id, |note| {
+            note.title = clean.title.clone();
+            note.body = clean.body.clone();
+            note.tags = clean.tags.clone();
+        })?;
+    }
+    backup_current_board(&location)?;
+    save_board(&location, &board)?;
+    for clean in changed {
+        record_event(
+            &location,
+            LogEvent::new(&clean.id, LogOp::Edit { title: clean.title }),
+        );
+    }
+    println!("Normalized {}", summary);
+    Ok(())
+}
+
+pub fn restore(timestamp: Option<String>, yes: bool, dry_run: bool) -> Result<()> {
+    let cwd = env::current_dir()?;
+    let location = locate_board(&cwd)?;
+    let backups = list_backups(&location)?;
+    let Some(timestamp) = timestamp else {
+        if backups.is_empty() {
+            println!("No backups for {}", location.path.display());
+        }
+        for backup in &backups {
+            let notes = match read_board_file(&backup.path) {
+                Ok(board) if board.notes.len() == 1 => "1 note".to_string(),
+                Ok(board) => format!("{} notes", board.notes.len()),
+                Err(_) => "unreadable".to_string(),
+            };
+            let taken_at: DateTime<chrono::Local> = backup.taken_at.into();
+            println!(
+                "{}  {}  {}",
+                backup.timestamp,
+                taken_at.format("%Y-%m-%d %H:%M:%S"),
+                notes
+            );
+        }
+        return Ok(());
+    };
+    let backup = backups
+        .iter()
+        .find(|b| b.timestamp == timestamp)
+        .ok_or_else(|| {
+            anyhow!(
+                "no backup {} (run `postit restore` to list them)",
+                timestamp
+            )
+        })?;
+    let restored = read_board_file(&backup.path)?;
+    let current = load_board_readonly(&location)?;
+    let diff = diff_boards(&current, &restored);
+    if diff.is_empty() {
+        println!(
+            "No changes; {} already matches backup {}",
+            location.path.display(),
+            timestamp
+        );
+        return Ok(());
+    }
+    print_diff(&diff, &current, &restored);
+    if dry_run {
+        println!("Would restore backup {}", timestamp);
+        return Ok(());
+    }
+    if !yes && !confirm(&format!("Restore backup {}?", timestamp))? {
+        println!("Restore canceled");
+        return Ok(());
+    }
+    backup_current_board(&location)?;
+    save_board(&location, &restored)?;
+    println!(
+        "Restored backup {} into {}",
+        timestamp,
+        location.path.display()
+    );
     Ok(())
 }
 
+fn print_diff(diff: &BoardDiff, current: &Board, incoming: &Board) {
+    let title = |board: &Board, id: &str| {
+        board
+            .notes
+            .get(id)
+            .map(|n| n.title.clone())
+            .unwrap_or_default()
+    };
+    if !diff.changed_settings.is_empty() {
+        let line = format!("Settings: ~ {}", diff.changed_settings.join(", "));
+        println!("{}", paint(&line, YELLOW));
+    }
+    if !diff.added_columns.is_empty()
+        || !diff.removed_columns.is_empty()
+        || !diff.changed_columns.is_empty()
+        || diff.columns_reordered
+    {
+        println!("Columns:");
+        for id in &diff.added_columns {
+            println!("{}", paint(&format!("  + {}", id), GREEN));
+        }
+        for id in &diff.removed_columns {
+            println!("{}", paint(&format!("  - {}", id), RED));
+        }
+        for (id, fields) in &diff.changed_columns {
+            let line = format!("  ~ {} ({})", id, fields.join(", "));
+            println!("{}", paint(&line, YELLOW));
+        }
+        if diff.columns_reordered {
+            println!("{}", paint("  ~ reordered", YELLOW));
+        }
+    }
+    if diff.added_notes.is_empty() && diff.removed_notes.is_empty() && diff.changed_notes.is_empty()
+    {
+        return;
+    }
+    println!("Notes:");
+    for id in &diff.added_notes {
+        let line = format!("  + {}: {}", id, title(incoming, id));
+        println!("{}", paint(&line, GREEN));
+    }
+    for id in &diff.removed_notes {
+        let line = format!("  - {}: {}", id, title(current, id));
+        println!("{}", paint(&line, RED));
+    }
+    for (id, fields) in &diff.changed_notes {
+        let line = format!(
+            "  ~ {}: {} ({})",
+            id,
+            title(incoming, id),
+            fields.join(", ")
+        );
+        println!("{}", paint(&line, YELLOW));
+    }
+}
+
+const GREEN: &str = "32";
+const RED: &str = "31";
+const YELLOW: &str = "33";
+
+/// Wraps `text` in an ANSI color when stdout is a terminal and `NO_COLOR` is unset.
+fn paint(text: &str, color: &str) -> String {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    if no_color || !io::stdout().is_terminal() {
+        text.to_string()
+    } else {
+        format!("\x1b[{}m{}\x1b[0m", color, text)
+    }
+}
+
+/// Asks a yes/no question on stdin; anything but `y`/`yes` (including EOF) is no.
+fn confirm(question: &str) -> Result<bool> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn edit(
     note_id: String,
     title: Option<String>,
     body: Option<String>,
+    append_body: Option<String>,
     tags: Vec<String>,
     clear_tags: bool,
     column: Option<String>,
     due: Option<String>,
     clear_due: bool,
+    due_shift: Option<String>,
+    from_now: bool,
+    attach: Vec<String>,
+    detach: Vec<String>,
+    waiting_on: Option<String>,
+    clear_waiting_on: bool,
+    dry_run: bool,
 ) -> Result<()> {
-    let (mut board, location) = load_current_board()?;
-    let due_dt = parse_due(due.as_deref())?;
-    let mut found = false;
+    let (mut board, location) = load_existing_board()?;
+    let zone = board_zone(&board);
+    let before = board.notes.get(&note_id).cloned();
+    let from = note_column_id(&board, &note_id);
+    let mut due_dt = parse_due_warning(due.as_deref(), zone)?;
+    if let Some(shift) = &due_shift {
+        let offset = parse_shift(shift)?;
+        let base = match before.as_ref().and_then(|n| n.due) {
+            Some(existing) => existing,
+            None if from_now => Utc::now(),
+            None if before.is_some() => {
+                bail!("note {} has no due date to shift (add --from-now)", note_id)
+            }
+            None => return Err(BoardError::NoteNotFound(note_id).into()),
+        };
+        due_dt = Some(base + offset);
+    }
+    // A shift keeps the note's precision; a new date sets it.
+    let precision = due
+        .as_deref()
+        .filter(|_| due_shift.is_none())
+        .map(due_precision);
     board
         .update_note(&note_id, |note| {
             if let Some(t) = title.clone() {
@@ -97,6 +1063,12 @@ pub fn edit(
             if let Some(b) = body.clone() {
                 note.body = Some(b);
             }
+            if let Some(extra) = &append_body {
+                note.body = Some(match note.body.take().filter(|b| !b.is_empty()) {
+                    Some(existing) => format!("{}\n{}", existing, extra),
+                    None => extra.clone(),
+                });
+            }
             if clear_tags {
                 note.tags.clear();
             }
@@ -105,75 +1077,380 @@ pub fn edit(
             }
             if clear_due {
                 note.due = None;
+                note.due_precision = DuePrecision::default();
             }
             if let Some(d) = due_dt {
                 note.due = Some(d);
             }
-            found = true;
+            if let Some(precision) = precision {
+                note.due_precision = precision;
+            }
+            note.attachments.retain(|a| !detach.contains(a));
+            for target in &attach {
+                if !note.attachments.contains(target) {
+                    note.attachments.push(target.clone());
+                }
+            }
+            if clear_waiting_on {
+                note.waiting_on = None;
+            }
+            if let Some(text) = waiting_on.as_deref().map(str::trim) {
+                note.waiting_on = Some(text.to_string()).filter(|t| !t.is_empty());
+            }
         })
-        .or_else(|err| match err {
-            BoardError::NoteNotFound(_) => Ok::<(), anyhow::Error>(()),
-            other => Err::<(), anyhow::Error>(other.into()),
-        })?;
-    if !found {
-        bail!("note {} not found", note_id);
+        .with_context(|| format!("editing note {}", note_id))?;
+    if due_dt.is_some() {
+        if let Some(note) = board.notes.get(&note_id) {
+            warn_mistyped_due(note, zone);
+        }
     }
     if let Some(col) = column {
         board
             .move_note(&note_id, &col)
             .with_context(|| format!("moving note {} to {}", note_id, col))?;
     }
+    if dry_run {
+        println!("Would update note {}", note_id);
+        if let Some(note) = before {
+            println!("before ({}):", from);
+            print_note(&note, zone);
+        }
+        if let Some(note) = board.notes.get(&note_id) {
+            println!("after ({}):", note_column_id(&board, &note_id));
+            print_note(note, zone);
+        }
+        return Ok(());
+    }
     save_board(&location, &board)?;
+    if let Some(note) = board.notes.get(&note_id) {
+        record_event(
+            &location,
+            LogEvent::new(
+                &note_id,
+                LogOp::Edit {
+                    title: note.title.clone(),
+                },
+            ),
+        );
+    }
     println!("Updated note {}", note_id);
+    hint_stale_waiting(&board, &note_id, &from);
+    let to = note_column_id(&board, &note_id);
+    if to != from {
+        record_event(&location, LogEvent::new(&note_id, LogOp::Move { from, to }));
+    }
+    Ok(())
+}
+
+/// Adds a copy of a note, under a new id, to the project or global board.
+pub fn copy_note(
+    note_id: String,
+    to: BoardScope,
+    column: Option<String>,
+    dry_run: bool,
+) -> Result<()> {
+    let (board, location) = load_existing_board()?;
+    let note = board
+        .notes
+        .get(&note_id)
+        .ok_or_else(|| BoardError::NoteNotFound(note_id.clone()))?;
+    let dest = board_location(to, &env::current_dir()?)?;
+    if dest.path == location.path {
+        bail!("note {} is already on the {} board", note_id, to.label());
+    }
+    let mut target =
+        load_board_readonly(&dest).with_context(|| format!("opening the {} board", to.label()))?;
+    let column_id = column
+        .or_else(|| target.default_column_id().map(str::to_string))
+        .ok_or_else(|| anyhow!("the {} board has no columns", to.label()))?;
+    ensure_column(&target, &column_id)?;
+    let id = new_note_id(&target);
+    target.add_note(note.copy_as(id.clone()), &column_id)?;
+    if dry_run {
+        println!(
+            "Would copy note {} to the {} board as {} in {}",
+            note_id,
+            to.label(),
+            id,
+            column_id
+        );
+        return Ok(());
+    }
+    save_board(&dest, &target)?;
+    record_event(
+        &dest,
+        LogEvent::new(
+            &id,
+            LogOp::Add {
+                column: column_id.clone(),
+                title: note.title.clone(),
+            },
+        ),
+    );
+    println!(
+        "Copied note {} to the {} board as {} in {}",
+        note_id,
+        to.label(),
+        id,
+        column_id
+    );
+    Ok(())
+}
+
+pub fn set_column_color(column_id: String, color: Option<String>, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    if let Some(name) = &color {
+        if ui::parse_color(name).is_none() {
+            eprintln!(
+                "warning: unknown color {}; column will use its palette default",
+                name
+            );
+        }
+    }
+    board
+        .set_column_color(&column_id, color.clone())
+        .with_context(|| format!("setting color of column {}", column_id))?;
+    let shown = color.unwrap_or_else(|| "default".to_string());
+    if dry_run {
+        println!("Would set column {} color to {}", column_id, shown);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    println!("Set column {} color to {}", column_id, shown);
+    Ok(())
+}
+
+pub fn set_column_icon(column_id: String, icon: Option<String>, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let icon = icon.map(|i| i.trim().to_string()).filter(|i| !i.is_empty());
+    board
+        .set_column_icon(&column_id, icon.clone())
+        .with_context(|| format!("setting icon of column {}", column_id))?;
+    let (would, did) = match &icon {
+        Some(icon) => (
+            format!("Would set column {} icon to {}", column_id, icon),
+            format!("Set column {} icon to {}", column_id, icon),
+        ),
+        None => (
+            format!("Would remove the icon of column {}", column_id),
+            format!("Removed the icon of column {}", column_id),
+        ),
+    };
+    if dry_run {
+        println!("{}", would);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    println!("{}", did);
+    Ok(())
+}
+
+pub fn describe_column(
+    column_id: String,
+    description: Option<String>,
+    dry_run: bool,
+) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    let description = description
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+    board
+        .set_column_description(&column_id, description.clone())
+        .with_context(|| format!("describing column {}", column_id))?;
+    let (would, did) = match &description {
+        Some(_) => ("Would describe", "Described"),
+        None => (
+            "Would clear the description of",
+            "Cleared the description of",
+        ),
+    };
+    if dry_run {
+        println!("{} column {}", would, column_id);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    println!("{} column {}", did, column_id);
+    Ok(())
+}
+
+pub fn set_inbox(column_id: Option<String>, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    board
+        .set_inbox(column_id.as_deref())
+        .with_context(|| format!("setting inbox to {}", column_id.as_deref().unwrap_or("")))?;
+    let message = match &column_id {
+        Some(id) => format!("inbox column to {}", id),
+        None => "no inbox column".to_string(),
+    };
+    if dry_run {
+        println!("Would set {}", message);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    println!("Set {}", message);
+    Ok(())
+}
+
+pub fn touch(note_id: String, dry_run: bool) -> Result<()> {
+    let (mut board, location) = load_existing_board()?;
+    board
+        .touch(&note_id)
+        .with_context(|| format!("touching note {}", note_id))?;
+    if dry_run {
+        println!("Would touch {}", note_id);
+        return Ok(());
+    }
+    save_board(&location, &board)?;
+    println!("Touched note {}", note_id);
     Ok(())
 }
 
 pub fn tui() -> Result<()> {
+    open(None, None, None)
+}
+
+pub fn open(
+    note_id: Option<String>,
+    column: Option<String>,
+    view: Option<ui::ViewMode>,
+) -> Result<()> {
     let (board, location) = load_current_board()?;
-    ui::run(board, location)
+    let config = load_config()?;
+    // Explicit arguments win over the spot saved by the last session.
+    let resume = (note_id.is_none() && column.is_none() && view.is_none() && config.restore_focus)
+        .then(|| load_focus(&location))
+        .flatten();
+    let focus = note_id
+        .map(ui::InitialFocus::Note)
+        .or(column.map(ui::InitialFocus::Column))
+        .or(resume.map(ui::InitialFocus::Resume));
+    let view = view.unwrap_or_else(|| default_view(config.default_view.as_deref()));
+    ui::run(board, location, config, focus, view)
 }
 
-fn load_current_board() -> Result<(crate::model::Board, BoardLocation)> {
+/// The configured start view, falling back to the board with a warning.
+fn default_view(value: Option<&str>) -> ui::ViewMode {
+    let Some(value) = value else {
+        return ui::ViewMode::Board;
+    };
+    ui::ViewMode::from_name(value).unwrap_or_else(|| {
+        eprintln!(
+            "warning: invalid default_view {:?} (use board, timeline, or project); using board",
+            value
+        );
+        ui::ViewMode::Board
+    })
+}
+
+fn load_current_board() -> Result<(Board, BoardLocation)> {
     let cwd = env::current_dir()?;
     let location = locate_board(&cwd)?;
     let board = load_board(&location)?;
+    warn_unknown_fields(&board);
     Ok((board, location))
 }
 
-fn parse_due(input: Option<&str>) -> Result<Option<DateTime<Utc>>> {
-    let raw = match input {
-        Some(r) => r.trim(),
-        None => return Ok(None),
+/// For previews: a missing board stands in as the default one, unsaved.
+fn load_board_or_default() -> Result<(Board, BoardLocation)> {
+    let cwd = env::current_dir()?;
+    let location = locate_board(&cwd)?;
+    let board = if location.path.exists() {
+        load_board_readonly(&location)?
+    } else {
+        default_board(&location)
     };
-    if raw.is_empty() {
-        return Ok(None);
+    warn_unknown_fields(&board);
+    Ok((board, location))
+}
+
+/// For commands that only act on an existing board; never creates one.
+fn load_existing_board() -> Result<(Board, BoardLocation)> {
+    let cwd = env::current_dir()?;
+    let location = locate_board(&cwd)?;
+    let board = load_board_readonly(&location)?;
+    warn_unknown_fields(&board);
+    Ok((board, location))
+}
+
+/// The zone `board`'s due dates are entered and shown in; its own
+/// `timezone` setting wins over the global config.
+fn board_zone(board: &Board) -> Zone {
+    let global = load_config().ok().and_then(|c| c.timezone);
+    resolve_timezone(board.settings.timezone.as_deref(), global.as_deref())
+}
+
+/// Warns when the board carries fields this version doesn't know.
+fn warn_unknown_fields(board: &Board) {
+    let unknown = board.unknown_fields();
+    if !unknown.is_empty() {
+        eprintln!(
+            "warning: board has fields this postit does not know ({}); a newer version may have written them. They are kept as-is",
+            unknown.join(", ")
+        );
+    }
+}
+
+/// Best-effort append to the event log; failures only warn.
+fn record_event(location: &BoardLocation, event: LogEvent) {
+    let enabled = load_config().map(|c| c.event_log).unwrap_or(false);
+    if !enabled {
+        return;
     }
-    let dt = NaiveDateTime::parse_from_str(raw, "%Y.%m.%d@%H:%M")
-        .map_err(|_| anyhow!("invalid date format (use YYYY.MM.DD@hh:mm): {}", raw))?;
-    return Ok(Some(Utc.from_utc_datetime(&dt)));
+    if let Err(err) = append_event(location, &event) {
+        eprintln!("warning: could not write event log: {:#}", err);
+    }
+}
+
+fn note_column_id(board: &Board, note_id: &str) -> String {
+    board
+        .find_note_column_index(note_id)
+        .and_then(|idx| board.columns.get(idx))
+        .map(|c| c.id.clone())
+        .unwrap_or_else(|| "(no column)".to_string())
+}
+
+/// A fresh note id for `board`, shaped by the `id_length` and `id_alphabet`
+/// config settings.
+fn new_note_id(board: &Board) -> String {
+    let config = load_config().unwrap_or_default();
+    generate_id(&config, |id| board.id_in_use(id))
 }
 
-fn format_due(dt: &DateTime<Utc>) -> String {
-    dt.format("%Y.%m.%d@%H:%M").to_string()
+/// Fails with a "did you mean" hint when `column_id` is not on the board.
+fn ensure_column(board: &Board, column_id: &str) -> Result<()> {
+    if board.find_column_index(column_id).is_some() {
+        return Ok(());
+    }
+    let missing = Err(BoardError::ColumnNotFound(column_id.to_string()));
+    match board.closest_column(column_id) {
+        Some(guess) => missing
+            .with_context(|| format!("column {} not found; did you mean `{}`?", column_id, guess)),
+        None => Ok(missing?),
+    }
 }
 
-fn generate_id() -> String {
-    rand::thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(6)
-        .map(char::from)
-        .collect()
+fn print_note(note: &Note, zone: Zone) {
+    for line in note_lines(note, zone) {
+        println!("{}", line);
+    }
 }
 
-fn print_note(note: &Note) {
-    println!("  - {}: {}", note.id, note.title);
+fn note_lines(note: &Note, zone: Zone) -> Vec<String> {
+    let mut lines = vec![format!("  - {}: {}", note.id, note.title)];
     if let Some(body) = &note.body {
-        println!("    {}", body);
+        lines.push(format!("    {}", body));
     }
     if !note.tags.is_empty() {
-        println!("    tags: {}", note.tags.join(", "));
+        lines.push(format!("    tags: {}", note.tags.join(", ")));
     }
     if let Some(due) = note.due {
-        println!("    due: {}", format_due(&due));
+        lines.push(format!(
+            "    due: {}",
+            format_due_as(&due, note.due_precision, zone)
+        ));
+    }
+    if let Some(waiting_on) = &note.waiting_on {
+        lines.push(format!("    waiting on: {}", waiting_on));
     }
+    lines
 }
@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use chrono::NaiveTime;
+use directories::ProjectDirs;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Config {
+    /// Rows of context kept above/below the selection when scrolling lists.
+    pub scrolloff: usize,
+    /// Append each note change to `events.jsonl` next to the board file.
+    pub event_log: bool,
+    /// Commit the board file after each save when it sits in a git repo.
+    pub git_autocommit: bool,
+    /// Start the board with one-line notes instead of cards.
+    pub compact: bool,
+    /// Show due dates as `YYYY-MM-DD` without the time of day.
+    pub due_date_only: bool,
+    /// Fixed colors for specific tags (name or `#rrggbb`); others get a hashed color.
+    pub tag_colors: HashMap<String, String>,
+    /// Time of day (`hh:mm`) given to due dates entered as `YYYY.MM.DD`.
+    pub default_due_time: Option<String>,
+    /// Keep the selection in the source column after moving notes instead of following them.
+    pub stay_after_move: bool,
+    /// Draw the TUI with plain ASCII instead of box-drawing and symbol glyphs.
+    pub ascii_only: bool,
+    /// Capture the mouse in the TUI, so clicks in the note form place the cursor.
+    pub mouse: bool,
+    /// Shortest idle wait between TUI redraws, in milliseconds. Keys are handled
+    /// as soon as they arrive either way.
+    pub poll_interval_ms: u64,
+    /// Start with the done column hidden from the board.
+    pub hide_done: bool,
+    /// Wrap long titles over several lines on board cards instead of cutting them off.
+    pub wrap_titles: bool,
+    /// Moving past the last column or note wraps to the first (and back).
+    pub wrap_navigation: bool,
+    /// Most cards drawn per column id before a "+N more" line; the rest appear
+    /// once the selection moves past the cap.
+    pub column_caps: HashMap<String, usize>,
+    /// Moving a note past the last column sends it to the first (and back).
+    pub cycle_move: bool,
+    /// First day of the week in the timeline calendar.
+    pub week_start: WeekStart,
+    /// Flag new due dates more than this many days in the past as likely typos (0 turns it off).
+    pub past_due_warning_days: u32,
+    /// Show a desktop notification when a note's due time passes while the TUI is open.
+    pub due_notifications: bool,
+    /// Order of tags in the project view: by name, or busiest first.
+    pub tag_order: TagOrder,
+    /// How `normalize` treats tag case: lowercase them, or keep them as typed.
+    pub tag_case: TagCase,
+    /// View the TUI opens in (`board`, `timeline`, or `project`).
+    pub default_view: Option<String>,
+    /// Reopen the TUI at the view, column, and note it was left on.
+    pub restore_focus: bool,
+    /// Characters in newly generated note ids.
+    pub id_length: usize,
+    /// Characters new note ids are drawn from.
+    pub id_alphabet: IdAlphabet,
+    /// Zone for entering and showing due dates (`utc`, `local`, `+hh:mm`); a board's
+    /// own `timezone` setting wins.
+    pub timezone: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            scrolloff: 1,
+            event_log: false,
+            git_autocommit: false,
+            compact: false,
+            due_date_only: false,
+            tag_colors: HashMap::new(),
+            default_due_time: None,
+            stay_after_move: false,
+            ascii_only: false,
+            mouse: false,
+            poll_interval_ms: 200,
+            hide_done: false,
+            wrap_titles: false,
+            wrap_navigation: false,
+            column_caps: HashMap::new(),
+            cycle_move: false,
+            week_start: WeekStart::Monday,
+            default_view: None,
+            restore_focus: true,
+            tag_order: TagOrder::Name,
+            tag_case: TagCase::Lower,
+            past_due_warning_days: 30,
+            due_notifications: true,
+            id_length: 6,
+            id_alphabet: IdAlphabet::Alphanumeric,
+            timezone: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    Monday,
+    Sunday,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TagCase {
+    Lower,
+    /// Leave tag spelling alone; only exact repeats are merged.
+    Keep,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TagOrder {
+    Name,
+    /// Most notes first, ties by name.
+    Count,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IdAlphabet {
+    /// Digits and upper- and lowercase letters.
+    Alphanumeric,
+    /// Like alphanumeric, without characters that look alike (`0/O/o`, `1/I/l`).
+    Unambiguous,
+}
+
+impl Config {
+    /// The time of day day-only due dates get: `default_due_time`, or
+    /// midnight when it is unset or not `hh:mm` (which also warns).
+    pub fn due_time(&self) -> NaiveTime {
+        let Some(value) = &self.default_due_time else {
+            return NaiveTime::MIN;
+        };
+        NaiveTime::parse_from_str(value.trim(), "%H:%M").unwrap_or_else(|_| {
+            eprintln!(
+                "warning: invalid default_due_time {:?} (expected hh:mm); using 00:00",
+                value
+            );
+            NaiveTime::MIN
+        })
+    }
+}
+
+pub fn load_config() -> Result<Config> {
+    let path = config_path()?;
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+    let data = fs::read_to_string(&path).with_context(|| format!("reading {:?}", path))?;
+    let config: Config = serde_yaml::from_str(&data).context("parsing config file")?;
+    Ok(config)
+}
+
+pub fn config_path() -> Result<PathBuf> {
+    let dirs = ProjectDirs::from("", "", "postit").context("locating config directory")?;
+    Ok(dirs.config_dir().join("config.yml"))
+}
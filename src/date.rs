@@ -0,0 +1,512 @@
+use crate::model::DuePrecision;
+use anyhow::{anyhow, Result};
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    Offset, TimeZone, Utc,
+};
+use std::fmt;
+
+/// Format used for due dates everywhere: `YYYY.MM.DD@hh:mm`.
+pub const DUE_FORMAT: &str = "%Y.%m.%d@%H:%M";
+
+/// Day-only due dates (`YYYY.MM.DD`) get the configured default time.
+const DUE_DATE_FORMAT: &str = "%Y.%m.%d";
+
+/// ISO 8601 times without an offset, read in the display zone like `DUE_FORMAT`.
+const ISO_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"];
+
+/// ISO 8601 day, which gets the default time like `DUE_DATE_FORMAT`.
+const ISO_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Every accepted due date form, for help and error messages.
+const DUE_FORMATS_HELP: &str =
+    "YYYY.MM.DD@hh:mm, YYYY.MM.DD, or ISO 8601 like 2025-01-31T17:00:00Z";
+
+/// Zone due dates are entered and shown in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Zone {
+    Fixed(FixedOffset),
+    /// The system zone, following its daylight saving changes.
+    Local,
+}
+
+impl Default for Zone {
+    fn default() -> Self {
+        Zone::Fixed(Utc.fix())
+    }
+}
+
+/// Parses a `timezone` setting: `utc`, `local`, or a fixed offset like `+02:00`.
+pub fn parse_timezone(value: &str) -> Result<Zone> {
+    let value = value.trim();
+    match value.to_lowercase().as_str() {
+        "utc" => return Ok(Zone::Fixed(Utc.fix())),
+        "local" => return Ok(Zone::Local),
+        _ => {}
+    }
+    DateTime::parse_from_str(&format!("2000-01-01 00:00 {}", value), "%Y-%m-%d %H:%M %:z")
+        .map(|dt| Zone::Fixed(*dt.offset()))
+        .map_err(|_| anyhow!("invalid timezone {:?} (use utc, local, or +hh:mm)", value))
+}
+
+/// The zone due dates are entered and shown in: the board's `timezone`
+/// setting, else the config's, else UTC. An invalid value warns and is skipped.
+pub fn resolve_timezone(board: Option<&str>, config: Option<&str>) -> Zone {
+    [board, config]
+        .into_iter()
+        .flatten()
+        .find_map(|value| match parse_timezone(value) {
+            Ok(zone) => Some(zone),
+            Err(err) => {
+                eprintln!("warning: {}; ignoring it", err);
+                None
+            }
+        })
+        .unwrap_or_default()
+}
+
+/// `dt` in the display zone.
+pub fn in_timezone(dt: &DateTime<Utc>, zone: Zone) -> DateTime<FixedOffset> {
+    match zone {
+        Zone::Fixed(offset) => dt.with_timezone(&offset),
+        Zone::Local => dt.with_timezone(&Local).fixed_offset(),
+    }
+}
+
+/// Parses a due date entered in `zone`; blank input means "no due date". A
+/// bare `YYYY.MM.DD` gets `default_time` (see `Config::due_time`).
+pub fn parse_due(
+    input: &str,
+    default_time: NaiveTime,
+    zone: Zone,
+) -> Result<Option<DateTime<Utc>>> {
+    parse_due_noting(input, default_time, zone).map(|(due, _)| due)
+}
+
+/// Like `parse_due`, but also returns a note when the entered time had to be
+/// adjusted around a daylight saving change.
+pub fn parse_due_noting(
+    input: &str,
+    default_time: NaiveTime,
+    zone: Zone,
+) -> Result<(Option<DateTime<Utc>>, Option<String>)> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok((None, None));
+    }
+    // A time with its own offset (RFC 3339) needs no zone.
+    if let Ok(dt) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok((Some(dt.with_timezone(&Utc)), None));
+    }
+    let dt = [DUE_FORMAT, ISO_FORMATS[0], ISO_FORMATS[1]]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(trimmed, format).ok())
+        .or_else(|| {
+            [DUE_DATE_FORMAT, ISO_DATE_FORMAT]
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(trimmed, format).ok())
+                .map(|d| d.and_time(default_time))
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "invalid date format (use {}): {}",
+                DUE_FORMATS_HELP,
+                trimmed
+            )
+        })?;
+    let (due, note) = match zone {
+        Zone::Fixed(offset) => resolve_local(&offset, dt),
+        Zone::Local => resolve_local(&Local, dt),
+    };
+    Ok((Some(due), note))
+}
+
+/// Maps a wall-clock time in `zone` to an instant. A time that happens twice
+/// when clocks go back resolves to the later occurrence; a time skipped when
+/// they go forward keeps the offset from before the jump, landing just after
+/// it. Either way the returned note explains the choice.
+fn resolve_local<Tz: TimeZone>(zone: &Tz, local: NaiveDateTime) -> (DateTime<Utc>, Option<String>)
+where
+    Tz::Offset: fmt::Display,
+{
+    let shown = |dt: &DateTime<Tz>| dt.format("%Y.%m.%d@%H:%M %:z").to_string();
+    match zone.from_local_datetime(&local) {
+        LocalResult::Single(dt) => (dt.with_timezone(&Utc), None),
+        LocalResult::Ambiguous(first, second) => {
+            let later = if second > first { second } else { first };
+            (
+                later.with_timezone(&Utc),
+                Some(format!(
+                    "{} happens twice (clocks go back); using the later one, {}",
+                    local.format(DUE_FORMAT),
+                    shown(&later)
+                )),
+            )
+        }
+        LocalResult::None => {
+            let before = zone
+                .offset_from_utc_datetime(&(local - Duration::days(1)))
+                .fix();
+            let due = Utc.from_utc_datetime(&(local - before));
+            (
+                due,
+                Some(format!(
+                    "{} does not exist (clocks go forward); using {}",
+                    local.format(DUE_FORMAT),
+                    shown(&due.with_timezone(zone))
+                )),
+            )
+        }
+    }
+}
+
+/// 23:59 in `zone`, `days_ahead` days after the day of `now` there.
+pub fn end_of_day(now: DateTime<Utc>, days_ahead: i64, zone: Zone) -> DateTime<Utc> {
+    let today = match zone {
+        Zone::Fixed(offset) => now.with_timezone(&offset).date_naive(),
+        Zone::Local => now.with_timezone(&Local).date_naive(),
+    };
+    let last_minute = NaiveTime::from_hms_opt(23, 59, 0).unwrap_or(NaiveTime::MIN);
+    let local = (today + Duration::days(days_ahead)).and_time(last_minute);
+    match zone {
+        Zone::Fixed(offset) => resolve_local(&offset, local).0,
+        Zone::Local => resolve_local(&Local, local).0,
+    }
+}
+
+pub fn format_due(dt: &DateTime<Utc>, zone: Zone) -> String {
+    in_timezone(dt, zone).format(DUE_FORMAT).to_string()
+}
+
+/// Like `format_due`, but only the day (`YYYY.MM.DD`) for day-only due dates.
+pub fn format_due_as(dt: &DateTime<Utc>, precision: DuePrecision, zone: Zone) -> String {
+    match precision {
+        DuePrecision::Date => in_timezone(dt, zone).format(DUE_DATE_FORMAT).to_string(),
+        DuePrecision::DateTime => format_due(dt, zone),
+    }
+}
+
+/// Whether due date `input` names a bare day or a time of day.
+pub fn due_precision(input: &str) -> DuePrecision {
+    let trimmed = input.trim();
+    let day_only = [DUE_DATE_FORMAT, ISO_DATE_FORMAT]
+        .iter()
+        .any(|format| NaiveDate::parse_from_str(trimmed, format).is_ok());
+    if day_only {
+        DuePrecision::Date
+    } else {
+        DuePrecision::DateTime
+    }
+}
+
+/// Why a newly entered `due` looks like a typo (e.g. the wrong year): it is more
+/// than `max_past_days` before `now` (0 skips this check), or more than a day
+/// before the note's `created_at`. Such dates are allowed, only flagged.
+pub fn mistyped_due_reason(
+    due: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    max_past_days: u32,
+    zone: Zone,
+) -> Option<String> {
+    let days_past = (now - due).num_days();
+    if max_past_days > 0 && days_past > i64::from(max_past_days) {
+        return Some(format!(
+            "due {} is {} days in the past",
+            format_due(&due, zone),
+            days_past
+        ));
+    }
+    if due < created_at - Duration::days(1) {
+        return Some(format!(
+            "due {} is before the note was created on {}",
+            format_due(&due, zone),
+            format_due(&created_at, zone)
+        ));
+    }
+    None
+}
+
+/// Parses a lower bound for "changed since" filters: an absolute due-style
+/// timestamp (a bare day means its start), `today`/`yesterday` (midnight UTC),
+/// or an age like `12h`, `3d`, `2w`.
+pub fn parse_since(input: &str, now: DateTime<Utc>, zone: Zone) -> Result<DateTime<Utc>> {
+    let trimmed = input.trim();
+    let midnight = |days_back: i64| {
+        let day = now.date_naive() - Duration::days(days_back);
+        Utc.from_utc_datetime(&day.and_hms_opt(0, 0, 0).unwrap_or_default())
+    };
+    match trimmed.to_lowercase().as_str() {
+        "today" => return Ok(midnight(0)),
+        "yesterday" => return Ok(midnight(1)),
+        _ => {}
+    }
+    if let Some(age) = parse_age(trimmed) {
+        return Ok(now - age);
+    }
+    parse_due(trimmed, NaiveTime::MIN, zone)?
+        .ok_or_else(|| anyhow!("expected YYYY.MM.DD@hh:mm, today, yesterday, or an age like 3d"))
+}
+
+/// Parses a signed offset such as `+3d`, `-1w`, or `12h` (no sign means later).
+pub fn parse_shift(input: &str) -> Result<Duration> {
+    let trimmed = input.trim();
+    let (sign, age) = match trimmed.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    parse_age(age)
+        .map(|age| age * sign)
+        .ok_or_else(|| anyhow!("invalid shift {:?} (expected e.g. +3d, -1w, 12h)", trimmed))
+}
+
+fn parse_age(input: &str) -> Option<Duration> {
+    let unit = input.chars().last()?;
+    let amount: i64 = input[..input.len() - unit.len_utf8()].parse().ok()?;
+    if amount < 0 {
+        return None;
+    }
+    match unit {
+        'h' => Some(Duration::hours(amount)),
+        'd' => Some(Duration::days(amount)),
+        'w' => Some(Duration::weeks(amount)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_timestamp() {
+        let dt = parse_due("2024.03.09@14:05", NaiveTime::MIN, Zone::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(dt, Utc.with_ymd_and_hms(2024, 3, 9, 14, 5, 0).unwrap());
+    }
+
+    #[test]
+    fn end_of_day_uses_the_display_zone_day() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 7, 22, 30, 0).unwrap();
+        let utc = Zone::Fixed(Utc.fix());
+        assert_eq!(
+            end_of_day(now, 0, utc),
+            Utc.with_ymd_and_hms(2025, 3, 7, 23, 59, 0).unwrap()
+        );
+        // Already the 8th at UTC+02:00, so two days ahead is the 10th there.
+        let plus_two = parse_timezone("+02:00").unwrap();
+        assert_eq!(
+            end_of_day(now, 2, plus_two),
+            Utc.with_ymd_and_hms(2025, 3, 10, 21, 59, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn due_far_in_the_past_or_before_creation_is_flagged() {
+        let at = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap();
+        let (created, now) = (at(2025, 1, 1), at(2025, 6, 1));
+        let typo = mistyped_due_reason(at(2023, 6, 1), now, now, 30, Zone::default()).unwrap();
+        assert!(typo.contains("731 days in the past"), "{}", typo);
+        let stale = mistyped_due_reason(at(2025, 3, 1), created, now, 30, Zone::default()).unwrap();
+        assert!(stale.contains("92 days in the past"), "{}", stale);
+        assert_eq!(
+            mistyped_due_reason(at(2025, 3, 1), created, now, 0, Zone::default()),
+            None
+        );
+        assert_eq!(
+            mistyped_due_reason(at(2025, 5, 20), created, now, 30, Zone::default()),
+            None
+        );
+        assert_eq!(
+            mistyped_due_reason(at(2025, 5, 31), now, now, 30, Zone::default()),
+            None
+        );
+        let early =
+            mistyped_due_reason(at(2024, 12, 20), created, now, 0, Zone::default()).unwrap();
+        assert!(early.contains("before the note was created"), "{}", early);
+    }
+
+    #[test]
+    fn blank_input_is_no_due_date() {
+        assert_eq!(
+            parse_due("", NaiveTime::MIN, Zone::default()).unwrap(),
+            None
+        );
+        assert_eq!(
+            parse_due("   ", NaiveTime::MIN, Zone::default()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        assert!(
+            parse_due("  2024.03.09@14:05 \n", NaiveTime::MIN, Zone::default())
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn day_only_uses_default_time() {
+        let at = |h, m| Utc.with_ymd_and_hms(2024, 3, 9, h, m, 0).unwrap();
+        let due = |input, time| parse_due_noting(input, time, Zone::default()).unwrap();
+        assert_eq!(due("2024.03.09", NaiveTime::MIN), (Some(at(0, 0)), None));
+        let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+        assert_eq!(due("2024.03.09", five), (Some(at(17, 0)), None));
+        assert_eq!(due("2024.03.09@08:30", five), (Some(at(8, 30)), None));
+    }
+
+    #[test]
+    fn timezone_offsets_shift_entered_times() {
+        let offset = |secs| Zone::Fixed(FixedOffset::east_opt(secs).unwrap());
+        let plus_two = parse_timezone("+02:00").unwrap();
+        assert_eq!(plus_two, offset(2 * 3600));
+        assert_eq!(parse_timezone("UTC").unwrap(), offset(0));
+        assert_eq!(parse_timezone("local").unwrap(), Zone::Local);
+        assert_eq!(
+            parse_timezone("-05:30").unwrap(),
+            offset(-(5 * 3600 + 1800))
+        );
+        for bad in ["", "mars", "+25:00", "0200"] {
+            assert!(parse_timezone(bad).is_err(), "accepted {:?}", bad);
+        }
+        let (due, note) = parse_due_noting("2024.03.09@17:00", NaiveTime::MIN, plus_two).unwrap();
+        assert_eq!(
+            due,
+            Some(Utc.with_ymd_and_hms(2024, 3, 9, 15, 0, 0).unwrap())
+        );
+        assert_eq!(note, None);
+    }
+
+    #[test]
+    fn iso_8601_input_is_accepted() {
+        let plus_two = Zone::Fixed(FixedOffset::east_opt(2 * 3600).unwrap());
+        let due = |input| parse_due_noting(input, NaiveTime::MIN, plus_two).unwrap().0;
+        let at = |h, m| Some(Utc.with_ymd_and_hms(2025, 1, 31, h, m, 0).unwrap());
+        assert_eq!(due("2025-01-31T17:00:00Z"), at(17, 0));
+        assert_eq!(due("2025-01-31T17:00:00+05:30"), at(11, 30));
+        assert_eq!(
+            due("2025-01-31T17:00:00.250-01:00"),
+            at(18, 0).map(|d| d + Duration::milliseconds(250))
+        );
+        // Without an offset, the time is read in the configured zone.
+        assert_eq!(due("2025-01-31T17:00:00"), at(15, 0));
+        assert_eq!(due("2025-01-31T17:00"), at(15, 0));
+        assert_eq!(
+            due("2025-01-31"),
+            Some(Utc.with_ymd_and_hms(2025, 1, 30, 22, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for bad in [
+            "2024-03-09 14:05",
+            "2024.03.09@",
+            "2024.13.01@10:00",
+            "2024.02.30@10:00",
+            "2024.03.09@25:00",
+            "2024-03-09T25:00:00Z",
+            "2024-03-09T14",
+            "tomorrow",
+        ] {
+            assert!(
+                parse_due(bad, NaiveTime::MIN, Zone::default()).is_err(),
+                "accepted {:?}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn since_accepts_keywords_ages_and_timestamps() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 9, 14, 5, 0).unwrap();
+        let at = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+        assert_eq!(
+            parse_since("today", now, Zone::default()).unwrap(),
+            at(2024, 3, 9, 0)
+        );
+        assert_eq!(
+            parse_since("Yesterday", now, Zone::default()).unwrap(),
+            at(2024, 3, 8, 0)
+        );
+        assert_eq!(
+            parse_since("3d", now, Zone::default()).unwrap(),
+            now - Duration::days(3)
+        );
+        assert_eq!(
+            parse_since("12h", now, Zone::default()).unwrap(),
+            now - Duration::hours(12)
+        );
+        assert_eq!(
+            parse_since("1w", now, Zone::default()).unwrap(),
+            now - Duration::weeks(1)
+        );
+        assert_eq!(
+            parse_since("2024.01.01@00:00", now, Zone::default()).unwrap(),
+            at(2024, 1, 1, 0)
+        );
+        for bad in ["", "3", "d", "-1d", "3y", "soon"] {
+            assert!(
+                parse_since(bad, now, Zone::default()).is_err(),
+                "accepted {:?}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn shift_crosses_month_boundaries() {
+        let due = |s| {
+            parse_due(s, NaiveTime::MIN, Zone::default())
+                .unwrap()
+                .unwrap()
+        };
+        let shifted = |s, by| format_due(&(due(s) + parse_shift(by).unwrap()), Zone::default());
+        assert_eq!(shifted("2024.01.30@10:00", "+3d"), "2024.02.02@10:00");
+        assert_eq!(shifted("2024.03.03@10:00", "-1w"), "2024.02.25@10:00");
+        assert_eq!(shifted("2023.12.31@20:00", "6h"), "2024.01.01@02:00");
+        assert_eq!(shifted("2024.03.01@09:00", "-1d"), "2024.02.29@09:00");
+        for bad in ["", "+", "3", "--1d", "+-1d", "1y"] {
+            assert!(parse_shift(bad).is_err(), "accepted {:?}", bad);
+        }
+    }
+
+    #[test]
+    fn format_round_trips() {
+        let dt = parse_due("2024.12.31@23:59", NaiveTime::MIN, Zone::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(format_due(&dt, Zone::default()), "2024.12.31@23:59");
+    }
+
+    #[test]
+    fn day_only_entries_keep_date_precision() {
+        for day in ["2024.12.31", " 2024-12-31 "] {
+            assert_eq!(due_precision(day), DuePrecision::Date, "{:?}", day);
+            let dt = parse_due(day, NaiveTime::MIN, Zone::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                format_due_as(&dt, DuePrecision::Date, Zone::default()),
+                "2024.12.31"
+            );
+        }
+        for timed in [
+            "2024.12.31@14:30",
+            "2024-12-31T14:30",
+            "2024-12-31T14:30:00Z",
+        ] {
+            assert_eq!(due_precision(timed), DuePrecision::DateTime, "{:?}", timed);
+            let dt = parse_due(timed, NaiveTime::MIN, Zone::default())
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                format_due_as(&dt, DuePrecision::DateTime, Zone::default()),
+                "2024.12.31@14:30"
+            );
+        }
+    }
+}
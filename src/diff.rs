@@ -0,0 +1,258 @@
+use crate::model::{Board, BoardSettings, Column, Note, NoteId};
+
+/// What replacing one board with another would change.
+#[derive(Debug, Default, PartialEq)]
+pub struct BoardDiff {
+    pub added_columns: Vec<String>,
+    pub removed_columns: Vec<String>,
+    /// Columns present on both sides, with the names of the fields that
+    /// differ; `order` means notes kept in the column were reordered.
+    pub changed_columns: Vec<(String, Vec<&'static str>)>,
+    /// Whether the columns kept on both sides are in a different order.
+    pub columns_reordered: bool,
+    /// Names of the board settings that differ.
+    pub changed_settings: Vec<&'static str>,
+    pub added_notes: Vec<NoteId>,
+    pub removed_notes: Vec<NoteId>,
+    /// Notes present on both sides, with the names of the fields that differ.
+    pub changed_notes: Vec<(NoteId, Vec<&'static str>)>,
+}
+
+impl BoardDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_columns.is_empty()
+            && self.removed_columns.is_empty()
+            && self.changed_columns.is_empty()
+            && !self.columns_reordered
+            && self.changed_settings.is_empty()
+            && self.added_notes.is_empty()
+            && self.removed_notes.is_empty()
+            && self.changed_notes.is_empty()
+    }
+}
+
+/// Compares `current` to `incoming`. Bookkeeping such as timestamps and
+/// history is ignored; only user-visible content and placement count.
+pub fn diff_boards(current: &Board, incoming: &Board) -> BoardDiff {
+    let column_ids = |board: &Board| board.columns.iter().map(|c| c.id.clone()).collect();
+    let (old_columns, new_columns): (Vec<String>, Vec<String>) =
+        (column_ids(current), column_ids(incoming));
+
+    let mut diff = BoardDiff {
+        added_columns: new_columns
+            .iter()
+            .filter(|id| !old_columns.contains(id))
+            .cloned()
+            .collect(),
+        removed_columns: old_columns
+            .iter()
+            .filter(|id| !new_columns.contains(id))
+            .cloned()
+            .collect(),
+        columns_reordered: kept_in_order(&old_columns, &new_columns)
+            != kept_in_order(&new_columns, &old_columns),
+        changed_settings: changed_settings(&current.settings, &incoming.settings),
+        ..BoardDiff::default()
+    };
+    for column in &incoming.columns {
+        if let Some(old) = current.columns.iter().find(|c| c.id == column.id) {
+            let fields = changed_column_fields(old, column);
+            if !fields.is_empty() {
+                diff.changed_columns.push((column.id.clone(), fields));
+            }
+        }
+    }
+
+    for (id, note) in &incoming.notes {
+        match current.notes.get(id) {
+            None => diff.added_notes.push(id.clone()),
+            Some(old) => {
+                let mut fields = changed_fields(old, note);
+                if column_of(current, id) != column_of(incoming, id) {
+                    fields.push("column");
+                }
+                if !fields.is_empty() {
+                    diff.changed_notes.push((id.clone(), fields));
+                }
+            }
+        }
+    }
+    diff.removed_notes = current
+        .notes
+        .keys()
+        .filter(|id| !incoming.notes.contains_key(*id))
+        .cloned()
+        .collect();
+
+    diff.added_notes.sort();
+    diff.removed_notes.sort();
+    diff.changed_notes.sort();
+    diff
+}
+
+/// The items of `ids` that also appear in `other`, in `ids`' order.
+fn kept_in_order<'a>(ids: &'a [String], other: &[String]) -> Vec<&'a String> {
+    ids.iter().filter(|id| other.contains(id)).collect()
+}
+
+fn changed_column_fields(old: &Column, new: &Column) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if old.name != new.name {
+        fields.push("name");
+    }
+    if old.color != new.color {
+        fields.push("color");
+    }
+    if old.icon != new.icon {
+        fields.push("icon");
+    }
+    if old.sort != new.sort {
+        fields.push("sort");
+    }
+    if old.is_inbox != new.is_inbox {
+        fields.push("is_inbox");
+    }
+    if old.description != new.description {
+        fields.push("description");
+    }
+    if old.wip_limit != new.wip_limit {
+        fields.push("wip_limit");
+    }
+    // Notes moving in or out show up as note changes; only a reshuffle of
+    // the notes the column keeps counts here.
+    if kept_in_order(&old.note_ids, &new.note_ids) != kept_in_order(&new.note_ids, &old.note_ids) {
+        fields.push("order");
+    }
+    fields
+}
+
+/// Differing settings; the saved TUI focus is bookkeeping and doesn't count.
+fn changed_settings(old: &BoardSettings, new: &BoardSettings) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if old.done_column != new.done_column {
+        fields.push("done_column");
+    }
+    if old.default_column != new.default_column {
+        fields.push("default_column");
+    }
+    if old.timezone != new.timezone {
+        fields.push("timezone");
+    }
+    if old.tag_limits != new.tag_limits {
+        fields.push("tag_limits");
+    }
+    fields
+}
+
+fn changed_fields(old: &Note, new: &Note) -> Vec<&'static str> {
+    let mut fields = Vec::new();
+    if old.title != new.title {
+        fields.push("title");
+    }
+    if old.body != new.body {
+        fields.push("body");
+    }
+    if old.tags != new.tags {
+        fields.push("tags");
+    }
+    if old.due != new.due {
+        fields.push("due");
+    }
+    if old.due_precision != new.due_precision {
+        fields.push("due_precision");
+    }
+    if old.priority != new.priority {
+        fields.push("priority");
+    }
+    if old.attachments != new.attachments {
+        fields.push("attachments");
+    }
+    if old.waiting_on != new.waiting_on {
+        fields.push("waiting_on");
+    }
+    fields
+}
+
+fn column_of<'a>(board: &'a Board, note_id: &str) -> Option<&'a str> {
+    board
+        .find_note_column_index(note_id)
+        .map(|idx| board.columns[idx].id.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Column, Priority};
+
+    fn note(id: &str, title: &str) -> Note {
+        Note::new(id.to_string(), title.to_string(), None, Vec::new(), None)
+    }
+
+    #[test]
+    fn identical_boards_have_no_diff() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", "one"), "todo").unwrap();
+        let copy = board.clone();
+        assert!(diff_boards(&board, &copy).is_empty());
+    }
+
+    #[test]
+    fn reports_added_removed_and_changed() {
+        let mut current = Board::default_named("test");
+        current.add_note(note("keep", "same"), "todo").unwrap();
+        current.add_note(note("edit", "before"), "todo").unwrap();
+        current.add_note(note("gone", "bye"), "todo").unwrap();
+
+        let mut incoming = current.clone();
+        incoming.remove_note("gone").unwrap();
+        incoming.add_note(note("new", "hi"), "doing").unwrap();
+        incoming
+            .update_note("edit", |n| n.title = "after".into())
+            .unwrap();
+        incoming.move_note("edit", "done").unwrap();
+        incoming.columns.retain(|c| c.id != "waiting");
+        incoming.columns.push(Column::new("review"));
+
+        let diff = diff_boards(&current, &incoming);
+        assert_eq!(diff.added_columns, vec!["review"]);
+        assert_eq!(diff.removed_columns, vec!["waiting"]);
+        assert_eq!(diff.added_notes, vec!["new"]);
+        assert_eq!(diff.removed_notes, vec!["gone"]);
+        assert_eq!(
+            diff.changed_notes,
+            vec![("edit".to_string(), vec!["title", "column"])]
+        );
+        assert!(diff.changed_columns.is_empty());
+    }
+
+    #[test]
+    fn reports_column_metadata_order_and_settings() {
+        let mut current = Board::default_named("test");
+        current.add_note(note("a", "one"), "todo").unwrap();
+        current.add_note(note("b", "two"), "todo").unwrap();
+
+        let mut incoming = current.clone();
+        incoming.columns[1].wip_limit = Some(1);
+        incoming.columns[0].note_ids.reverse();
+        incoming.columns.swap(2, 3);
+        incoming.settings.timezone = Some("utc".into());
+        incoming
+            .update_note("a", |n| n.priority = Some(Priority::High))
+            .unwrap();
+
+        let diff = diff_boards(&current, &incoming);
+        assert_eq!(
+            diff.changed_columns,
+            vec![
+                ("todo".to_string(), vec!["order"]),
+                ("doing".to_string(), vec!["wip_limit"]),
+            ]
+        );
+        assert!(diff.columns_reordered);
+        assert_eq!(diff.changed_settings, vec!["timezone"]);
+        assert_eq!(
+            diff.changed_notes,
+            vec![("a".to_string(), vec!["priority"])]
+        );
+    }
+}
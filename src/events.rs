@@ -0,0 +1,55 @@
+use crate::storage::BoardLocation;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Bump when the shape of logged events changes incompatibly.
+pub const EVENT_LOG_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+pub struct LogEvent {
+    pub version: u32,
+    pub at: DateTime<Utc>,
+    pub note_id: String,
+    #[serde(flatten)]
+    pub op: LogOp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum LogOp {
+    Add { column: String, title: String },
+    Move { from: String, to: String },
+    Edit { title: String },
+    Delete { column: String, title: String },
+}
+
+impl LogEvent {
+    pub fn new(note_id: impl Into<String>, op: LogOp) -> Self {
+        LogEvent {
+            version: EVENT_LOG_VERSION,
+            at: Utc::now(),
+            note_id: note_id.into(),
+            op,
+        }
+    }
+}
+
+pub fn event_log_path(location: &BoardLocation) -> PathBuf {
+    location.path.with_file_name("events.jsonl")
+}
+
+pub fn append_event(location: &BoardLocation, event: &LogEvent) -> Result<()> {
+    let path = event_log_path(location);
+    let line = serde_json::to_string(event).context("serializing event")?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("opening {:?}", path))?;
+    writeln!(file, "{}", line).with_context(|| format!("writing {:?}", path))?;
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use crate::diff::BoardDiff;
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// Stages and commits the board file at `path` with `message` when it sits in
+/// a git work tree; outside one this does nothing. Other staged changes are
+/// left out of the commit.
+pub fn autocommit(path: &Path, message: &str) -> Result<()> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let file = path.file_name().context("board path has no file name")?;
+    let inside = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .context("running git")?;
+    if !inside.status.success() {
+        return Ok(());
+    }
+    git(dir, &["add", "--"], file)?;
+    git(dir, &["commit", "-q", "-m", message, "--only", "--"], file)
+}
+
+fn git(dir: &Path, args: &[&str], file: &std::ffi::OsStr) -> Result<()> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .arg(file)
+        .output()
+        .context("running git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// The branch checked out in the repo that contains `dir`.
+pub fn current_branch(dir: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["symbolic-ref", "--short", "-q", "HEAD"])
+        .output()
+        .context("running git")?;
+    match output.status.code() {
+        Some(0) => {}
+        Some(1) => bail!("HEAD is detached, so there is no branch"),
+        _ => bail!("{}", String::from_utf8_lossy(&output.stderr).trim()),
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if branch.is_empty() {
+        bail!("git reported no branch");
+    }
+    Ok(branch)
+}
+
+/// A commit subject for a save, e.g. `postit: add note abc123` or
+/// `postit: move 2 notes, edit note abc123`. `None` means the board is new.
+pub fn commit_message(diff: Option<&BoardDiff>) -> String {
+    let Some(diff) = diff else {
+        return "postit: create board".into();
+    };
+    let (moved, edited): (Vec<_>, Vec<_>) = diff
+        .changed_notes
+        .iter()
+        .partition(|(_, fields)| fields.contains(&"column"));
+    let moved: Vec<_> = moved.into_iter().map(|(id, _)| id.clone()).collect();
+    let edited: Vec<_> = edited.into_iter().map(|(id, _)| id.clone()).collect();
+    let changed_columns: Vec<_> = diff
+        .changed_columns
+        .iter()
+        .map(|(id, _)| id.clone())
+        .collect();
+    let mut parts: Vec<String> = [
+        ("add", "column", &diff.added_columns),
+        ("remove", "column", &diff.removed_columns),
+        ("edit", "column", &changed_columns),
+        ("add", "note", &diff.added_notes),
+        ("delete", "note", &diff.removed_notes),
+        ("move", "note", &moved),
+        ("edit", "note", &edited),
+    ]
+    .into_iter()
+    .filter_map(|(verb, noun, ids)| match ids.as_slice() {
+        [] => None,
+        [id] => Some(format!("{} {} {}", verb, noun, id)),
+        ids => Some(format!("{} {} {}s", verb, ids.len(), noun)),
+    })
+    .collect();
+    if diff.columns_reordered {
+        parts.push("reorder columns".into());
+    }
+    if !diff.changed_settings.is_empty() {
+        parts.push("edit settings".into());
+    }
+    if parts.is_empty() {
+        "postit: update board".into()
+    } else {
+        format!("postit: {}", parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_message_names_single_changes_and_counts_the_rest() {
+        assert_eq!(commit_message(None), "postit: create board");
+        assert_eq!(
+            commit_message(Some(&BoardDiff::default())),
+            "postit: update board"
+        );
+        let diff = BoardDiff {
+            added_notes: vec!["abc123".into()],
+            changed_notes: vec![
+                ("a".into(), vec!["column"]),
+                ("b".into(), vec!["title", "column"]),
+                ("c".into(), vec!["tags"]),
+            ],
+            ..BoardDiff::default()
+        };
+        assert_eq!(
+            commit_message(Some(&diff)),
+            "postit: add note abc123, move 2 notes, edit note c"
+        );
+        let diff = BoardDiff {
+            changed_columns: vec![("doing".into(), vec!["wip_limit"])],
+            changed_settings: vec!["timezone"],
+            ..BoardDiff::default()
+        };
+        assert_eq!(
+            commit_message(Some(&diff)),
+            "postit: edit column doing, edit settings"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::config::{Config, IdAlphabet};
+use rand::Rng;
+
+/// Shortest id handed out, whatever `id_length` says.
+pub const MIN_ID_LENGTH: usize = 3;
+
+/// Every character an id may use.
+const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Alphanumerics minus the look-alikes `0`, `O`, `o`, `1`, `I`, and `l`.
+const UNAMBIGUOUS: &[u8] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz";
+
+impl IdAlphabet {
+    fn chars(self) -> &'static [u8] {
+        match self {
+            IdAlphabet::Alphanumeric => ALPHANUMERIC,
+            IdAlphabet::Unambiguous => UNAMBIGUOUS,
+        }
+    }
+}
+
+/// A random note id of the configured length and alphabet that `taken`
+/// does not already claim.
+pub fn generate_id(config: &Config, taken: impl Fn(&str) -> bool) -> String {
+    let chars = config.id_alphabet.chars();
+    let length = config.id_length.max(MIN_ID_LENGTH);
+    let mut rng = rand::thread_rng();
+    loop {
+        let id: String = (0..length)
+            .map(|_| chars[rng.gen_range(0..chars.len())] as char)
+            .collect();
+        if !taken(&id) {
+            return id;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn ids_follow_the_configured_length_and_alphabet() {
+        let config = Config {
+            id_length: 8,
+            id_alphabet: IdAlphabet::Unambiguous,
+            ..Config::default()
+        };
+        for _ in 0..200 {
+            let id = generate_id(&config, |_| false);
+            assert_eq!(id.len(), 8);
+            assert!(id.bytes().all(|b| UNAMBIGUOUS.contains(&b)), "{}", id);
+        }
+        let short = Config {
+            id_length: 1,
+            ..Config::default()
+        };
+        let id = generate_id(&short, |_| false);
+        assert_eq!(id.len(), MIN_ID_LENGTH);
+        assert!(id.bytes().all(|b| b.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn taken_ids_are_drawn_again() {
+        let tries = Cell::new(0);
+        let id = generate_id(&Config::default(), |_| {
+            tries.set(tries.get() + 1);
+            tries.get() < 3
+        });
+        assert_eq!(tries.get(), 3);
+        assert_eq!(id.len(), 6);
+    }
+}
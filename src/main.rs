@@ -1,38 +1,198 @@
 mod cli;
 mod commands;
+mod config;
+mod date;
+mod diff;
+mod events;
+mod git;
+mod ids;
 mod model;
+mod stats;
 mod storage;
 mod ui;
 
 use anyhow::Result;
 use clap::Parser;
+use model::BoardError;
+use std::process::ExitCode;
+use storage::MissingBoard;
 
-fn main() -> Result<()> {
+/// Exit codes besides 0 and clap's 2 for usage errors; see the README.
+const EXIT_FAILURE: u8 = 1;
+const EXIT_NOT_FOUND: u8 = 3;
+const EXIT_NO_BOARD: u8 = 4;
+const EXIT_CONFLICT: u8 = 5;
+const EXIT_WIP_LIMIT: u8 = 6;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            ExitCode::from(exit_code(&err))
+        }
+    }
+}
+
+/// The exit code for the first recognized error in the chain.
+fn exit_code(err: &anyhow::Error) -> u8 {
+    for cause in err.chain() {
+        if let Some(err) = cause.downcast_ref::<BoardError>() {
+            return match err {
+                BoardError::ColumnNotFound(_)
+                | BoardError::NoteNotFound(_)
+                | BoardError::NoteLocationMissing(_)
+                | BoardError::ItemOutOfRange { .. } => EXIT_NOT_FOUND,
+                BoardError::ColumnExists(_)
+                | BoardError::MergeIntoSelf(_)
+                | BoardError::TagLimitReached { .. }
+                | BoardError::NoteHasBody(_)
+                | BoardError::DemoteIntoSelf(_) => EXIT_CONFLICT,
+                BoardError::WipLimitReached { .. } => EXIT_WIP_LIMIT,
+            };
+        }
+        if cause.is::<MissingBoard>() {
+            return EXIT_NO_BOARD;
+        }
+    }
+    EXIT_FAILURE
+}
+
+fn run() -> Result<()> {
     let args = cli::Cli::parse();
+    let dry_run = args.dry_run;
     let command = args.command.unwrap_or(cli::Command::Tui);
     match command {
-        cli::Command::Init { name } => commands::init(name),
-        cli::Command::List { column } => commands::list(column),
+        cli::Command::Init { name, force } => commands::init(name, force, dry_run),
+        cli::Command::List {
+            column,
+            due_after,
+            due_before,
+            since,
+            sort,
+            overdue,
+            group_by,
+        } => commands::list(
+            column,
+            due_after,
+            due_before,
+            since,
+            sort,
+            overdue,
+            group_by == Some(cli::ListGroup::Tag),
+        ),
+        cli::Command::Which => commands::which(),
+        cli::Command::Info => commands::info(),
+        cli::Command::Stats { json, snapshot } => commands::stats(json, snapshot, dry_run),
+        cli::Command::Finished { since, json } => commands::finished(since, json),
+        cli::Command::Settings { key, value, unset } => {
+            commands::settings(key, value, unset, dry_run)
+        }
+        cli::Command::Search { query, regex } => commands::search(query, regex),
+        cli::Command::Show { note_id, history } => commands::show(note_id, history),
         cli::Command::Add {
             title,
             body,
             tags,
             column,
             due,
-        } => commands::add(title, body, tags, column, due),
-        cli::Command::Move { note_id, column_id } => commands::move_note(note_id, column_id),
+            attachments,
+            top,
+            branch_tag,
+        } => commands::add(
+            title,
+            body,
+            tags,
+            column,
+            due,
+            attachments,
+            top,
+            branch_tag,
+            dry_run,
+        ),
+        cli::Command::Q { title } => commands::quick(title, dry_run),
+        cli::Command::Move {
+            note_id,
+            column_id,
+            create_column,
+        } => commands::move_note(note_id, column_id, create_column, dry_run),
+        cli::Command::MoveTag { tag, column_id } => commands::move_tag(tag, column_id, dry_run),
         cli::Command::Edit {
             note_id,
             title,
             body,
+            append_body,
             tags,
             clear_tags,
             column,
             due,
             clear_due,
+            due_shift,
+            from_now,
+            attach,
+            detach,
+            waiting_on,
+            clear_waiting_on,
         } => commands::edit(
-            note_id, title, body, tags, clear_tags, column, due, clear_due,
+            note_id,
+            title,
+            body,
+            append_body,
+            tags,
+            clear_tags,
+            column,
+            due,
+            clear_due,
+            due_shift,
+            from_now,
+            attach,
+            detach,
+            waiting_on,
+            clear_waiting_on,
+            dry_run,
         ),
+        cli::Command::Copy {
+            note_id,
+            to,
+            column,
+        } => {
+            let scope = match to {
+                cli::BoardTarget::Project => storage::BoardScope::Project,
+                cli::BoardTarget::Global => storage::BoardScope::Global,
+            };
+            commands::copy_note(note_id, scope, column, dry_run)
+        }
+        cli::Command::Delete { note_id } => commands::delete(note_id, dry_run),
+        cli::Command::Merge { keep, absorb } => commands::merge(keep, absorb, dry_run),
+        cli::Command::Import { file, yes } => commands::import(file, yes, dry_run),
+        cli::Command::Normalize { yes } => commands::normalize(yes, dry_run),
+        cli::Command::Restore { timestamp, yes } => commands::restore(timestamp, yes, dry_run),
+        cli::Command::Column { action } => match action {
+            cli::ColumnCommand::SetColor { column_id, color } => {
+                commands::set_column_color(column_id, color, dry_run)
+            }
+            cli::ColumnCommand::SetIcon { column_id, icon } => {
+                commands::set_column_icon(column_id, icon, dry_run)
+            }
+            cli::ColumnCommand::SetInbox { column_id } => commands::set_inbox(column_id, dry_run),
+            cli::ColumnCommand::Describe {
+                column_id,
+                description,
+            } => commands::describe_column(column_id, description, dry_run),
+        },
+        cli::Command::Touch { note_id } => commands::touch(note_id, dry_run),
         cli::Command::Tui => commands::tui(),
+        cli::Command::Open {
+            note_id,
+            column,
+            view,
+        } => {
+            let view = view.map(|view| match view {
+                cli::StartView::Board => ui::ViewMode::Board,
+                cli::StartView::Timeline => ui::ViewMode::Timeline,
+                cli::StartView::Project => ui::ViewMode::Project,
+            });
+            commands::open(note_id, column, view)
+        }
     }
 }
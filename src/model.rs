@@ -1,23 +1,189 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
 
 pub type NoteId = String;
 
+/// Column whose notes count as completed.
+pub const DONE_COLUMN: &str = "done";
+
+/// Column for notes blocked on someone or something else.
+pub const WAITING_COLUMN: &str = "waiting";
+
+/// Pseudo column id used for notes that no column references.
+pub const UNFILED: &str = "unfiled";
+
+/// Oldest history entries are dropped once a note records more than this.
+pub const MAX_HISTORY: usize = 50;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Board {
     pub name: String,
     pub columns: Vec<Column>,
-    pub notes: HashMap<NoteId, Note>,
+    /// Sorted by id, so saving an unchanged board writes the same bytes.
+    pub notes: BTreeMap<NoteId, Note>,
+    #[serde(default, skip_serializing_if = "BoardSettings::is_empty")]
+    pub settings: BoardSettings,
+    #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: Extra,
+}
+
+/// Fields this version doesn't know, e.g. ones added by a newer postit. They
+/// are kept and written back unchanged instead of being dropped on save.
+pub type Extra = BTreeMap<String, serde_yaml::Value>;
+
+/// Preferences stored with the board; they win over the global config.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BoardSettings {
+    /// Column that counts as finished (default `done`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub done_column: Option<String>,
+    /// Column for new notes when none is given, ahead of the inbox.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_column: Option<String>,
+    /// Zone for entering and showing due dates: `utc`, `local`, or `+hh:mm`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Caps on how many notes with a tag one column may hold.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tag_limits: Vec<TagLimit>,
+}
+
+/// The view, column, and note selected when the TUI last quit.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FocusPointer {
+    pub view: String,
+    pub column: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<NoteId>,
+}
+
+/// At most `max` notes tagged `tag` in column `column`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TagLimit {
+    pub tag: String,
+    pub column: String,
+    pub max: usize,
+}
+
+impl BoardSettings {
+    pub const KEYS: [&'static str; 3] = ["done_column", "default_column", "timezone"];
+
+    pub fn is_empty(&self) -> bool {
+        *self == BoardSettings::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Option<String>> {
+        match key {
+            "done_column" => Some(&self.done_column),
+            "default_column" => Some(&self.default_column),
+            "timezone" => Some(&self.timezone),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Option<String>> {
+        match key {
+            "done_column" => Some(&mut self.done_column),
+            "default_column" => Some(&mut self.default_column),
+            "timezone" => Some(&mut self.timezone),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Column {
     pub id: String,
+    /// Human-friendly title; the id is shown when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     pub note_ids: Vec<NoteId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Short emoji or glyph drawn before the name in board titles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Display order for the column; `None` keeps the manual (insertion) order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortKey>,
+    /// Capture column: new notes land here when no column is given.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_inbox: bool,
+    /// What the column is for; shown on request, never used for logic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Most notes the column should hold; the board title shows a gauge of it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wip_limit: Option<usize>,
+    #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Title,
+    Due,
+    Created,
+    Updated,
+}
+
+impl SortKey {
+    /// Next sort in the TUI cycle; manual order (`None`) follows the last key.
+    pub fn cycle(current: Option<SortKey>) -> Option<SortKey> {
+        match current {
+            None => Some(SortKey::Title),
+            Some(SortKey::Title) => Some(SortKey::Due),
+            Some(SortKey::Due) => Some(SortKey::Created),
+            Some(SortKey::Created) => Some(SortKey::Updated),
+            Some(SortKey::Updated) => None,
+        }
+    }
+
+    /// Orders two notes the same way `Board::note_ids_sorted_by` does.
+    pub fn compare(self, a: &Note, b: &Note) -> std::cmp::Ordering {
+        match self {
+            SortKey::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            SortKey::Due => (a.due.is_none(), a.due).cmp(&(b.due.is_none(), b.due)),
+            SortKey::Created => a.created_at.cmp(&b.created_at),
+            SortKey::Updated => b.updated_at.cmp(&a.updated_at),
+        }
+        .then_with(|| a.sequence_key().cmp(&b.sequence_key()))
+    }
+}
+
+impl std::str::FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "title" => Ok(SortKey::Title),
+            "due" => Ok(SortKey::Due),
+            "created" => Ok(SortKey::Created),
+            "updated" => Ok(SortKey::Updated),
+            other => Err(format!(
+                "unknown sort {:?} (expected title, due, created, or updated)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            SortKey::Title => "title",
+            SortKey::Due => "due",
+            SortKey::Created => "created",
+            SortKey::Updated => "updated",
+        };
+        f.write_str(label)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Note {
     pub id: NoteId,
     pub title: String,
@@ -26,6 +192,110 @@ pub struct Note {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub due: Option<DateTime<Utc>>,
+    /// Whether `due` was given as a day or a time of day.
+    #[serde(default, skip_serializing_if = "DuePrecision::is_date_time")]
+    pub due_precision: DuePrecision,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<NoteEvent>,
+    /// Related files or URLs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
+    /// Position in the board's creation sequence; breaks ties between notes
+    /// with equal timestamps. Zero for notes saved before it existed.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub order: u64,
+    /// What the note is blocked on, e.g. "review from Sam".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra: Extra,
+}
+
+/// A due date entered as a bare day shows as one; its stored time of day
+/// only decides when it counts as due.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DuePrecision {
+    Date,
+    #[default]
+    DateTime,
+}
+
+impl DuePrecision {
+    fn is_date_time(&self) -> bool {
+        *self == DuePrecision::DateTime
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    const LEVELS: [Option<Priority>; 4] = [
+        None,
+        Some(Priority::Low),
+        Some(Priority::Medium),
+        Some(Priority::High),
+    ];
+
+    /// The level above (or below) `current`, wrapping between high and none.
+    pub fn step(current: Option<Priority>, up: bool) -> Option<Priority> {
+        let idx = Self::LEVELS
+            .iter()
+            .position(|level| *level == current)
+            .unwrap_or(0);
+        let len = Self::LEVELS.len();
+        Self::LEVELS[if up { idx + 1 } else { idx + len - 1 } % len]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+        }
+    }
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NoteEvent {
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub kind: NoteEventKind,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum NoteEventKind {
+    Created,
+    Moved {
+        from: String,
+        to: String,
+    },
+    Edited,
+    DueChanged {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+}
+
+/// Outcome of moving several notes at once; per-note failures don't abort the batch.
+#[derive(Debug, Default)]
+pub struct BulkMove {
+    pub moved: Vec<(NoteId, String)>,
+    pub unchanged: Vec<NoteId>,
+    pub failed: Vec<(NoteId, BoardError)>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -36,6 +306,29 @@ pub enum BoardError {
     NoteNotFound(String),
     #[error("note {0} not present in any column")]
     NoteLocationMissing(String),
+    #[error("column already exists: {0}")]
+    ColumnExists(String),
+    #[error("cannot merge note {0} into itself")]
+    MergeIntoSelf(String),
+    #[error("note {note} has no checklist item {index} (it has {count})")]
+    ItemOutOfRange {
+        note: String,
+        /// 1-based, as shown to the user.
+        index: usize,
+        count: usize,
+    },
+    #[error("note {0} has a body; only notes without one can become checklist items")]
+    NoteHasBody(String),
+    #[error("cannot make note {0} a checklist item of itself")]
+    DemoteIntoSelf(String),
+    #[error("column {column} already holds {max} note(s) tagged {tag}")]
+    TagLimitReached {
+        tag: String,
+        column: String,
+        max: usize,
+    },
+    #[error("column {column} is at its WIP limit of {limit}")]
+    WipLimitReached { column: String, limit: usize },
 }
 
 impl Board {
@@ -43,24 +336,14 @@ impl Board {
         Board {
             name: name.into(),
             columns: vec![
-                Column {
-                    id: "todo".into(),
-                    note_ids: Vec::new(),
-                },
-                Column {
-                    id: "doing".into(),
-                    note_ids: Vec::new(),
-                },
-                Column {
-                    id: "waiting".into(),
-                    note_ids: Vec::new(),
-                },
-                Column {
-                    id: "done".into(),
-                    note_ids: Vec::new(),
-                },
+                Column::new("todo"),
+                Column::new("doing"),
+                Column::new(WAITING_COLUMN),
+                Column::new(DONE_COLUMN),
             ],
-            notes: HashMap::new(),
+            notes: BTreeMap::new(),
+            settings: BoardSettings::default(),
+            extra: Extra::new(),
         }
     }
 
@@ -68,22 +351,342 @@ impl Board {
         self.columns.iter().position(|c| c.id == id)
     }
 
+    /// Nearest column id by edit distance, for "did you mean" hints. Ids that
+    /// differ in more than half their characters are not suggested.
+    pub fn closest_column(&self, id: &str) -> Option<&str> {
+        let id = id.to_lowercase();
+        self.columns
+            .iter()
+            .map(|c| (levenshtein(&id, &c.id.to_lowercase()), c.id.as_str()))
+            .filter(|(distance, candidate)| {
+                *distance * 2 <= id.chars().count().max(candidate.chars().count())
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// Unknown fields kept from the file, as `board.x`, `column.x`, or `note.x`.
+    pub fn unknown_fields(&self) -> Vec<String> {
+        let mut fields = BTreeSet::new();
+        fields.extend(self.extra.keys().map(|key| format!("board.{}", key)));
+        for column in &self.columns {
+            fields.extend(column.extra.keys().map(|key| format!("column.{}", key)));
+        }
+        for note in self.notes.values() {
+            fields.extend(note.extra.keys().map(|key| format!("note.{}", key)));
+        }
+        fields.into_iter().collect()
+    }
+
     pub fn find_note_column_index(&self, note_id: &str) -> Option<usize> {
         self.columns
             .iter()
             .position(|c| c.note_ids.iter().any(|id| id == note_id))
     }
 
+    /// The note's `waiting_on` text when it has left the waiting column
+    /// (`from`) but still carries it.
+    pub fn stale_waiting_on(&self, note_id: &str, from: &str) -> Option<&str> {
+        let column = self.find_note_column_index(note_id)?;
+        if from != WAITING_COLUMN || self.columns[column].id == WAITING_COLUMN {
+            return None;
+        }
+        self.notes.get(note_id)?.waiting_on.as_deref()
+    }
+
+    pub fn inbox_column_index(&self) -> Option<usize> {
+        self.columns.iter().position(|c| c.is_inbox)
+    }
+
+    /// Notes still to be worked on, in board order: column by column (skipping
+    /// done), each in its display order.
+    pub fn actionable_note_ids(&self) -> Vec<&str> {
+        (0..self.columns.len())
+            .filter(|idx| self.columns[*idx].id != self.done_column_id())
+            .flat_map(|idx| self.ordered_note_ids(idx))
+            .collect()
+    }
+
+    /// The column that counts as finished: the board's `done_column` setting,
+    /// else `done`.
+    pub fn done_column_id(&self) -> &str {
+        self.settings.done_column.as_deref().unwrap_or(DONE_COLUMN)
+    }
+
+    /// Where notes go when no column is specified: the board's `default_column`
+    /// setting if that column exists, then the inbox, then the first column.
+    pub fn default_column_id(&self) -> Option<&str> {
+        let configured = self
+            .settings
+            .default_column
+            .as_deref()
+            .and_then(|id| self.find_column_index(id));
+        configured
+            .or(self.inbox_column_index())
+            .or(if self.columns.is_empty() {
+                None
+            } else {
+                Some(0)
+            })
+            .map(|idx| self.columns[idx].id.as_str())
+    }
+
+    /// Flags a single column as the inbox (`None` clears it); at most one column is the inbox.
+    pub fn set_inbox(&mut self, column_id: Option<&str>) -> Result<(), BoardError> {
+        if let Some(id) = column_id {
+            if self.find_column_index(id).is_none() {
+                return Err(BoardError::ColumnNotFound(id.to_string()));
+            }
+        }
+        for column in &mut self.columns {
+            column.is_inbox = Some(column.id.as_str()) == column_id;
+        }
+        Ok(())
+    }
+
+    /// Appends a new empty column; `name` defaults to the title-cased id.
+    pub fn add_column(
+        &mut self,
+        id: impl Into<String>,
+        name: Option<String>,
+    ) -> Result<(), BoardError> {
+        self.insert_column(self.columns.len(), id, name)
+    }
+
+    /// Adds an empty column at `index` (clamped to the end of the board).
+    pub fn insert_column(
+        &mut self,
+        index: usize,
+        id: impl Into<String>,
+        name: Option<String>,
+    ) -> Result<(), BoardError> {
+        let id = id.into();
+        if self.find_column_index(&id).is_some() {
+            return Err(BoardError::ColumnExists(id));
+        }
+        let mut column = Column::new(id);
+        column.name = Some(name.unwrap_or_else(|| title_case(&column.id)));
+        self.columns.insert(index.min(self.columns.len()), column);
+        Ok(())
+    }
+
+    pub fn set_column_color(
+        &mut self,
+        column_id: &str,
+        color: Option<String>,
+    ) -> Result<(), BoardError> {
+        let idx = self
+            .find_column_index(column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
+        self.columns[idx].color = color;
+        Ok(())
+    }
+
+    /// Shifts a column `delta` places, stopping at either end of the board.
+    /// Returns the column's new index.
+    pub fn reorder_column(&mut self, column_id: &str, delta: isize) -> Result<usize, BoardError> {
+        let idx = self
+            .find_column_index(column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
+        let target = idx.saturating_add_signed(delta).min(self.columns.len() - 1);
+        let column = self.columns.remove(idx);
+        self.columns.insert(target, column);
+        Ok(target)
+    }
+
+    pub fn set_column_icon(
+        &mut self,
+        column_id: &str,
+        icon: Option<String>,
+    ) -> Result<(), BoardError> {
+        let idx = self
+            .find_column_index(column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
+        self.columns[idx].icon = icon;
+        Ok(())
+    }
+
+    pub fn set_column_description(
+        &mut self,
+        column_id: &str,
+        description: Option<String>,
+    ) -> Result<(), BoardError> {
+        let idx = self
+            .find_column_index(column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
+        self.columns[idx].description = description;
+        Ok(())
+    }
+
+    pub fn set_column_sort(
+        &mut self,
+        column_id: &str,
+        sort: Option<SortKey>,
+    ) -> Result<(), BoardError> {
+        let idx = self
+            .find_column_index(column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
+        self.columns[idx].sort = sort;
+        Ok(())
+    }
+
+    /// Note ids of a column in display order, applying the column's sort if set.
+    /// Due sorts put undated notes last; updated sorts show the most recent first.
+    pub fn ordered_note_ids(&self, col_idx: usize) -> Vec<&str> {
+        let sort = self.columns.get(col_idx).and_then(|c| c.sort);
+        self.note_ids_sorted_by(col_idx, sort)
+    }
+
+    /// Like `ordered_note_ids`, but with an explicit sort instead of the column's own.
+    pub fn note_ids_sorted_by(&self, col_idx: usize, sort: Option<SortKey>) -> Vec<&str> {
+        let Some(column) = self.columns.get(col_idx) else {
+            return Vec::new();
+        };
+        let mut ids: Vec<&str> = column.note_ids.iter().map(String::as_str).collect();
+        let Some(sort) = sort else {
+            return ids;
+        };
+        let notes = &self.notes;
+        match sort {
+            SortKey::Title => {
+                ids.sort_by_cached_key(|id| notes.get(*id).map(|n| n.title.to_lowercase()))
+            }
+            SortKey::Due => ids.sort_by_key(|id| {
+                let due = notes.get(*id).and_then(|n| n.due);
+                (due.is_none(), due)
+            }),
+            SortKey::Created => {
+                ids.sort_by_key(|id| notes.get(*id).map(|n| (n.created_at, n.sequence_key())))
+            }
+            SortKey::Updated => ids.sort_by_key(|id| {
+                notes
+                    .get(*id)
+                    .map(|n| (std::cmp::Reverse(n.updated_at), n.sequence_key()))
+            }),
+        }
+        ids
+    }
+
+    fn placed_note_count(&self) -> usize {
+        self.columns.iter().map(|c| c.note_ids.len()).sum()
+    }
+
+    /// Fraction of placed notes sitting in the done column (0.0 for an empty board).
+    pub fn completion_ratio(&self) -> f64 {
+        let total = self.placed_note_count();
+        if total == 0 {
+            return 0.0;
+        }
+        let done = self
+            .columns
+            .iter()
+            .filter(|c| c.id == self.done_column_id())
+            .map(|c| c.note_ids.len())
+            .sum::<usize>();
+        done as f64 / total as f64
+    }
+
+    /// Each column's share of the placed notes, in column order (all 0.0 for
+    /// an empty board).
+    pub fn column_ratios(&self) -> Vec<(String, f64)> {
+        let total = self.placed_note_count();
+        self.columns
+            .iter()
+            .map(|c| {
+                let ratio = if total == 0 {
+                    0.0
+                } else {
+                    c.note_ids.len() as f64 / total as f64
+                };
+                (c.id.clone(), ratio)
+            })
+            .collect()
+    }
+
     pub fn add_note(&mut self, note: Note, column_id: &str) -> Result<(), BoardError> {
+        self.insert_note(note, column_id, false)
+    }
+
+    /// Adds a note as the first in its column. A sorted column still shows it
+    /// in sort order.
+    pub fn add_note_on_top(&mut self, note: Note, column_id: &str) -> Result<(), BoardError> {
+        self.insert_note(note, column_id, true)
+    }
+
+    fn insert_note(
+        &mut self,
+        mut note: Note,
+        column_id: &str,
+        top: bool,
+    ) -> Result<(), BoardError> {
         let target_idx = self
             .find_column_index(column_id)
             .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
-        self.notes.insert(note.id.clone(), note.clone());
-        self.columns[target_idx].note_ids.push(note.id.clone());
+        self.check_wip_limit(target_idx)?;
+        self.check_tag_limits(&note, column_id)?;
+        note.record(NoteEventKind::Created);
+        note.order = self.notes.values().map(|n| n.order).max().unwrap_or(0) + 1;
+        let index = if top {
+            0
+        } else {
+            self.insert_index(target_idx, &note)
+        };
+        self.columns[target_idx]
+            .note_ids
+            .insert(index, note.id.clone());
+        self.notes.insert(note.id.clone(), note);
         Ok(())
     }
 
+    /// Where a new note goes in a column: before the first note that sorts after
+    /// it when the column has a sort, otherwise at the end.
+    fn insert_index(&self, col_idx: usize, note: &Note) -> usize {
+        let column = &self.columns[col_idx];
+        let Some(sort) = column.sort else {
+            return column.note_ids.len();
+        };
+        column
+            .note_ids
+            .iter()
+            .position(|id| {
+                self.notes
+                    .get(id)
+                    .is_some_and(|other| sort.compare(note, other).is_lt())
+            })
+            .unwrap_or(column.note_ids.len())
+    }
+
     pub fn move_note(&mut self, note_id: &str, dest_column_id: &str) -> Result<(), BoardError> {
+        self.relocate_note(note_id, dest_column_id, None)
+    }
+
+    /// Moves a note to `index` in the destination column's manual order
+    /// (clamped to its length), e.g. to put it back exactly where it was.
+    pub fn move_note_to(
+        &mut self,
+        note_id: &str,
+        dest_column_id: &str,
+        index: usize,
+    ) -> Result<(), BoardError> {
+        self.relocate_note(note_id, dest_column_id, Some(index))
+    }
+
+    /// The column index of a note and its index in that column's manual order.
+    pub fn note_position(&self, note_id: &str) -> Option<(usize, usize)> {
+        self.columns.iter().enumerate().find_map(|(col_idx, col)| {
+            col.note_ids
+                .iter()
+                .position(|id| id == note_id)
+                .map(|idx| (col_idx, idx))
+        })
+    }
+
+    fn relocate_note(
+        &mut self,
+        note_id: &str,
+        dest_column_id: &str,
+        index: Option<usize>,
+    ) -> Result<(), BoardError> {
         if !self.notes.contains_key(note_id) {
             return Err(BoardError::NoteNotFound(note_id.to_string()));
         }
@@ -94,15 +697,354 @@ impl Board {
             Some(idx) => idx,
             None => return Err(BoardError::NoteLocationMissing(note_id.to_string())),
         };
-        if src_idx == dest_idx {
+        if src_idx == dest_idx && index.is_none() {
             return Ok(());
         }
+        if src_idx != dest_idx {
+            self.check_wip_limit(dest_idx)?;
+            self.check_tag_limits(&self.notes[note_id], dest_column_id)?;
+        }
+        let from = self.columns[src_idx].id.clone();
         self.columns[src_idx].note_ids.retain(|id| id != note_id);
-        self.columns[dest_idx].note_ids.push(note_id.to_string());
+        let dest = &mut self.columns[dest_idx].note_ids;
+        let index = index.unwrap_or(dest.len()).min(dest.len());
+        dest.insert(index, note_id.to_string());
+        if src_idx == dest_idx {
+            return self.touch(note_id);
+        }
         self.touch(note_id)?;
+        if let Some(note) = self.notes.get_mut(note_id) {
+            note.record(NoteEventKind::Moved {
+                from,
+                to: dest_column_id.to_string(),
+            });
+        }
         Ok(())
     }
 
+    /// The first tag limit that `note` would break by joining `column_id`;
+    /// the note itself is not counted if it is already there.
+    pub fn tag_limit_reached(&self, note: &Note, column_id: &str) -> Option<&TagLimit> {
+        let column = self.columns.iter().find(|c| c.id == column_id)?;
+        self.settings.tag_limits.iter().find(|limit| {
+            limit.column == column_id
+                && note.tags.contains(&limit.tag)
+                && column
+                    .note_ids
+                    .iter()
+                    .filter(|id| **id != note.id)
+                    .filter_map(|id| self.notes.get(id))
+                    .filter(|other| other.tags.contains(&limit.tag))
+                    .count()
+                    >= limit.max
+        })
+    }
+
+    /// Refuses another note in the column at `col_idx` once it is at its WIP limit.
+    fn check_wip_limit(&self, col_idx: usize) -> Result<(), BoardError> {
+        let column = &self.columns[col_idx];
+        match column.wip_limit {
+            Some(limit) if column.at_wip_limit() => Err(BoardError::WipLimitReached {
+                column: column.id.clone(),
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_tag_limits(&self, note: &Note, column_id: &str) -> Result<(), BoardError> {
+        match self.tag_limit_reached(note, column_id) {
+            Some(limit) => Err(BoardError::TagLimitReached {
+                tag: limit.tag.clone(),
+                column: limit.column.clone(),
+                max: limit.max,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Sends a note to the done column, or back out of it to the column it came
+    /// from (per its history), falling back to the first column if that is gone.
+    /// Returns the `(from, to)` column ids.
+    pub fn toggle_done(&mut self, note_id: &str) -> Result<(String, String), BoardError> {
+        let note = self
+            .notes
+            .get(note_id)
+            .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        let src_idx = self
+            .find_note_column_index(note_id)
+            .ok_or_else(|| BoardError::NoteLocationMissing(note_id.to_string()))?;
+        let from = self.columns[src_idx].id.clone();
+        let done = self.done_column_id();
+        let to = if from == done {
+            let previous = note
+                .history
+                .iter()
+                .rev()
+                .find_map(|event| match &event.kind {
+                    NoteEventKind::Moved { from, to } if to == done => Some(from.as_str()),
+                    _ => None,
+                });
+            match previous.filter(|id| *id != done && self.find_column_index(id).is_some()) {
+                Some(id) => id.to_string(),
+                None => self
+                    .columns
+                    .iter()
+                    .find(|c| c.id != done)
+                    .map(|c| c.id.clone())
+                    .ok_or_else(|| BoardError::ColumnNotFound(format!("(any besides {})", done)))?,
+            }
+        } else {
+            done.to_string()
+        };
+        self.move_note(note_id, &to)?;
+        Ok((from, to))
+    }
+
+    /// Ids of notes that exist but sit in no column, sorted for stable display.
+    pub fn orphan_notes(&self) -> Vec<&str> {
+        let placed: HashSet<&str> = self
+            .columns
+            .iter()
+            .flat_map(|c| c.note_ids.iter().map(String::as_str))
+            .collect();
+        let mut orphans: Vec<&str> = self
+            .notes
+            .keys()
+            .map(String::as_str)
+            .filter(|id| !placed.contains(id))
+            .collect();
+        orphans.sort_unstable();
+        orphans
+    }
+
+    /// Puts an unfiled note into a column; placed notes are simply moved.
+    pub fn file_note(&mut self, note_id: &str, column_id: &str) -> Result<(), BoardError> {
+        if self.find_note_column_index(note_id).is_some() {
+            return self.move_note(note_id, column_id);
+        }
+        let dest_idx = self
+            .find_column_index(column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(column_id.to_string()))?;
+        if let Some(note) = self.notes.get(note_id) {
+            self.check_wip_limit(dest_idx)?;
+            self.check_tag_limits(note, column_id)?;
+        }
+        let note = self
+            .notes
+            .get_mut(note_id)
+            .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        note.updated_at = Utc::now();
+        note.record(NoteEventKind::Moved {
+            from: UNFILED.to_string(),
+            to: column_id.to_string(),
+        });
+        self.columns[dest_idx].note_ids.push(note_id.to_string());
+        Ok(())
+    }
+
+    pub fn remove_note(&mut self, note_id: &str) -> Result<Note, BoardError> {
+        let note = self
+            .notes
+            .remove(note_id)
+            .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        for column in &mut self.columns {
+            column.note_ids.retain(|id| id != note_id);
+        }
+        Ok(note)
+    }
+
+    /// Folds `absorb` into `keep` (tags unioned, bodies joined, earliest created,
+    /// latest due) and deletes `absorb`. `keep` stays where it is.
+    pub fn merge_notes(&mut self, keep: &str, absorb: &str) -> Result<Note, BoardError> {
+        if keep == absorb {
+            return Err(BoardError::MergeIntoSelf(keep.to_string()));
+        }
+        if !self.notes.contains_key(keep) {
+            return Err(BoardError::NoteNotFound(keep.to_string()));
+        }
+        let absorbed = self.remove_note(absorb)?;
+        self.update_note(keep, |note| {
+            for tag in &absorbed.tags {
+                if !note.tags.contains(tag) {
+                    note.tags.push(tag.clone());
+                }
+            }
+            note.body = match (note.body.take(), absorbed.body.clone()) {
+                (Some(ours), Some(theirs)) => Some(format!("{}\n---\n{}", ours, theirs)),
+                (ours, theirs) => ours.or(theirs),
+            };
+            note.created_at = note.created_at.min(absorbed.created_at);
+            if absorbed.due > note.due {
+                note.due = absorbed.due;
+                note.due_precision = absorbed.due_precision;
+            }
+        })?;
+        Ok(self.notes[keep].clone())
+    }
+
+    /// Turns checklist item `index` (0-based, see [`checklist_items`]) of
+    /// `note_id` into note `new_id` in the same column, just below it. The new
+    /// note gets the parent's tags and a body pointing back at it, and the
+    /// item's line leaves the parent's body. Returns the new note.
+    pub fn promote_item(
+        &mut self,
+        note_id: &str,
+        index: usize,
+        new_id: NoteId,
+    ) -> Result<Note, BoardError> {
+        let parent = self
+            .notes
+            .get(note_id)
+            .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        let col_idx = self
+            .find_note_column_index(note_id)
+            .ok_or_else(|| BoardError::NoteLocationMissing(note_id.to_string()))?;
+        let body = parent.body.clone().unwrap_or_default();
+        let items = checklist_lines(&body);
+        let (line, item) = items
+            .get(index)
+            .cloned()
+            .ok_or(BoardError::ItemOutOfRange {
+                note: note_id.to_string(),
+                index: index + 1,
+                count: items.len(),
+            })?;
+        let child = Note::new(
+            new_id.clone(),
+            item,
+            Some(parent_reference(note_id, &parent.title)),
+            parent.tags.clone(),
+            None,
+        );
+        let column_id = self.columns[col_idx].id.clone();
+        self.add_note(child, &column_id)?;
+        let column = &mut self.columns[col_idx];
+        if column.sort.is_none() {
+            column.note_ids.retain(|id| *id != new_id);
+            let after = column.note_ids.iter().position(|id| id == note_id);
+            let at = after.map_or(column.note_ids.len(), |idx| idx + 1);
+            column.note_ids.insert(at, new_id.clone());
+        }
+        let rest: Vec<&str> = body
+            .lines()
+            .enumerate()
+            .filter(|(idx, _)| *idx != line)
+            .map(|(_, text)| text)
+            .collect();
+        let rest = rest.join("\n");
+        self.update_note(note_id, |note| {
+            note.body = Some(rest.clone()).filter(|text| !text.trim().is_empty());
+        })?;
+        Ok(self.notes[&new_id].clone())
+    }
+
+    /// Folds `note_id` into `parent_id` as an unchecked checklist item with its
+    /// title, deleting the note. Only notes without a body (apart from a
+    /// pointer back to that parent, as left by [`Board::promote_item`]) can
+    /// be folded, so nothing is lost. Returns the updated parent.
+    pub fn demote_note(&mut self, note_id: &str, parent_id: &str) -> Result<Note, BoardError> {
+        if note_id == parent_id {
+            return Err(BoardError::DemoteIntoSelf(note_id.to_string()));
+        }
+        let note = self
+            .notes
+            .get(note_id)
+            .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        let parent = self
+            .notes
+            .get(parent_id)
+            .ok_or_else(|| BoardError::NoteNotFound(parent_id.to_string()))?;
+        let reference = parent_reference(parent_id, &parent.title);
+        let body = note.body.as_deref().map(str::trim).unwrap_or_default();
+        if !body.is_empty() && body != reference {
+            return Err(BoardError::NoteHasBody(note_id.to_string()));
+        }
+        let item = format!("- [ ] {}", note.title.trim());
+        self.remove_note(note_id)?;
+        self.update_note(parent_id, |parent| {
+            parent.body = Some(match parent.body.take().filter(|b| !b.trim().is_empty()) {
+                Some(body) => format!("{}\n{}", body.trim_end(), item),
+                None => item.clone(),
+            });
+        })?;
+        Ok(self.notes[parent_id].clone())
+    }
+
+    /// Whether `id` names a note or is still referenced by a column.
+    pub fn id_in_use(&self, id: &str) -> bool {
+        self.notes.contains_key(id)
+            || self
+                .columns
+                .iter()
+                .any(|c| c.note_ids.iter().any(|n| n == id))
+    }
+
+    /// Ids of notes whose title matches `title` ignoring case and surrounding whitespace, sorted.
+    pub fn notes_with_title(&self, title: &str) -> Vec<NoteId> {
+        let wanted = title.trim().to_lowercase();
+        let mut ids: Vec<NoteId> = self
+            .notes
+            .values()
+            .filter(|n| n.title.trim().to_lowercase() == wanted)
+            .map(|n| n.id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Ids of notes carrying `tag`, in board order, with unplaced notes last.
+    pub fn note_ids_with_tag(&self, tag: &str) -> Vec<NoteId> {
+        let has_tag = |id: &NoteId| {
+            self.notes
+                .get(id)
+                .map(|n| n.tags.iter().any(|t| t == tag))
+                .unwrap_or(false)
+        };
+        let mut ids: Vec<NoteId> = self
+            .columns
+            .iter()
+            .flat_map(|c| c.note_ids.iter())
+            .filter(|id| has_tag(id))
+            .cloned()
+            .collect();
+        let mut unplaced: Vec<NoteId> = self
+            .notes
+            .keys()
+            .filter(|id| has_tag(id) && !ids.contains(id))
+            .cloned()
+            .collect();
+        unplaced.sort();
+        ids.extend(unplaced);
+        ids
+    }
+
+    pub fn move_notes_with_tag(
+        &mut self,
+        tag: &str,
+        dest_column_id: &str,
+    ) -> Result<BulkMove, BoardError> {
+        let dest_idx = self
+            .find_column_index(dest_column_id)
+            .ok_or_else(|| BoardError::ColumnNotFound(dest_column_id.to_string()))?;
+        let mut report = BulkMove::default();
+        for id in self.note_ids_with_tag(tag) {
+            let src_idx = self.find_note_column_index(&id);
+            if src_idx == Some(dest_idx) {
+                report.unchanged.push(id);
+                continue;
+            }
+            let from = src_idx
+                .map(|idx| self.columns[idx].id.clone())
+                .unwrap_or_default();
+            match self.move_note(&id, dest_column_id) {
+                Ok(()) => report.moved.push((id, from)),
+                Err(err) => report.failed.push((id, err)),
+            }
+        }
+        Ok(report)
+    }
+
     pub fn update_note<F>(&mut self, note_id: &str, mut f: F) -> Result<(), BoardError>
     where
         F: FnMut(&mut Note),
@@ -111,16 +1053,96 @@ impl Board {
             .notes
             .get_mut(note_id)
             .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        let before = note.clone();
         f(note);
+        if *note == before {
+            return Ok(());
+        }
+        let mut expected = before.clone();
+        expected.due = note.due;
+        if *note != expected {
+            note.record(NoteEventKind::Edited);
+        }
+        if note.due != before.due {
+            note.record(NoteEventKind::DueChanged {
+                from: before.due,
+                to: note.due,
+            });
+        }
         note.updated_at = Utc::now();
         Ok(())
     }
 
-    fn touch(&mut self, note_id: &str) -> Result<(), BoardError> {
-        self.update_note(note_id, |_| {})
+    /// Bumps `updated_at` without changing anything else.
+    pub fn touch(&mut self, note_id: &str) -> Result<(), BoardError> {
+        let note = self
+            .notes
+            .get_mut(note_id)
+            .ok_or_else(|| BoardError::NoteNotFound(note_id.to_string()))?;
+        note.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+impl Column {
+    pub fn new(id: impl Into<String>) -> Self {
+        Column {
+            id: id.into(),
+            name: None,
+            note_ids: Vec::new(),
+            color: None,
+            icon: None,
+            sort: None,
+            is_inbox: false,
+            description: None,
+            wip_limit: None,
+            extra: Extra::new(),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Whether the column holds as many notes as its WIP limit allows; a
+    /// limit of 0 means none.
+    pub fn at_wip_limit(&self) -> bool {
+        self.wip_limit
+            .is_some_and(|limit| limit > 0 && self.note_ids.len() >= limit)
     }
 }
 
+/// Number of single-character insertions, deletions, or substitutions
+/// needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            row[j + 1] = substitute.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+/// `in-review` / `in_review` -> `In Review`.
+fn title_case(id: &str) -> String {
+    id.split(['-', '_', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
 impl Note {
     pub fn new(
         id: NoteId,
@@ -138,6 +1160,869 @@ impl Note {
             created_at: now,
             updated_at: now,
             due,
+            due_precision: DuePrecision::DateTime,
+            history: Vec::new(),
+            attachments: Vec::new(),
+            order: 0,
+            waiting_on: None,
+            priority: None,
+            extra: Extra::new(),
         }
     }
+
+    /// Whether `is_match` accepts the title, the body, or any tag.
+    pub fn matches(&self, is_match: impl Fn(&str) -> bool) -> bool {
+        is_match(&self.title)
+            || self.body.as_deref().is_some_and(&is_match)
+            || self.tags.iter().any(|t| is_match(t))
+    }
+
+    /// Whether the note has a due date that has already passed at `now`.
+    /// Whether it sits in the done column is up to the caller.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.due.is_some_and(|due| due < now)
+    }
+
+    /// A fresh copy of this note under `id`, e.g. for another board; its
+    /// history and timestamps start over.
+    pub fn copy_as(&self, id: NoteId) -> Note {
+        let mut copy = Note::new(
+            id,
+            self.title.clone(),
+            self.body.clone(),
+            self.tags.clone(),
+            self.due,
+        );
+        copy.attachments = self.attachments.clone();
+        copy.waiting_on = self.waiting_on.clone();
+        copy.priority = self.priority;
+        copy.due_precision = self.due_precision;
+        copy
+    }
+
+    /// One new note per checklist item in the body (see [`checklist_items`]),
+    /// carrying over this note's tags; ids come from `next_id`.
+    pub fn split_items(&self, mut next_id: impl FnMut() -> NoteId) -> Vec<Note> {
+        checklist_items(self.body.as_deref().unwrap_or_default())
+            .into_iter()
+            .map(|item| Note::new(next_id(), item, None, self.tags.clone(), None))
+            .collect()
+    }
+
+    /// Last-resort sort key for notes whose other keys tie: creation
+    /// sequence, then id, so equal timestamps still order the same every run.
+    pub fn sequence_key(&self) -> (u64, &str) {
+        (self.order, &self.id)
+    }
+
+    fn record(&mut self, kind: NoteEventKind) {
+        self.history.push(NoteEvent {
+            at: Utc::now(),
+            kind,
+        });
+        if self.history.len() > MAX_HISTORY {
+            let excess = self.history.len() - MAX_HISTORY;
+            self.history.drain(..excess);
+        }
+    }
+}
+
+/// The candidate after `current`, wrapping to the first; the first when
+/// `current` is not a candidate.
+pub fn next_in_cycle<'a>(candidates: &[&'a str], current: Option<&str>) -> Option<&'a str> {
+    let start = current
+        .and_then(|id| candidates.iter().position(|c| *c == id))
+        .map_or(0, |idx| idx + 1);
+    candidates.get(start % candidates.len().max(1)).copied()
+}
+
+/// Label for notes without tags when grouping by tag.
+pub const UNTAGGED: &str = "(untagged)";
+
+/// Groups items under each of their note's tags, sorted by tag, so a note with
+/// several tags appears in several groups; untagged notes go under [`UNTAGGED`].
+/// Items keep their given order within a group.
+pub fn bucket_by_tag<'a, T: Clone>(
+    items: impl IntoIterator<Item = (T, &'a Note)>,
+) -> BTreeMap<String, Vec<(T, &'a Note)>> {
+    let mut buckets: BTreeMap<String, Vec<(T, &'a Note)>> = BTreeMap::new();
+    for (key, note) in items {
+        if note.tags.is_empty() {
+            buckets
+                .entry(UNTAGGED.to_string())
+                .or_default()
+                .push((key, note));
+        } else {
+            for tag in &note.tags {
+                buckets
+                    .entry(tag.clone())
+                    .or_default()
+                    .push((key.clone(), note));
+            }
+        }
+    }
+    buckets
+}
+
+/// Text of each `-`/`*` bullet or `- [ ]`/`- [x]` checklist line in `body`;
+/// other lines and empty items are skipped.
+pub fn checklist_items(body: &str) -> Vec<String> {
+    checklist_lines(body)
+        .into_iter()
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Body of a note promoted from a checklist item, pointing at its parent.
+fn parent_reference(parent_id: &str, title: &str) -> String {
+    format!("From {}: {}", parent_id, title)
+}
+
+/// Like [`checklist_items`], with each item's line number in `body`.
+fn checklist_lines(body: &str) -> Vec<(usize, String)> {
+    body.lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let line = line.trim_start();
+            let item = line.strip_prefix('-').or_else(|| line.strip_prefix('*'))?;
+            let item = item.trim_start();
+            let item = ["[ ]", "[x]", "[X]"]
+                .iter()
+                .find_map(|mark| item.strip_prefix(mark))
+                .unwrap_or(item)
+                .trim();
+            (!item.is_empty()).then(|| (idx, item.to_string()))
+        })
+        .collect()
+}
+
+/// `note` with its title trimmed, trailing spaces and surrounding blank lines
+/// cut from the body (an empty body becomes none), and its tags trimmed,
+/// optionally lowercased, and stripped of empties and repeats.
+pub fn normalized_note(note: &Note, lowercase_tags: bool) -> Note {
+    let mut clean = note.clone();
+    let title = note.title.trim();
+    if !title.is_empty() {
+        clean.title = title.to_string();
+    }
+    clean.body = note.body.as_deref().and_then(|body| {
+        let lines: Vec<&str> = body.lines().map(str::trim_end).collect();
+        let text = lines.join("\n");
+        let text = text.trim_matches('\n');
+        (!text.trim().is_empty()).then(|| text.to_string())
+    });
+    let mut seen = HashSet::new();
+    clean.tags = note
+        .tags
+        .iter()
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| {
+            if lowercase_tags {
+                tag.to_lowercase()
+            } else {
+                tag.to_string()
+            }
+        })
+        .filter(|tag| seen.insert(tag.clone()))
+        .collect();
+    clean
+}
+
+/// True for local attachment paths that don't exist; URLs are never flagged.
+pub fn attachment_missing(target: &str) -> bool {
+    !target.contains("://") && !std::path::Path::new(target).exists()
+}
+
+impl fmt::Display for NoteEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NoteEventKind::Created => write!(f, "created"),
+            NoteEventKind::Moved { from, to } => write!(f, "moved {} -> {}", from, to),
+            NoteEventKind::Edited => write!(f, "edited"),
+            NoteEventKind::DueChanged { to: Some(due), .. } => {
+                write!(f, "due set to {}", due.format("%Y.%m.%d@%H:%M"))
+            }
+            NoteEventKind::DueChanged { to: None, .. } => write!(f, "due cleared"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn note(id: &str, tags: &[&str]) -> Note {
+        Note::new(
+            id.to_string(),
+            format!("note {}", id),
+            None,
+            tags.iter().map(|t| t.to_string()).collect(),
+            None,
+        )
+    }
+
+    #[test]
+    fn column_sort_orders_by_due_with_undated_last() {
+        let mut board = Board::default_named("test");
+        for (id, day) in [("a", None), ("b", Some(3)), ("c", Some(1))] {
+            let mut n = note(id, &[]);
+            n.due = day.map(|d| Utc.with_ymd_and_hms(2024, 1, d, 0, 0, 0).unwrap());
+            board.add_note(n, "todo").unwrap();
+        }
+        assert_eq!(board.ordered_note_ids(0), vec!["a", "b", "c"]);
+
+        board.set_column_sort("todo", Some(SortKey::Due)).unwrap();
+        assert_eq!(board.ordered_note_ids(0), vec!["c", "b", "a"]);
+        assert_eq!(board.columns[0].note_ids, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn add_note_inserts_in_sorted_position() {
+        let mut board = Board::default_named("test");
+        board.set_column_sort("todo", Some(SortKey::Due)).unwrap();
+        for (id, day) in [("a", None), ("b", Some(3)), ("c", Some(1)), ("d", Some(2))] {
+            let mut n = note(id, &[]);
+            n.due = day.map(|d| Utc.with_ymd_and_hms(2024, 1, d, 0, 0, 0).unwrap());
+            board.add_note(n, "todo").unwrap();
+        }
+        assert_eq!(board.columns[0].note_ids, vec!["c", "d", "b", "a"]);
+        assert_eq!(board.ordered_note_ids(0), vec!["c", "d", "b", "a"]);
+
+        board
+            .set_column_sort("doing", Some(SortKey::Title))
+            .unwrap();
+        for id in ["x", "W", "y"] {
+            board.add_note(note(id, &[]), "doing").unwrap();
+        }
+        assert_eq!(board.columns[1].note_ids, vec!["W", "x", "y"]);
+
+        for id in ["q", "p"] {
+            board.add_note(note(id, &[]), "waiting").unwrap();
+        }
+        assert_eq!(board.columns[2].note_ids, vec!["q", "p"]);
+    }
+
+    #[test]
+    fn add_note_on_top_puts_the_note_first() {
+        let mut board = Board::default_named("test");
+        for id in ["a", "b"] {
+            board.add_note(note(id, &[]), "todo").unwrap();
+        }
+        board.add_note_on_top(note("c", &[]), "todo").unwrap();
+        board.add_note(note("d", &[]), "todo").unwrap();
+        assert_eq!(board.columns[0].note_ids, vec!["c", "a", "b", "d"]);
+        assert_eq!(board.ordered_note_ids(0), vec!["c", "a", "b", "d"]);
+        assert!(matches!(
+            board.add_note_on_top(note("e", &[]), "nope"),
+            Err(BoardError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn notes_with_title_ignores_case_and_padding() {
+        let mut board = Board::default_named("test");
+        let mut a = note("a", &[]);
+        a.title = "Buy milk".into();
+        let mut b = note("b", &[]);
+        b.title = "  buy MILK ".into();
+        board.add_note(b, "todo").unwrap();
+        board.add_note(a, "doing").unwrap();
+        board.add_note(note("c", &[]), "todo").unwrap();
+
+        assert_eq!(board.notes_with_title("BUY milk"), vec!["a", "b"]);
+        assert!(board.notes_with_title("buy").is_empty());
+    }
+
+    #[test]
+    fn merge_notes_combines_fields_and_drops_absorbed() {
+        let mut board = Board::default_named("test");
+        let day = |d| Some(Utc.with_ymd_and_hms(2024, 1, d, 0, 0, 0).unwrap());
+        let mut keep = note("keep", &["a", "b"]);
+        keep.body = Some("first".into());
+        keep.due = day(2);
+        let mut absorb = note("absorb", &["b", "c"]);
+        absorb.body = Some("second".into());
+        absorb.due = day(5);
+        absorb.created_at = keep.created_at - chrono::Duration::days(1);
+        let earliest = absorb.created_at;
+        board.add_note(keep, "doing").unwrap();
+        board.add_note(absorb, "todo").unwrap();
+
+        let merged = board.merge_notes("keep", "absorb").unwrap();
+        assert_eq!(merged.tags, vec!["a", "b", "c"]);
+        assert_eq!(merged.body.as_deref(), Some("first\n---\nsecond"));
+        assert_eq!(merged.created_at, earliest);
+        assert_eq!(merged.due, day(5));
+        assert!(!board.notes.contains_key("absorb"));
+        assert!(board.columns[0].note_ids.is_empty());
+        assert_eq!(board.columns[1].note_ids, vec!["keep"]);
+
+        assert!(matches!(
+            board.merge_notes("keep", "keep"),
+            Err(BoardError::MergeIntoSelf(_))
+        ));
+        assert!(board.merge_notes("keep", "absorb").is_err());
+    }
+
+    #[test]
+    fn toggle_done_returns_note_to_previous_column() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &[]), "todo").unwrap();
+        board.move_note("a", "waiting").unwrap();
+
+        assert_eq!(
+            board.toggle_done("a").unwrap(),
+            ("waiting".to_string(), "done".to_string())
+        );
+        assert_eq!(
+            board.toggle_done("a").unwrap(),
+            ("done".to_string(), "waiting".to_string())
+        );
+
+        board.toggle_done("a").unwrap();
+        board.columns.retain(|c| c.id != "waiting");
+        assert_eq!(board.toggle_done("a").unwrap().1, "todo");
+
+        board.add_note(note("b", &[]), "done").unwrap();
+        assert_eq!(board.toggle_done("b").unwrap().1, "todo");
+    }
+
+    #[test]
+    fn column_sort_defaults_to_manual_for_old_boards() {
+        let column: Column = serde_yaml::from_str("id: todo\nnote_ids: []\n").unwrap();
+        assert_eq!(column.sort, None);
+    }
+
+    #[test]
+    fn orphan_notes_lists_notes_missing_from_columns() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &[]), "todo").unwrap();
+        board.notes.insert("c".into(), note("c", &[]));
+        board.notes.insert("b".into(), note("b", &[]));
+        assert_eq!(board.orphan_notes(), vec!["b", "c"]);
+
+        board.file_note("b", "doing").unwrap();
+        assert_eq!(board.orphan_notes(), vec!["c"]);
+        assert_eq!(board.columns[1].note_ids, vec!["b"]);
+        assert!(matches!(
+            board.file_note("c", "nope"),
+            Err(BoardError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn inbox_becomes_default_column() {
+        let mut board = Board::default_named("test");
+        assert_eq!(board.default_column_id(), Some("todo"));
+
+        board.set_inbox(Some("waiting")).unwrap();
+        board.set_inbox(Some("doing")).unwrap();
+        assert_eq!(board.default_column_id(), Some("doing"));
+        assert_eq!(board.columns.iter().filter(|c| c.is_inbox).count(), 1);
+
+        assert!(board.set_inbox(Some("nope")).is_err());
+        board.set_inbox(None).unwrap();
+        assert_eq!(board.default_column_id(), Some("todo"));
+    }
+
+    #[test]
+    fn completion_ratio_handles_empty_board() {
+        let board = Board::default_named("test");
+        assert_eq!(board.completion_ratio(), 0.0);
+        assert!(board.column_ratios().iter().all(|(_, r)| *r == 0.0));
+    }
+
+    #[test]
+    fn completion_and_column_ratios() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &[]), "todo").unwrap();
+        board.add_note(note("b", &[]), "doing").unwrap();
+        board.add_note(note("c", &[]), "done").unwrap();
+        board.add_note(note("d", &[]), "done").unwrap();
+
+        assert_eq!(board.completion_ratio(), 0.5);
+        let ratios: Vec<f64> = board.column_ratios().into_iter().map(|(_, r)| r).collect();
+        assert_eq!(ratios, [0.25, 0.25, 0.0, 0.5]);
+        assert_eq!(board.column_ratios()[3].0, "done");
+
+        board.notes.insert("e".into(), note("e", &[]));
+        assert_eq!(board.column_ratios()[3].1, 0.5);
+    }
+
+    #[test]
+    fn move_notes_with_tag_moves_only_matching_notes() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &["sprint"]), "todo").unwrap();
+        board.add_note(note("b", &["other"]), "todo").unwrap();
+        board
+            .add_note(note("c", &["other", "sprint"]), "doing")
+            .unwrap();
+        board.add_note(note("d", &["sprint"]), "done").unwrap();
+        board.add_note(note("e", &[]), "doing").unwrap();
+
+        let report = board.move_notes_with_tag("sprint", "done").unwrap();
+
+        let moved: Vec<&str> = report.moved.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(moved, vec!["a", "c"]);
+        assert_eq!(report.unchanged, vec!["d".to_string()]);
+        assert!(report.failed.is_empty());
+        assert_eq!(board.columns[3].note_ids, vec!["d", "a", "c"]);
+        assert_eq!(board.columns[0].note_ids, vec!["b"]);
+        assert_eq!(board.columns[1].note_ids, vec!["e"]);
+    }
+
+    #[test]
+    fn move_notes_with_tag_reports_failures_without_aborting() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &["sprint"]), "todo").unwrap();
+        board.notes.insert("z".into(), note("z", &["sprint"]));
+        board.add_note(note("b", &["sprint"]), "waiting").unwrap();
+
+        let report = board.move_notes_with_tag("sprint", "doing").unwrap();
+
+        assert_eq!(report.moved.len(), 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "z");
+        assert!(matches!(
+            report.failed[0].1,
+            BoardError::NoteLocationMissing(_)
+        ));
+    }
+
+    #[test]
+    fn move_notes_with_tag_rejects_unknown_column() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &["sprint"]), "todo").unwrap();
+        assert!(matches!(
+            board.move_notes_with_tag("sprint", "nope"),
+            Err(BoardError::ColumnNotFound(_))
+        ));
+        assert_eq!(board.columns[0].note_ids, vec!["a"]);
+    }
+
+    #[test]
+    fn insert_column_places_after_index_and_rejects_duplicates() {
+        let mut board = Board::default_named("test");
+        let count = board.columns.len();
+        board.insert_column(1, "review", None).unwrap();
+        assert_eq!(board.columns[1].id, "review");
+        assert_eq!(board.columns[1].name.as_deref(), Some("Review"));
+        assert!(matches!(
+            board.insert_column(0, "review", None),
+            Err(BoardError::ColumnExists(_))
+        ));
+        board.insert_column(99, "later", None).unwrap();
+        assert_eq!(board.columns.len(), count + 2);
+        assert_eq!(board.columns.last().unwrap().id, "later");
+    }
+
+    #[test]
+    fn closest_column_suggests_near_misses_only() {
+        let board = Board::default_named("test");
+        assert_eq!(levenshtein("doign", "doing"), 2);
+        assert_eq!(levenshtein("", "done"), 4);
+        assert_eq!(board.closest_column("doign"), Some("doing"));
+        assert_eq!(board.closest_column("TODO"), Some("todo"));
+        assert_eq!(board.closest_column("dne"), Some("done"));
+        assert_eq!(board.closest_column("backlog"), None);
+    }
+
+    #[test]
+    fn reorder_column_shifts_and_clamps() {
+        let mut board = Board::default_named("test");
+        let ids = |board: &Board| {
+            board
+                .columns
+                .iter()
+                .map(|c| c.id.clone())
+                .collect::<Vec<_>>()
+        };
+        let original = ids(&board);
+        assert_eq!(board.reorder_column(&original[0], 1).unwrap(), 1);
+        assert_eq!(ids(&board)[1], original[0]);
+        assert_eq!(ids(&board)[0], original[1]);
+        assert_eq!(board.reorder_column(&original[0], -5).unwrap(), 0);
+        assert_eq!(ids(&board), original);
+        let last = original.len() - 1;
+        assert_eq!(board.reorder_column(&original[last], 1).unwrap(), last);
+        assert!(matches!(
+            board.reorder_column("nope", 1),
+            Err(BoardError::ColumnNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn equal_timestamps_sort_by_creation_sequence() {
+        let mut board = Board::default_named("test");
+        let stamp = Utc::now();
+        for id in ["c", "a", "b"] {
+            let mut note = Note::new(id.into(), "same".into(), None, vec![], None);
+            note.created_at = stamp;
+            note.updated_at = stamp;
+            board.add_note(note, "todo").unwrap();
+        }
+        let orders: Vec<u64> = ["c", "a", "b"]
+            .iter()
+            .map(|id| board.notes[*id].order)
+            .collect();
+        assert_eq!(orders, vec![1, 2, 3]);
+        for note in board.notes.values_mut() {
+            note.created_at = stamp;
+            note.updated_at = stamp;
+        }
+        board.columns[0].note_ids.reverse();
+        for sort in [SortKey::Created, SortKey::Updated] {
+            assert_eq!(board.note_ids_sorted_by(0, Some(sort)), vec!["c", "a", "b"]);
+        }
+    }
+
+    #[test]
+    fn next_actionable_skips_done_and_wraps() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &[]), "todo").unwrap();
+        board.add_note(note("b", &[]), "done").unwrap();
+        board.add_note(note("c", &[]), "doing").unwrap();
+        let candidates = board.actionable_note_ids();
+        assert_eq!(candidates, vec!["a", "c"]);
+        assert_eq!(next_in_cycle(&candidates, None), Some("a"));
+        assert_eq!(next_in_cycle(&candidates, Some("a")), Some("c"));
+        assert_eq!(next_in_cycle(&candidates, Some("c")), Some("a"));
+        assert_eq!(next_in_cycle(&candidates, Some("b")), Some("a"));
+        assert_eq!(next_in_cycle(&[], Some("a")), None);
+    }
+
+    #[test]
+    fn board_settings_override_done_and_default_columns() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &[]), "todo").unwrap();
+        board.add_note(note("b", &[]), "waiting").unwrap();
+        assert_eq!(board.default_column_id(), Some("todo"));
+        assert_eq!(board.completion_ratio(), 0.0);
+
+        board.settings.done_column = Some("waiting".into());
+        board.settings.default_column = Some("doing".into());
+        assert_eq!(board.default_column_id(), Some("doing"));
+        assert_eq!(board.completion_ratio(), 0.5);
+        assert_eq!(board.actionable_note_ids(), vec!["a"]);
+        let (_, to) = board.toggle_done("a").unwrap();
+        assert_eq!(to, "waiting");
+
+        board.settings.default_column = Some("gone".into());
+        assert_eq!(board.default_column_id(), Some("todo"));
+    }
+
+    #[test]
+    fn boards_without_settings_load_and_save_unchanged() {
+        let yaml = "name: old\ncolumns: []\nnotes: {}\n";
+        let board: Board = serde_yaml::from_str(yaml).unwrap();
+        assert!(board.settings.is_empty());
+        assert_eq!(serde_yaml::to_string(&board).unwrap(), yaml);
+    }
+
+    #[test]
+    fn waiting_on_goes_stale_once_the_note_leaves_waiting() {
+        let mut board = Board::default_named("test");
+        board.add_note(note("a", &[]), WAITING_COLUMN).unwrap();
+        board
+            .update_note("a", |n| n.waiting_on = Some("review".into()))
+            .unwrap();
+        assert_eq!(board.stale_waiting_on("a", "todo"), None);
+        assert_eq!(board.stale_waiting_on("a", WAITING_COLUMN), None);
+        board.move_note("a", "doing").unwrap();
+        assert_eq!(board.stale_waiting_on("a", WAITING_COLUMN), Some("review"));
+        board.update_note("a", |n| n.waiting_on = None).unwrap();
+        assert_eq!(board.stale_waiting_on("a", WAITING_COLUMN), None);
+
+        let yaml = serde_yaml::to_string(&board).unwrap();
+        assert!(!yaml.contains("waiting_on"));
+    }
+
+    #[test]
+    fn unknown_fields_survive_a_load_and_save() {
+        let yaml = "\
+name: newer
+columns:
+- id: todo
+  note_ids:
+  - a
+  collapsed: true
+notes:
+  a:
+    id: a
+    title: task
+    body: null
+    tags: []
+    created_at: 2024-01-01T00:00:00Z
+    updated_at: 2024-01-01T00:00:00Z
+    due: null
+    effort: 3
+    checklist:
+    - done: true
+      text: step
+theme: dark
+";
+        let mut board: Board = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            board.unknown_fields(),
+            vec![
+                "board.theme",
+                "column.collapsed",
+                "note.checklist",
+                "note.effort"
+            ]
+        );
+        board
+            .update_note("a", |n| n.title = "renamed".into())
+            .unwrap();
+        let saved = serde_yaml::to_string(&board).unwrap();
+        let reloaded: Board = serde_yaml::from_str(&saved).unwrap();
+        assert_eq!(reloaded.notes["a"].title, "renamed");
+        assert_eq!(reloaded.notes["a"].extra, board.notes["a"].extra);
+        assert_eq!(
+            reloaded.columns[0].extra["collapsed"],
+            serde_yaml::Value::from(true)
+        );
+        assert_eq!(reloaded.extra["theme"], serde_yaml::Value::from("dark"));
+        assert!(saved.contains("effort: 3"), "{}", saved);
+    }
+
+    #[test]
+    fn priority_steps_through_levels_and_wraps() {
+        assert_eq!(Priority::step(None, true), Some(Priority::Low));
+        assert_eq!(
+            Priority::step(Some(Priority::Medium), true),
+            Some(Priority::High)
+        );
+        assert_eq!(Priority::step(Some(Priority::High), true), None);
+        assert_eq!(Priority::step(None, false), Some(Priority::High));
+        assert_eq!(Priority::step(Some(Priority::Low), false), None);
+    }
+
+    #[test]
+    fn saving_orders_notes_by_id_and_is_repeatable() {
+        // Notes listed out of order, as older versions could write them.
+        let yaml = "\
+name: old
+columns:
+- id: todo
+  note_ids: [b, c, a]
+notes:
+  c: {id: c, title: three, body: null, tags: [], created_at: 2024-01-01T00:00:00Z, updated_at: 2024-01-01T00:00:00Z, due: null}
+  a: {id: a, title: one, body: null, tags: [], created_at: 2024-01-01T00:00:00Z, updated_at: 2024-01-01T00:00:00Z, due: null}
+  b: {id: b, title: two, body: null, tags: [], created_at: 2024-01-01T00:00:00Z, updated_at: 2024-01-01T00:00:00Z, due: null}
+";
+        let board: Board = serde_yaml::from_str(yaml).unwrap();
+        let saved = serde_yaml::to_string(&board).unwrap();
+        let at = |id: &str| saved.find(&format!("\n  {}:\n", id)).unwrap();
+        assert!(at("a") < at("b") && at("b") < at("c"), "{}", saved);
+        assert_eq!(board.columns[0].note_ids, ["b", "c", "a"]);
+
+        let reloaded: Board = serde_yaml::from_str(&saved).unwrap();
+        assert_eq!(serde_yaml::to_string(&reloaded).unwrap(), saved);
+    }
+
+    #[test]
+    fn split_items_turns_checklist_lines_into_tagged_notes() {
+        let body = "Plan:\n- [ ] book venue\n  * send invites\n- [x] pick date\n-\nnotes after";
+        assert_eq!(
+            checklist_items(body),
+            vec!["book venue", "send invites", "pick date"]
+        );
+        let mut note = Note::new(
+            "big".into(),
+            "Party".into(),
+            Some(body.into()),
+            vec!["home".into()],
+            None,
+        );
+        note.waiting_on = Some("budget".into());
+        let mut n = 0;
+        let parts = note.split_items(|| {
+            n += 1;
+            format!("part{}", n)
+        });
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[2].id, "part3");
+        assert_eq!(parts[0].title, "book venue");
+        assert!(parts.iter().all(|p| p.tags == ["home"] && p.body.is_none()));
+        assert!(parts.iter().all(|p| p.waiting_on.is_none()));
+        note.body = Some("no bullets here".into());
+        assert!(note.split_items(|| unreachable!()).is_empty());
+    }
+
+    #[test]
+    fn checklist_items_promote_to_notes_and_back() {
+        let mut board = Board::default_named("test");
+        let mut parent = note("p", &["home"]);
+        parent.body = Some("Plan:\n- [ ] book venue\n- [x] pick date".into());
+        board.add_note(parent, "todo").unwrap();
+        board.add_note(note("q", &[]), "todo").unwrap();
+
+        assert!(matches!(
+            board.promote_item("p", 2, "c".into()),
+            Err(BoardError::ItemOutOfRange {
+                index: 3,
+                count: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            board.promote_item("nope", 0, "c".into()),
+            Err(BoardError::NoteNotFound(_))
+        ));
+        let child = board.promote_item("p", 1, "c".into()).unwrap();
+        assert_eq!(child.title, "pick date");
+        assert_eq!(child.tags, ["home"]);
+        assert_eq!(board.columns[0].note_ids, ["p", "c", "q"]);
+        assert_eq!(
+            board.notes["p"].body.as_deref(),
+            Some("Plan:\n- [ ] book venue")
+        );
+
+        assert!(matches!(
+            board.demote_note("c", "gone"),
+            Err(BoardError::NoteNotFound(id)) if id == "gone"
+        ));
+        assert!(matches!(
+            board.demote_note("c", "c"),
+            Err(BoardError::DemoteIntoSelf(_))
+        ));
+        assert!(matches!(
+            board.demote_note("c", "q"),
+            Err(BoardError::NoteHasBody(_))
+        ));
+        let parent = board.demote_note("c", "p").unwrap();
+        assert_eq!(
+            parent.body.as_deref(),
+            Some("Plan:\n- [ ] book venue\n- [ ] pick date")
+        );
+        assert!(!board.id_in_use("c"));
+    }
+
+    #[test]
+    fn normalized_note_cleans_text_and_tags() {
+        let mut messy = note("a", &[" Work", "work ", "", "UI", "  "]);
+        messy.title = "  Fix login \t".into();
+        messy.body = Some("\n  first  \nsecond\t\n\n".into());
+        let clean = normalized_note(&messy, true);
+        assert_eq!(clean.title, "Fix login");
+        assert_eq!(clean.body.as_deref(), Some("  first\nsecond"));
+        assert_eq!(clean.tags, vec!["work", "ui"]);
+        assert_eq!(
+            normalized_note(&messy, false).tags,
+            vec!["Work", "work", "UI"]
+        );
+        assert_eq!(normalized_note(&clean, true), clean);
+
+        messy.body = Some(" \n ".into());
+        assert_eq!(normalized_note(&messy, true).body, None);
+    }
+
+    #[test]
+    fn bucket_by_tag_lists_notes_under_every_tag() {
+        let note = |id: &str, tags: &[&str]| {
+            Note::new(
+                id.into(),
+                id.into(),
+                None,
+                tags.iter().map(|t| t.to_string()).collect(),
+                None,
+            )
+        };
+        let notes = [
+            note("a", &["web", "api"]),
+            note("b", &[]),
+            note("c", &["web"]),
+        ];
+        let buckets = bucket_by_tag(notes.iter().map(|n| (n.id.as_str(), n)));
+        let ids = |tag: &str| -> Vec<&str> { buckets[tag].iter().map(|(id, _)| *id).collect() };
+        assert_eq!(
+            buckets.keys().collect::<Vec<_>>(),
+            ["(untagged)", "api", "web"]
+        );
+        assert_eq!(ids("web"), ["a", "c"]);
+        assert_eq!(ids("api"), ["a"]);
+        assert_eq!(ids(UNTAGGED), ["b"]);
+    }
+
+    #[test]
+    fn move_note_to_restores_the_old_position() {
+        let mut board = Board::default_named("b");
+        for id in ["a", "b", "c"] {
+            board
+                .add_note(Note::new(id.into(), id.into(), None, vec![], None), "todo")
+                .unwrap();
+        }
+        let before = board.note_position("b").unwrap();
+        assert_eq!(before, (0, 1));
+        board.move_note("b", "doing").unwrap();
+        assert_eq!(board.note_position("b"), Some((1, 0)));
+        board.move_note_to("b", "todo", before.1).unwrap();
+        assert_eq!(board.columns[0].note_ids, ["a", "b", "c"]);
+        assert_eq!(board.notes["b"].history.len(), 3);
+        board.move_note_to("b", "todo", 99).unwrap();
+        assert_eq!(board.columns[0].note_ids, ["a", "c", "b"]);
+    }
+
+    #[test]
+    fn tag_limits_block_adds_and_moves_past_the_cap() {
+        let mut board = Board::default_named("test");
+        board.settings.tag_limits.push(TagLimit {
+            tag: "focus".into(),
+            column: "doing".into(),
+            max: 1,
+        });
+        board.add_note(note("a", &["focus"]), "doing").unwrap();
+        board.add_note(note("b", &["focus"]), "todo").unwrap();
+        board.add_note(note("c", &["other"]), "doing").unwrap();
+
+        assert_eq!(board.tag_limit_reached(&board.notes["a"], "doing"), None);
+        assert!(board
+            .tag_limit_reached(&board.notes["b"], "doing")
+            .is_some());
+        assert!(matches!(
+            board.move_note("b", "doing"),
+            Err(BoardError::TagLimitReached { max: 1, .. })
+        ));
+        assert!(board.columns[0].note_ids.contains(&"b".to_string()));
+        assert!(matches!(
+            board.add_note(note("d", &["focus"]), "doing"),
+            Err(BoardError::TagLimitReached { .. })
+        ));
+        assert!(!board.notes.contains_key("d"));
+        board.move_note_to("a", "doing", 1).unwrap();
+        board.move_note("a", "done").unwrap();
+        board.move_note("b", "doing").unwrap();
+    }
+
+    #[test]
+    fn wip_limits_block_adds_moves_and_filing_into_a_full_column() {
+        let mut board = Board::default_named("test");
+        board.columns[1].wip_limit = Some(1);
+        board.add_note(note("a", &[]), "doing").unwrap();
+        board.add_note(note("b", &[]), "todo").unwrap();
+
+        assert!(matches!(
+            board.move_note("b", "doing"),
+            Err(BoardError::WipLimitReached { limit: 1, .. })
+        ));
+        assert_eq!(board.columns[0].note_ids, ["b"]);
+        assert!(matches!(
+            board.add_note_on_top(note("c", &[]), "doing"),
+            Err(BoardError::WipLimitReached { .. })
+        ));
+        assert!(!board.notes.contains_key("c"));
+        board.notes.insert("d".into(), note("d", &[]));
+        assert!(matches!(
+            board.file_note("d", "doing"),
+            Err(BoardError::WipLimitReached { .. })
+        ));
+        // Reordering inside the full column is still fine.
+        board.move_note_to("a", "doing", 0).unwrap();
+
+        board.columns[1].wip_limit = Some(0);
+        board.move_note("b", "doing").unwrap();
+    }
 }
@@ -0,0 +1,214 @@
+use crate::date::{in_timezone, Zone};
+use crate::model::Board;
+use crate::storage::BoardLocation;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Bump when the shape of `BoardStats` changes incompatibly.
+pub const STATS_VERSION: u32 = 1;
+
+/// Board metrics as printed by `stats --json` and stored by `--snapshot`.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct BoardStats {
+    pub version: u32,
+    pub at: DateTime<Utc>,
+    pub board: String,
+    pub notes: usize,
+    pub done: usize,
+    /// Share of placed notes in the done column, 0.0 to 1.0.
+    pub completion: f64,
+    /// Notes past their due date outside the done column.
+    pub overdue: usize,
+    /// Notes due on today's date (in the display zone) outside the done column.
+    pub due_today: usize,
+    pub untagged: usize,
+    pub unfiled: usize,
+    pub columns: Vec<ColumnStats>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ColumnStats {
+    pub id: String,
+    pub notes: usize,
+}
+
+pub fn board_stats(board: &Board, now: DateTime<Utc>, zone: Zone) -> BoardStats {
+    let done_ids = board
+        .find_column_index(board.done_column_id())
+        .map(|idx| board.columns[idx].note_ids.as_slice())
+        .unwrap_or_default();
+    let today = in_timezone(&now, zone).date_naive();
+    BoardStats {
+        version: STATS_VERSION,
+        at: now,
+        board: board.name.clone(),
+        notes: board.notes.len(),
+        done: done_ids.len(),
+        completion: board.completion_ratio(),
+        overdue: board
+            .notes
+            .values()
+            .filter(|note| note.is_overdue(now))
+            .filter(|note| !done_ids.contains(&note.id))
+            .count(),
+        due_today: board
+            .notes
+            .values()
+            .filter(|note| {
+                note.due
+                    .is_some_and(|due| in_timezone(&due, zone).date_naive() == today)
+            })
+            .filter(|note| !done_ids.contains(&note.id))
+            .count(),
+        untagged: board.notes.values().filter(|n| n.tags.is_empty()).count(),
+        unfiled: board.orphan_notes().len(),
+        columns: board
+            .columns
+            .iter()
+            .map(|c| ColumnStats {
+                id: c.id.clone(),
+                notes: c.note_ids.len(),
+            })
+            .collect(),
+    }
+}
+
+/// Done-column notes changed since a time, as printed by `finished`.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct FinishedReport {
+    pub since: DateTime<Utc>,
+    /// The done column, or `None` when the board has none.
+    pub column: Option<String>,
+    pub notes: Vec<FinishedNote>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct FinishedNote {
+    pub id: String,
+    pub title: String,
+    pub tags: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Notes in the done column whose `updated_at` is at or after `since`,
+/// oldest change first.
+pub fn finished_since(board: &Board, since: DateTime<Utc>) -> FinishedReport {
+    let Some(idx) = board.find_column_index(board.done_column_id()) else {
+        return FinishedReport {
+            since,
+            column: None,
+            notes: Vec::new(),
+        };
+    };
+    let mut notes: Vec<FinishedNote> = board
+        .ordered_note_ids(idx)
+        .into_iter()
+        .filter_map(|id| board.notes.get(id))
+        .filter(|note| note.updated_at >= since)
+        .map(|note| FinishedNote {
+            id: note.id.clone(),
+            title: note.title.clone(),
+            tags: note.tags.clone(),
+            updated_at: note.updated_at,
+        })
+        .collect();
+    notes.sort_by_key(|note| note.updated_at);
+    FinishedReport {
+        since,
+        column: Some(board.columns[idx].id.clone()),
+        notes,
+    }
+}
+
+pub fn stats_log_path(location: &BoardLocation) -> PathBuf {
+    location.path.with_file_name("stats.jsonl")
+}
+
+pub fn append_snapshot(location: &BoardLocation, stats: &BoardStats) -> Result<()> {
+    let path = stats_log_path(location);
+    let line = serde_json::to_string(stats).context("serializing stats")?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("opening {:?}", path))?;
+    writeln!(file, "{}", line).with_context(|| format!("writing {:?}", path))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Note;
+    use chrono::Duration;
+
+    #[test]
+    fn counts_overdue_and_due_today_outside_done_only() {
+        let now = Utc::now();
+        let mut board = Board::default_named("test");
+        let late = Some(now - Duration::days(1));
+        board
+            .add_note(
+                Note::new("a".into(), "late".into(), None, vec![], late),
+                "todo",
+            )
+            .unwrap();
+        board
+            .add_note(
+                Note::new("b".into(), "shipped".into(), None, vec![], late),
+                "done",
+            )
+            .unwrap();
+        board
+            .add_note(
+                Note::new("c".into(), "open".into(), None, vec![], None),
+                "doing",
+            )
+            .unwrap();
+        board
+            .add_note(
+                Note::new("d".into(), "soon".into(), None, vec!["x".into()], Some(now)),
+                "doing",
+            )
+            .unwrap();
+
+        let stats = board_stats(&board, now, Zone::default());
+        assert_eq!(stats.notes, 4);
+        assert_eq!(stats.done, 1);
+        assert_eq!(stats.overdue, 1);
+        assert_eq!(stats.due_today, 1);
+        assert_eq!(stats.untagged, 3);
+        assert_eq!(stats.unfiled, 0);
+        let todo = stats.columns.iter().find(|c| c.id == "todo").unwrap();
+        assert_eq!(todo.notes, 1);
+
+        let json: serde_json::Value = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["version"], STATS_VERSION);
+        assert_eq!(json["columns"][0]["id"], "todo");
+    }
+
+    #[test]
+    fn finished_lists_recent_done_notes_only() {
+        let now = Utc::now();
+        let mut board = Board::default_named("test");
+        for (id, column, age) in [("a", "done", 2), ("b", "done", 30), ("c", "todo", 1)] {
+            let mut note = Note::new(id.into(), id.into(), None, vec![], None);
+            note.updated_at = now - Duration::hours(age);
+            board.add_note(note, column).unwrap();
+        }
+
+        let report = finished_since(&board, now - Duration::days(1));
+        assert_eq!(report.column.as_deref(), Some("done"));
+        let ids: Vec<&str> = report.notes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a"]);
+
+        board.settings.done_column = Some("shipped".into());
+        let report = finished_since(&board, now - Duration::days(1));
+        assert_eq!(report.column, None);
+        assert!(report.notes.is_empty());
+    }
+}
@@ -1,9 +1,23 @@
-use crate::model::Board;
+use crate::config::load_config;
+use crate::diff::{diff_boards, BoardDiff};
+use crate::git;
+use crate::model::{Board, FocusPointer};
 use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
 use directories::ProjectDirs;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// There is no board where a command needs one.
+#[derive(Debug, Error)]
+pub enum MissingBoard {
+    #[error("no board found at {}; run `postit init`", .0.display())]
+    At(PathBuf),
+    #[error("no project board found here; run `postit init` to create one")]
+    Project,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BoardScope {
@@ -11,37 +25,88 @@ pub enum BoardScope {
     Global,
 }
 
+impl BoardScope {
+    pub fn label(self) -> &'static str {
+        match self {
+            BoardScope::Project => "project",
+            BoardScope::Global => "global",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BoardLocation {
     pub path: PathBuf,
     pub scope: BoardScope,
 }
 
-pub fn init_project_board(name: Option<String>) -> Result<BoardLocation> {
+/// Older backups beyond this many are deleted after each save.
+const MAX_BACKUPS: usize = 20;
+
+/// An ordinary save skips its backup while the newest one is younger than
+/// this, so a burst of TUI edits doesn't push older backups out.
+const BACKUP_INTERVAL_MINUTES: i64 = 10;
+
+/// Backup file names are `board-<stamp>.yml`, stamped in UTC.
+const BACKUP_STAMP: &str = "%Y%m%dT%H%M%S%.3f";
+
+/// A copy of the board file taken just before it was overwritten.
+#[derive(Debug, Clone)]
+pub struct Backup {
+    pub timestamp: String,
+    pub taken_at: DateTime<Utc>,
+    pub path: PathBuf,
+}
+
+/// What `init_project_board` did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitOutcome {
+    Created,
+    /// A board was already there and was left untouched.
+    Exists,
+    /// An existing board was backed up and replaced with a fresh one.
+    Reset,
+}
+
+/// Creates `.postit/board.yml` in the current directory. An existing board
+/// is only replaced with `force`, and is backed up first. With `dry_run`
+/// nothing is written; the outcome is only reported.
+pub fn init_project_board(
+    name: Option<String>,
+    force: bool,
+    dry_run: bool,
+) -> Result<(BoardLocation, InitOutcome)> {
     let cwd = env::current_dir()?;
     let dir = cwd.join(".postit");
-    fs::create_dir_all(&dir).context("failed to create .postit directory")?;
     let path = dir.join("board.yml");
-    if !path.exists() {
+    let outcome = match (path.exists(), force) {
+        (false, _) => InitOutcome::Created,
+        (true, false) => InitOutcome::Exists,
+        (true, true) => InitOutcome::Reset,
+    };
+    if outcome != InitOutcome::Exists && !dry_run {
+        fs::create_dir_all(&dir).context("failed to create .postit directory")?;
+        let location = BoardLocation {
+            path: path.clone(),
+            scope: BoardScope::Project,
+        };
+        // Starting over replaces every note, so always keep what was there.
+        backup_current_board(&location)?;
         let board_name = name.unwrap_or_else(|| {
             cwd.file_name()
                 .and_then(|n| n.to_str())
                 .unwrap_or("project")
                 .to_string()
         });
-        let board = Board::default_named(board_name);
-        save_board(
-            &BoardLocation {
-                path: path.clone(),
-                scope: BoardScope::Project,
-            },
-            &board,
-        )?;
+        save_board(&location, &Board::default_named(board_name))?;
     }
-    Ok(BoardLocation {
-        path,
-        scope: BoardScope::Project,
-    })
+    Ok((
+        BoardLocation {
+            path,
+            scope: BoardScope::Project,
+        },
+        outcome,
+    ))
 }
 
 pub fn locate_board(start: &Path) -> Result<BoardLocation> {
@@ -58,41 +123,175 @@ pub fn locate_board(start: &Path) -> Result<BoardLocation> {
     })
 }
 
+/// The board `scope` names: the project board found from `start`, or the
+/// global one. The file itself may not exist yet.
+pub fn board_location(scope: BoardScope, start: &Path) -> Result<BoardLocation> {
+    let path = match scope {
+        BoardScope::Project => find_project_board(start).ok_or(MissingBoard::Project)?,
+        BoardScope::Global => global_board_path()?,
+    };
+    Ok(BoardLocation { path, scope })
+}
+
+/// Like `load_board`, but errors instead of creating a missing board file.
+pub fn load_board_readonly(location: &BoardLocation) -> Result<Board> {
+    if !location.path.exists() {
+        return Err(MissingBoard::At(location.path.clone()).into());
+    }
+    read_board_file(&location.path)
+}
+
+/// Parses any board YAML file, e.g. one being imported.
+pub fn read_board_file(path: &Path) -> Result<Board> {
+    let data = fs::read_to_string(path).with_context(|| format!("reading {:?}", path))?;
+    let board: Board = serde_yaml::from_str(&data).context("parsing board file")?;
+    Ok(board)
+}
+
 pub fn load_board(location: &BoardLocation) -> Result<Board> {
     if location.path.exists() {
-        let data = fs::read_to_string(&location.path)
-            .with_context(|| format!("reading {:?}", location.path))?;
-        let board: Board = serde_yaml::from_str(&data).context("parsing board file")?;
-        Ok(board)
+        load_board_readonly(location)
     } else {
-        let fallback_name = match location.scope {
-            BoardScope::Project => location
-                .path
-                .parent()
-                .and_then(|p| p.parent())
-                .and_then(|p| p.file_name())
-                .and_then(|n| n.to_str())
-                .unwrap_or("project")
-                .to_string(),
-            BoardScope::Global => "default".to_string(),
-        };
-        let board = Board::default_named(fallback_name);
+        let board = default_board(location);
         save_board(location, &board)?;
         Ok(board)
     }
 }
 
+/// The board `load_board` would create at `location`, without saving it.
+pub fn default_board(location: &BoardLocation) -> Board {
+    let fallback_name = match location.scope {
+        BoardScope::Project => location
+            .path
+            .parent()
+            .and_then(|p| p.parent())
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("project")
+            .to_string(),
+        BoardScope::Global => "default".to_string(),
+    };
+    Board::default_named(fallback_name)
+}
+
 pub fn save_board(location: &BoardLocation, board: &Board) -> Result<()> {
+    if let Some(warning) = save_board_reporting(location, board)? {
+        eprintln!("warning: {}", warning);
+    }
+    Ok(())
+}
+
+/// Like `save_board`, but hands back a failed `git_autocommit` as a warning
+/// instead of printing it, for the TUI to show.
+pub fn save_board_reporting(location: &BoardLocation, board: &Board) -> Result<Option<String>> {
     if let Some(parent) = location.path.parent() {
         fs::create_dir_all(parent).with_context(|| format!("creating {:?}", parent))?;
     }
     let serialized = serde_yaml::to_string(board).context("serializing board")?;
+    // Leave the file (and its mtime) alone when nothing would change.
+    let previous = match fs::read(&location.path) {
+        Ok(existing) if existing == serialized.as_bytes() => return Ok(None),
+        Ok(existing) => {
+            if !backed_up_recently(location)? {
+                backup_board(location, &existing)?;
+            }
+            Some(existing)
+        }
+        Err(_) => None,
+    };
     fs::write(&location.path, serialized)
         .with_context(|| format!("writing {:?}", location.path))?;
+    if !load_config().map(|c| c.git_autocommit).unwrap_or(false) {
+        return Ok(None);
+    }
+    let diff = previous.map(|bytes| match serde_yaml::from_slice::<Board>(&bytes) {
+        Ok(old) => diff_boards(&old, board),
+        Err(_) => BoardDiff::default(),
+    });
+    let message = git::commit_message(diff.as_ref());
+    Ok(git::autocommit(&location.path, &message)
+        .err()
+        .map(|err| format!("git auto-commit failed: {:#}", err)))
+}
+
+/// Where the TUI keeps the spot it was left on; a file of its own so moving
+/// around never rewrites the board.
+pub fn focus_path(location: &BoardLocation) -> PathBuf {
+    location.path.with_file_name("focus.yml")
+}
+
+/// The spot saved by the last TUI session; a missing or unreadable file means none.
+pub fn load_focus(location: &BoardLocation) -> Option<FocusPointer> {
+    let text = fs::read_to_string(focus_path(location)).ok()?;
+    serde_yaml::from_str(&text).ok()
+}
+
+pub fn save_focus(location: &BoardLocation, focus: &FocusPointer) -> Result<()> {
+    let path = focus_path(location);
+    let text = serde_yaml::to_string(focus).context("serializing focus")?;
+    fs::write(&path, text).with_context(|| format!("writing {:?}", path))
+}
+
+pub fn backup_dir(location: &BoardLocation) -> PathBuf {
+    location.path.with_file_name("backups")
+}
+
+/// Backs up the board file as it is now, however recent the last backup;
+/// for commands that replace much of the board at once.
+pub fn backup_current_board(location: &BoardLocation) -> Result<()> {
+    match fs::read(&location.path) {
+        Ok(existing) => backup_board(location, &existing),
+        Err(_) => Ok(()),
+    }
+}
+
+fn backed_up_recently(location: &BoardLocation) -> Result<bool> {
+    let window = Duration::minutes(BACKUP_INTERVAL_MINUTES);
+    Ok(list_backups(location)?
+        .first()
+        .is_some_and(|newest| Utc::now() - newest.taken_at < window))
+}
+
+fn backup_board(location: &BoardLocation, contents: &[u8]) -> Result<()> {
+    let dir = backup_dir(location);
+    fs::create_dir_all(&dir).with_context(|| format!("creating {:?}", dir))?;
+    let stamp = Utc::now().format(BACKUP_STAMP);
+    let path = dir.join(format!("board-{}.yml", stamp));
+    fs::write(&path, contents).with_context(|| format!("writing {:?}", path))?;
+    for old in list_backups(location)?.into_iter().skip(MAX_BACKUPS) {
+        let _ = fs::remove_file(old.path);
+    }
     Ok(())
 }
 
-fn find_project_board(start: &Path) -> Option<PathBuf> {
+/// Backups of the board, newest first.
+pub fn list_backups(location: &BoardLocation) -> Result<Vec<Backup>> {
+    let dir = backup_dir(location);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let mut backups: Vec<Backup> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let timestamp = name
+                .strip_prefix("board-")?
+                .strip_suffix(".yml")?
+                .to_string();
+            let taken_at = NaiveDateTime::parse_from_str(&timestamp, BACKUP_STAMP).ok()?;
+            Some(Backup {
+                taken_at: Utc.from_utc_datetime(&taken_at),
+                timestamp,
+                path,
+            })
+        })
+        .collect();
+    backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(backups)
+}
+
+pub fn find_project_board(start: &Path) -> Option<PathBuf> {
     let mut dir = Some(start);
     while let Some(current) = dir {
         let candidate = current.join(".postit/board.yml");
@@ -104,7 +303,105 @@ fn find_project_board(start: &Path) -> Option<PathBuf> {
     None
 }
 
-fn global_board_path() -> Result<PathBuf> {
+pub fn global_board_path() -> Result<PathBuf> {
     let dirs = ProjectDirs::from("", "", "postit").context("locating data directory")?;
     Ok(dirs.data_dir().join("board.yml"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Note;
+
+    #[test]
+    fn unchanged_board_is_not_rewritten() {
+        let dir = env::temp_dir().join(format!("postit-storage-{}", std::process::id()));
+        let location = BoardLocation {
+            path: dir.join("board.yml"),
+            scope: BoardScope::Project,
+        };
+        let mut board = Board::default_named("test");
+        let note = Note::new("a".into(), "first".into(), None, Vec::new(), None);
+        board.add_note(note, "todo").unwrap();
+        save_board(&location, &board).unwrap();
+        let before = fs::read(&location.path).unwrap();
+        let modified = fs::metadata(&location.path).unwrap().modified().unwrap();
+
+        let mut board = load_board_readonly(&location).unwrap();
+        board.update_note("a", |_| {}).unwrap();
+        save_board(&location, &board).unwrap();
+
+        assert_eq!(before, fs::read(&location.path).unwrap());
+        assert_eq!(
+            modified,
+            fs::metadata(&location.path).unwrap().modified().unwrap()
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn overwriting_a_board_keeps_a_backup() {
+        let dir = env::temp_dir().join(format!("postit-backup-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let location = BoardLocation {
+            path: dir.join("board.yml"),
+            scope: BoardScope::Project,
+        };
+        let mut board = Board::default_named("test");
+        save_board(&location, &board).unwrap();
+        assert!(list_backups(&location).unwrap().is_empty());
+        let original = fs::read(&location.path).unwrap();
+
+        let note = Note::new("a".into(), "first".into(), None, Vec::new(), None);
+        board.add_note(note, "todo").unwrap();
+        save_board(&location, &board).unwrap();
+
+        let backups = list_backups(&location).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(fs::read(&backups[0].path).unwrap(), original);
+
+        // A save right after another relies on the fresh backup...
+        let note = Note::new("b".into(), "second".into(), None, Vec::new(), None);
+        board.add_note(note, "todo").unwrap();
+        save_board(&location, &board).unwrap();
+        assert_eq!(list_backups(&location).unwrap().len(), 1);
+        // ...unless a command asks for one outright.
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        backup_current_board(&location).unwrap();
+        let backups = list_backups(&location).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(
+            fs::read(&backups[0].path).unwrap(),
+            fs::read(&location.path).unwrap()
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn focus_is_saved_beside_the_board_without_touching_it() {
+        let dir = env::temp_dir().join(format!("postit-focus-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let location = BoardLocation {
+            path: dir.join("board.yml"),
+            scope: BoardScope::Project,
+        };
+        save_board(&location, &Board::default_named("test")).unwrap();
+        let board = fs::read(&location.path).unwrap();
+        assert_eq!(load_focus(&location), None);
+
+        let mut focus = FocusPointer {
+            view: "board".into(),
+            column: "todo".into(),
+            note: None,
+        };
+        save_focus(&location, &focus).unwrap();
+        assert_eq!(load_focus(&location), Some(focus.clone()));
+        focus.note = Some("abc123".into());
+        save_focus(&location, &focus).unwrap();
+        assert_eq!(load_focus(&location), Some(focus));
+
+        assert_eq!(fs::read(&location.path).unwrap(), board);
+        assert!(list_backups(&location).unwrap().is_empty());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
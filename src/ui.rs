@@ -1,57 +1,252 @@
-use crate::model::{Board, Note};
-use crate::storage::{save_board, BoardLocation};
+use crate::config::{Config, TagOrder, WeekStart};
+use crate::date::{
+    due_precision, end_of_day, format_due, format_due_as, in_timezone, mistyped_due_reason,
+    parse_due, parse_due_noting, resolve_timezone, Zone,
+};
+use crate::events::{append_event, LogEvent, LogOp};
+use crate::ids::generate_id;
+use crate::model::{
+    attachment_missing, bucket_by_tag, checklist_items, next_in_cycle, Board, BoardError, Column,
+    DuePrecision, FocusPointer, Note, Priority, SortKey, UNFILED, UNTAGGED,
+};
+use crate::stats::board_stats;
+use crate::storage::{
+    board_location, load_board_readonly, save_board_reporting, save_focus, BoardLocation,
+    BoardScope,
+};
 use anyhow::{anyhow, Result};
-use chrono::{
-    DateTime, Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, TimeZone, Utc,
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Local, NaiveDate, NaiveTime, Utc};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+    KeyModifiers, MouseButton, MouseEventKind,
 };
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
-use rand::{distributions::Alphanumeric, Rng};
 use ratatui::backend::CrosstermBackend;
+use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::{Alignment, Color, Modifier, Rect, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::ListState;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
 use ratatui::Terminal;
-use std::collections::{BTreeMap, HashMap};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use std::io::{stdout, Stdout};
+use std::ops::Range;
+use std::rc::Rc;
 use std::time::{Duration, Instant};
 
-pub fn run(board: Board, location: BoardLocation) -> Result<()> {
-    let mut terminal = setup_terminal()?;
-    let mut app = App::new(board, location);
+/// How often the open TUI looks for notes whose due time just passed.
+const DUE_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Swimlane for notes without a priority when lanes group by priority.
+const NO_PRIORITY: &str = "(no priority)";
+
+/// Selection to apply when the TUI opens.
+pub enum InitialFocus {
+    Note(String),
+    Column(String),
+    /// The spot saved by the last session.
+    Resume(FocusPointer),
+}
+
+pub fn run(
+    board: Board,
+    location: BoardLocation,
+    config: Config,
+    focus: Option<InitialFocus>,
+    view: ViewMode,
+) -> Result<()> {
+    let mouse = config.mouse;
+    // Before the alternate screen, so config warnings stay visible.
+    let mut app = App::new(board, location, config, focus, view);
+    let mut terminal = setup_terminal(mouse)?;
     let result = app.event_loop(&mut terminal);
-    teardown_terminal(&mut terminal)?;
+    teardown_terminal(&mut terminal, mouse)?;
+    // After teardown, so the warning lands on the normal screen.
+    if let Err(err) = app.save_focus() {
+        eprintln!("warning: not saving where the TUI was left: {:#}", err);
+    }
     result
 }
 
+/// Smallest terminal that fits the header, a usable body, and the footer.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 15;
+
+/// From this terminal height on, the footer grows so the detail pane can
+/// show a note's fields on separate lines.
+const TALL_FOOTER_HEIGHT: u16 = 24;
+
+/// Status messages kept for the `L` log popup; older ones are dropped.
+const STATUS_LOG_LEN: usize = 200;
+
+/// Positions kept for `Ctrl+O` / `Ctrl+I` jumps.
+const JUMP_HISTORY_LEN: usize = 50;
+
+type NoteRefs<'a> = Vec<(&'a str, &'a Note)>;
+
 struct App {
     board: Board,
     location: BoardLocation,
+    config: Config,
     selected_column: usize,
     selected_note: usize,
+    selected_lane: usize,
+    /// What the board's swimlanes group by; `None` when they are off.
+    swimlanes: Option<LaneKey>,
+    compact: bool,
+    date_only: bool,
+    /// Time of day given to due dates entered as a bare day.
+    due_time: NaiveTime,
+    /// Zone due dates are entered and shown in.
+    zone: Zone,
+    stay_after_move: bool,
+    /// Leave the done column off the board; notes can still be moved there.
+    hide_done: bool,
+    /// Hide header/footer and show only the selected column (or note).
+    zen: bool,
+    /// Board shows only notes carrying this tag (chosen in the project view).
+    tag_filter: Option<String>,
+    /// Set by the first key press; hides the empty-board welcome overlay.
+    onboarding_dismissed: bool,
+    tag_colors: HashMap<String, Color>,
+    caps: TermCaps,
+    marked: HashSet<String>,
+    pending_events: Vec<LogEvent>,
     scroll_offsets: Vec<usize>,
+    /// Rows visible in the focused list at the last draw, for PageUp/PageDown.
+    page_rows: usize,
     last_save: Instant,
+    /// Due times up to here have been announced.
+    last_due_check: DateTime<Utc>,
+    /// Notes already announced as due this session.
+    announced_due: HashSet<String>,
     status: String,
+    /// Show `status` as an error until the next key press.
+    status_error: bool,
+    /// Recent distinct status messages, oldest first.
+    status_log: VecDeque<(DateTime<Local>, String)>,
+    /// Earlier selections (`Ctrl+O` pops), most recent last.
+    jumps_back: VecDeque<JumpPos>,
+    /// Selections left by jumping back (`Ctrl+I` pops).
+    jumps_forward: Vec<JumpPos>,
+    /// Where each note's last `m`/`b` move took it from, for `u` to put it back.
+    move_origins: HashMap<String, MoveOrigin>,
+    /// Columns picked as move targets this session, most recent first.
+    recent_columns: Vec<String>,
     mode: Mode,
     view: ViewMode,
     timeline: TimelineState,
     project: ProjectState,
+    views: RefCell<Option<Rc<DerivedViews>>>,
+}
+
+/// Sorted note ids behind the timeline and project views. Built lazily and
+/// dropped whenever the board changes, so draws don't re-sort every frame.
+#[derive(Default)]
+struct DerivedViews {
+    unassigned: Vec<String>,
+    assigned: Vec<String>,
+    tags: Vec<(String, Vec<String>)>,
+    due_counts: HashMap<NaiveDate, usize>,
+}
+
+/// A selected note in a given view, as remembered by the jump list.
+#[derive(Clone, PartialEq, Eq)]
+struct JumpPos {
+    view: ViewMode,
+    note_id: String,
+}
+
+/// A note's place before its last move; stale once the note changes again.
+struct MoveOrigin {
+    column_id: String,
+    index: usize,
+    /// The note's `updated_at` right after the move.
+    moved_at: DateTime<Utc>,
 }
 
 enum Mode {
     Normal,
     Creating(NoteForm),
-    Editing { note_id: String, form: NoteForm },
-    ConfirmDelete { note_id: String },
+    Editing {
+        note_id: String,
+        form: NoteForm,
+    },
+    EditingTags {
+        note_id: String,
+        tags: FieldValue,
+    },
+    ConfirmDelete {
+        note_ids: Vec<String>,
+    },
+    Triage(TriageState),
+    StatusLog {
+        filter: FieldValue,
+        scroll: usize,
+    },
+    NewColumn(ColumnForm),
+    ColumnInfo,
+    /// Board totals, computed each frame; any key closes it.
+    Stats,
+    /// Offered after a note leaves the waiting column still carrying `waiting_on`.
+    ConfirmClearWaiting {
+        note_id: String,
+    },
+    /// Turning the note's checklist into separate notes; asks whether to keep it.
+    ConfirmSplit {
+        note_id: String,
+    },
+    /// Picking which checklist item of the note becomes its own note.
+    PromoteItem {
+        note_id: String,
+    },
+    /// Typing the id of the note this one becomes a checklist item of.
+    DemoteNote {
+        note_id: String,
+        parent: FieldValue,
+    },
+    /// Picking a column to move the selected (or marked) notes to; `selected`
+    /// indexes the columns matching `query`.
+    ColumnPicker {
+        query: FieldValue,
+        selected: usize,
+    },
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-enum ViewMode {
+/// Inline form for adding a column after the selected one.
+struct ColumnForm {
+    id: FieldValue,
+    name: FieldValue,
+    wip_limit: FieldValue,
+    field: ColumnFormField,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColumnFormField {
+    Id,
+    Name,
+    WipLimit,
+}
+
+/// Walks the inbox one note at a time. `skipped` counts notes passed over,
+/// taken modulo the inbox size so it stays valid as the inbox shrinks.
+struct TriageState {
+    skipped: usize,
+    input: Option<TriageInput>,
+}
+
+enum TriageInput {
+    Tags(FieldValue),
+    Due(FieldValue),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ViewMode {
     Board,
     Timeline,
     Project,
@@ -64,6 +259,46 @@ enum TimelineFocus {
     Calendar,
 }
 
+/// What board swimlanes group notes by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LaneKey {
+    /// The note's first tag.
+    Tag,
+    Priority,
+}
+
+impl LaneKey {
+    /// The grouping after `current` in the `s` cycle: tag, priority, then off.
+    fn cycle(current: Option<LaneKey>) -> Option<LaneKey> {
+        match current {
+            None => Some(LaneKey::Tag),
+            Some(LaneKey::Tag) => Some(LaneKey::Priority),
+            Some(LaneKey::Priority) => None,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            LaneKey::Tag => "tag",
+            LaneKey::Priority => "priority",
+        }
+    }
+
+    /// The lane `note` falls in, as a sort rank and a label; tags sort by name,
+    /// priorities from high to none.
+    fn lane<'a>(&self, note: &'a Note) -> (u8, &'a str) {
+        match self {
+            LaneKey::Tag => (0, note.tags.first().map_or(UNTAGGED, String::as_str)),
+            LaneKey::Priority => match note.priority {
+                Some(Priority::High) => (0, Priority::High.label()),
+                Some(Priority::Medium) => (1, Priority::Medium.label()),
+                Some(Priority::Low) => (2, Priority::Low.label()),
+                None => (3, NO_PRIORITY),
+            },
+        }
+    }
+}
+
 struct TimelineState {
     focus: TimelineFocus,
     unassigned_idx: usize,
@@ -71,6 +306,7 @@ struct TimelineState {
     calendar_cursor: NaiveDate,
     unassigned_offset: usize,
     assigned_offset: usize,
+    overdue_only: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -83,15 +319,21 @@ struct ProjectState {
     focus: ProjectFocus,
     tag_idx: usize,
     note_idx: usize,
+    tag_order: TagOrder,
+    /// Tags picked with Space; when any are picked, their notes replace the
+    /// highlighted tag's.
+    selected_tags: BTreeSet<String>,
+    /// Show notes carrying every picked tag instead of any of them.
+    match_all: bool,
 }
 
 impl TimelineState {
-    fn new(board: &Board) -> Self {
-        let today = Utc::now().date_naive();
+    fn new(board: &Board, zone: Zone) -> Self {
+        let today = due_day(&Utc::now(), zone);
         let earliest_due = board
             .notes
             .values()
-            .filter_map(|n| n.due.map(|d| d.date_naive()))
+            .filter_map(|n| n.due.as_ref().map(|due| due_day(due, zone)))
             .min();
         let cursor = earliest_due.unwrap_or(today);
         TimelineState {
@@ -101,6 +343,7 @@ impl TimelineState {
             calendar_cursor: cursor,
             unassigned_offset: 0,
             assigned_offset: 0,
+            overdue_only: false,
         }
     }
 
@@ -129,14 +372,27 @@ impl ViewMode {
             ViewMode::Project => "Project",
         }
     }
+
+    /// Parses a `default_view` setting, ignoring case.
+    pub fn from_name(name: &str) -> Option<ViewMode> {
+        match name.trim().to_lowercase().as_str() {
+            "board" => Some(ViewMode::Board),
+            "timeline" => Some(ViewMode::Timeline),
+            "project" => Some(ViewMode::Project),
+            _ => None,
+        }
+    }
 }
 
 impl ProjectState {
-    fn new() -> Self {
+    fn new(tag_order: TagOrder) -> Self {
         ProjectState {
             focus: ProjectFocus::Tags,
             tag_idx: 0,
             note_idx: 0,
+            tag_order,
+            selected_tags: BTreeSet::new(),
+            match_all: false,
         }
     }
 
@@ -154,15 +410,17 @@ struct NoteForm {
     body: FieldValue,
     tags: FieldValue,
     due: FieldValue,
+    waiting_on: FieldValue,
     field: FormField,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum FormField {
     Title,
     Body,
     Tags,
     Due,
+    WaitingOn,
 }
 
 enum FormAction {
@@ -170,6 +428,14 @@ enum FormAction {
     Edit(String),
 }
 
+/// What a key press in the note form leaves behind.
+enum FormOutcome {
+    Stay,
+    Close,
+    /// Saved; keep editing the saved note.
+    Reopen(String),
+}
+
 #[derive(Clone)]
 struct FieldValue {
     value: String,
@@ -232,59 +498,526 @@ impl FieldValue {
 
     fn with_caret(&self) -> String {
         let mut text = self.value.clone();
-        text.insert_str(self.cursor, "▌");
+        text.insert(self.cursor, '▌');
         text
     }
 }
 
 impl App {
-    fn new(board: Board, location: BoardLocation) -> Self {
+    fn new(
+        board: Board,
+        location: BoardLocation,
+        config: Config,
+        focus: Option<InitialFocus>,
+        view: ViewMode,
+    ) -> Self {
         let status = format!("Loaded board from {}", location.path.display());
         let column_count = board.columns.len();
-        let timeline = TimelineState::new(&board);
-        App {
+        let zone = resolve_timezone(
+            board.settings.timezone.as_deref(),
+            config.timezone.as_deref(),
+        );
+        let timeline = TimelineState::new(&board, zone);
+        let compact = config.compact;
+        let date_only = config.due_date_only;
+        let due_time = config.due_time();
+        let stay_after_move = config.stay_after_move;
+        let hide_done = config.hide_done;
+        let project = ProjectState::new(config.tag_order);
+        let caps = TermCaps::detect(&config);
+        let tag_colors = config
+            .tag_colors
+            .iter()
+            .filter_map(|(tag, name)| parse_color(name).map(|color| (tag.clone(), color)))
+            .collect();
+        let mut app = App {
             board,
             location,
+            config,
             selected_column: 0,
             selected_note: 0,
+            selected_lane: 0,
+            swimlanes: None,
+            compact,
+            date_only,
+            due_time,
+            zone,
+            stay_after_move,
+            hide_done,
+            zen: false,
+            tag_filter: None,
+            onboarding_dismissed: false,
+            tag_colors,
+            caps,
+            marked: HashSet::new(),
+            pending_events: Vec::new(),
             scroll_offsets: vec![0; column_count],
+            page_rows: 0,
             last_save: Instant::now(),
+            last_due_check: Utc::now(),
+            announced_due: HashSet::new(),
             status,
+            status_error: false,
+            status_log: VecDeque::new(),
+            jumps_back: VecDeque::new(),
+            jumps_forward: Vec::new(),
+            move_origins: HashMap::new(),
+            recent_columns: Vec::new(),
             mode: Mode::Normal,
-            view: ViewMode::Board,
+            view,
             timeline,
-            project: ProjectState::new(),
+            project,
+            views: RefCell::new(None),
+        };
+        if let Some(focus) = focus {
+            app.apply_focus(focus);
+        }
+        app.ensure_board_bounds();
+        app.ensure_timeline_bounds();
+        app.ensure_project_bounds();
+        app.log_status();
+        app
+    }
+
+    /// Appends the current status to the log unless it repeats the last entry.
+    fn log_status(&mut self) {
+        if self.status.is_empty()
+            || self
+                .status_log
+                .back()
+                .is_some_and(|(_, last)| *last == self.status)
+        {
+            return;
+        }
+        if self.status_log.len() == STATUS_LOG_LEN {
+            self.status_log.pop_front();
+        }
+        self.status_log
+            .push_back((Local::now(), self.status.clone()));
+    }
+
+    fn apply_focus(&mut self, focus: InitialFocus) {
+        match focus {
+            InitialFocus::Note(note_id) => {
+                if self.board.find_note_column_index(&note_id).is_some() {
+                    self.select_board_note(&note_id);
+                    self.status = format!("Opened at note {}", note_id);
+                } else {
+                    self.status = format!("Note {} not found; showing default selection", note_id);
+                }
+            }
+            InitialFocus::Column(column_id) => match self.board.find_column_index(&column_id) {
+                Some(idx) => {
+                    self.selected_column = idx;
+                    self.selected_note = 0;
+                    self.status = format!("Opened at column {}", column_id);
+                }
+                None => {
+                    self.status =
+                        format!("Column {} not found; showing default selection", column_id);
+                }
+            },
+            InitialFocus::Resume(pointer) => self.resume_focus(&pointer),
+        }
+    }
+
+    /// Returns to the saved spot, skipping a note or column that is gone.
+    fn resume_focus(&mut self, pointer: &FocusPointer) {
+        let status = self.status.clone();
+        if let Some(idx) = self.board.find_column_index(&pointer.column) {
+            self.selected_column = idx;
+            self.selected_note = 0;
+        }
+        let view = ViewMode::from_name(&pointer.view).unwrap_or(self.view);
+        let found = pointer.note.as_ref().is_some_and(|note_id| {
+            self.select_jump_pos(&JumpPos {
+                view,
+                note_id: note_id.clone(),
+            })
+        });
+        if !found {
+            self.set_view(view);
+        }
+        self.status = status;
+    }
+
+    /// The spot to reopen at next time.
+    fn focus_pointer(&self) -> Option<FocusPointer> {
+        let column = self.board.columns.get(self.selected_column)?;
+        Some(FocusPointer {
+            view: self.view.label().to_lowercase(),
+            column: column.id.clone(),
+            note: self.current_note().map(|(id, _)| id.to_string()),
+        })
+    }
+
+    /// Saves the spot on quit, beside the board file rather than in it.
+    fn save_focus(&self) -> Result<()> {
+        match self.focus_pointer() {
+            Some(focus) if self.config.restore_focus => save_focus(&self.location, &focus),
+            _ => Ok(()),
         }
     }
 
     fn event_loop(&mut self, terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
         loop {
             terminal.draw(|f| self.draw(f))?;
-            if event::poll(Duration::from_millis(200))? {
-                if let Event::Key(key) = event::read()? {
-                    if key.kind != KeyEventKind::Press {
-                        continue;
+            if event::poll(self.idle_wait())? {
+                match event::read()? {
+                    Event::Key(key) => {
+                        if key.kind != KeyEventKind::Press {
+                            continue;
+                        }
+                        match self.handle_key(key) {
+                            Ok(true) => break,
+                            Ok(false) => {}
+                            // A change the board refuses, like one past a tag
+                            // limit, is reported instead of ending the session.
+                            Err(err) if err.is::<BoardError>() => {
+                                self.status = err.to_string();
+                                self.status_error = true;
+                                self.log_status();
+                            }
+                            Err(err) => return Err(err),
+                        }
                     }
-                    if self.handle_key(key)? {
-                        break;
+                    Event::Resize(_, _) => self.handle_resize(),
+                    Event::Mouse(mouse)
+                        if mouse.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        let size = terminal.size()?;
+                        self.click_form(mouse.column, mouse.row, size);
                     }
+                    _ => {}
                 }
             }
+            let now = Utc::now();
+            if (now - self.last_due_check).to_std().unwrap_or_default() >= DUE_CHECK_INTERVAL {
+                self.announce_due(now);
+            }
         }
         Ok(())
     }
 
+    /// How long the event loop may wait for input before redrawing.
+    fn idle_wait(&self) -> Duration {
+        let since_check = (Utc::now() - self.last_due_check)
+            .to_std()
+            .unwrap_or_default();
+        idle_wait(
+            self.last_save.elapsed(),
+            DUE_CHECK_INTERVAL.saturating_sub(since_check),
+            Duration::from_millis(self.config.poll_interval_ms.max(1)),
+        )
+    }
+
+    /// Reports notes whose due time passed since the last check, in the status
+    /// bar and (if enabled) as a desktop notification, once per session each.
+    fn announce_due(&mut self, now: DateTime<Utc>) {
+        let due = newly_due(&self.board, self.last_due_check, now, &self.announced_due);
+        self.last_due_check = now;
+        if due.is_empty() {
+            return;
+        }
+        let titles: Vec<String> = due
+            .iter()
+            .filter_map(|id| self.board.notes.get(id))
+            .map(|note| note.title.clone())
+            .collect();
+        self.status = format!("Now due: {}", titles.join(", "));
+        self.log_status();
+        if self.config.due_notifications {
+            // On its own thread so a slow notification service can't stall
+            // the UI; without one (no D-Bus session, say) this fails and the
+            // status line is all there is.
+            let summary = if titles.len() == 1 {
+                "Note due"
+            } else {
+                "Notes due"
+            };
+            let body = titles.join("\n");
+            std::thread::spawn(move || {
+                let _ = notify_rust::Notification::new()
+                    .appname("postit")
+                    .summary(summary)
+                    .body(&body)
+                    .show();
+            });
+        }
+        self.announced_due.extend(due);
+    }
+
+    /// Moves the note form's cursor to a clicked spot in one of its fields,
+    /// switching to that field. Clicks elsewhere are ignored.
+    fn click_form(&mut self, x: u16, y: u16, screen: Rect) {
+        let form = match &mut self.mode {
+            Mode::Creating(form) | Mode::Editing { form, .. } => form,
+            _ => return,
+        };
+        // Same popup as `draw_form`, inside its border.
+        let area = centered_rect(70, 60, screen);
+        let inner = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+        if x < inner.x || y < inner.y || x >= inner.right() || y >= inner.bottom() {
+            return;
+        }
+        let hit = form_hit(
+            form,
+            inner.width as usize,
+            (x - inner.x) as usize,
+            (y - inner.y) as usize,
+        );
+        if let Some((field, cursor)) = hit {
+            form.field = field;
+            form.field_mut(field).cursor = cursor;
+        }
+    }
+
+    fn handle_resize(&mut self) {
+        for (idx, column) in self.board.columns.iter().enumerate() {
+            if let Some(offset) = self.scroll_offsets.get_mut(idx) {
+                *offset = (*offset).min(column.note_ids.len().saturating_sub(1));
+            }
+        }
+        self.ensure_board_bounds();
+        self.ensure_timeline_bounds();
+        self.ensure_project_bounds();
+    }
+
     fn handle_key(&mut self, key: KeyEvent) -> Result<bool> {
-        match self.mode {
+        let before = self.jump_pos();
+        self.status_error = false;
+        self.onboarding_dismissed = true;
+        let quit = match self.mode {
             Mode::Normal => self.handle_normal_key(key),
             Mode::Creating(_) | Mode::Editing { .. } => self.handle_form_key(key),
+            Mode::EditingTags { .. } => self.handle_tags_key(key),
+            Mode::Triage(_) => self.handle_triage_key(key),
             Mode::ConfirmDelete { .. } => self.handle_confirm_key(key),
+            Mode::ConfirmClearWaiting { .. } => self.handle_clear_waiting_key(key),
+            Mode::ConfirmSplit { .. } => self.handle_split_key(key),
+            Mode::PromoteItem { .. } => self.handle_promote_key(key),
+            Mode::DemoteNote { .. } => self.handle_demote_key(key),
+            Mode::ColumnPicker { .. } => self.handle_column_picker_key(key),
+            Mode::StatusLog { .. } => self.handle_status_log_key(key),
+            Mode::NewColumn(_) => self.handle_column_form_key(key),
+            Mode::ColumnInfo | Mode::Stats => {
+                self.mode = Mode::Normal;
+                Ok(false)
+            }
+        };
+        if !is_jump_key(&key, self.view) {
+            self.record_jump(before);
+        }
+        self.log_status();
+        quit
+    }
+
+    fn jump_pos(&self) -> Option<JumpPos> {
+        self.current_note().map(|(id, _)| JumpPos {
+            view: self.view,
+            note_id: id.to_string(),
+        })
+    }
+
+    /// Remembers `before` if the selection moved away from it.
+    fn record_jump(&mut self, before: Option<JumpPos>) {
+        let Some(before) = before else {
+            return;
+        };
+        if self.jump_pos().as_ref() == Some(&before) || self.jumps_back.back() == Some(&before) {
+            return;
+        }
+        if self.jumps_back.len() == JUMP_HISTORY_LEN {
+            self.jumps_back.pop_front();
+        }
+        self.jumps_back.push_back(before);
+        self.jumps_forward.clear();
+    }
+
+    /// Steps through the jump list, skipping notes that no longer exist.
+    fn jump(&mut self, back: bool) {
+        loop {
+            let next = if back {
+                self.jumps_back.pop_back()
+            } else {
+                self.jumps_forward.pop()
+            };
+            let Some(pos) = next else {
+                self.status = if back {
+                    "No earlier position".into()
+                } else {
+                    "No later position".into()
+                };
+                return;
+            };
+            let here = self.jump_pos();
+            if !self.select_jump_pos(&pos) {
+                continue;
+            }
+            if let Some(here) = here.filter(|here| *here != pos) {
+                if back {
+                    self.jumps_forward.push(here);
+                } else {
+                    self.jumps_back.push_back(here);
+                }
+            }
+            self.status = format!("Jumped to {}", pos.note_id);
+            return;
+        }
+    }
+
+    /// Selects `pos.note_id` in `pos.view`; false if it can't be shown there any more.
+    fn select_jump_pos(&mut self, pos: &JumpPos) -> bool {
+        if !self.board.notes.contains_key(&pos.note_id) {
+            return false;
+        }
+        let found = match pos.view {
+            ViewMode::Board => self.board.find_note_column_index(&pos.note_id).is_some(),
+            ViewMode::Timeline => {
+                let (unassigned, assigned) = self.timeline_lists();
+                let find = |list: &NoteRefs<'_>| list.iter().position(|(id, _)| *id == pos.note_id);
+                match (find(&unassigned), find(&assigned)) {
+                    (Some(idx), _) => Some((TimelineFocus::Unassigned, idx)),
+                    (None, Some(idx)) => Some((TimelineFocus::Assigned, idx)),
+                    (None, None) => None,
+                }
+                .map(|(focus, idx)| {
+                    self.timeline.focus = focus;
+                    match focus {
+                        TimelineFocus::Unassigned => self.timeline.unassigned_idx = idx,
+                        _ => self.timeline.assigned_idx = idx,
+                    }
+                })
+                .is_some()
+            }
+            ViewMode::Project => {
+                let tags = self.project_tags(self.project.tag_order);
+                let combined = if self.project.selected_tags.is_empty() {
+                    None
+                } else {
+                    self.project_notes(&tags)
+                        .iter()
+                        .position(|(id, _)| *id == pos.note_id)
+                };
+                let note_in = |tag_idx: usize| {
+                    tags.get(tag_idx)?
+                        .1
+                        .iter()
+                        .position(|(id, _)| *id == pos.note_id)
+                        .map(|note_idx| (tag_idx, note_idx))
+                };
+                let hit =
+                    note_in(self.project.tag_idx).or_else(|| (0..tags.len()).find_map(note_in));
+                drop(tags);
+                if let Some(note_idx) = combined {
+                    self.project.focus = ProjectFocus::Notes;
+                    self.project.note_idx = note_idx;
+                    true
+                } else {
+                    hit.map(|(tag_idx, note_idx)| {
+                        self.project.selected_tags.clear();
+                        self.project.focus = ProjectFocus::Notes;
+                        self.project.tag_idx = tag_idx;
+                        self.project.note_idx = note_idx;
+                    })
+                    .is_some()
+                }
+            }
+        };
+        if !found {
+            return false;
+        }
+        self.set_view(pos.view);
+        if pos.view == ViewMode::Board {
+            self.select_board_note(&pos.note_id);
+        }
+        true
+    }
+
+    fn handle_status_log_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::StatusLog { filter, scroll } = &mut self.mode else {
+            return Ok(false);
+        };
+        match key.code {
+            KeyCode::Esc => self.mode = Mode::Normal,
+            KeyCode::Up => *scroll = scroll.saturating_sub(1),
+            KeyCode::Down => *scroll += 1,
+            KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+            KeyCode::PageDown => *scroll += 10,
+            KeyCode::Left => filter.move_left(),
+            KeyCode::Right => filter.move_right(),
+            KeyCode::Backspace => {
+                filter.backspace();
+                *scroll = 0;
+            }
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                filter.insert_char(c);
+                *scroll = 0;
+            }
+            _ => {}
+        }
+        if let Mode::StatusLog { filter, scroll } = &self.mode {
+            let last = self
+                .status_log_matches(&filter.value)
+                .len()
+                .saturating_sub(1);
+            let clamped = (*scroll).min(last);
+            if let Mode::StatusLog { scroll, .. } = &mut self.mode {
+                *scroll = clamped;
+            }
         }
+        Ok(false)
+    }
+
+    /// Logged messages matching `filter` (case-insensitive), newest first.
+    fn status_log_matches(&self, filter: &str) -> Vec<&(DateTime<Local>, String)> {
+        let needle = filter.trim().to_lowercase();
+        self.status_log
+            .iter()
+            .rev()
+            .filter(|(_, msg)| needle.is_empty() || msg.to_lowercase().contains(&needle))
+            .collect()
     }
 
     fn handle_normal_key(&mut self, key: KeyEvent) -> Result<bool> {
         match key.code {
             KeyCode::Char('q') => return Ok(true),
+            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.zen = !self.zen;
+                self.status = if self.zen {
+                    "Focus mode (Ctrl+Z to exit)".into()
+                } else {
+                    "Focus mode off".into()
+                };
+                return Ok(false);
+            }
+            KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.jump(true);
+                return Ok(false);
+            }
+            KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.jump(false);
+                return Ok(false);
+            }
+            KeyCode::Tab if self.view == ViewMode::Board => {
+                self.jump(false);
+                return Ok(false);
+            }
+            KeyCode::Char(c @ '1'..='9')
+                if key.modifiers.contains(KeyModifiers::ALT) && self.view == ViewMode::Board =>
+            {
+                self.move_selected_to(c as usize - '1' as usize)?;
+                return Ok(false);
+            }
             KeyCode::Char('1') => {
                 self.set_view(ViewMode::Board);
                 return Ok(false);
@@ -297,16 +1030,41 @@ impl App {
                 self.set_view(ViewMode::Project);
                 return Ok(false);
             }
+            KeyCode::Char('n')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.view == ViewMode::Board =>
+            {
+                self.mode = Mode::NewColumn(ColumnForm {
+                    id: FieldValue::new(""),
+                    name: FieldValue::new(""),
+                    wip_limit: FieldValue::new(""),
+                    field: ColumnFormField::Id,
+                });
+                self.status = "New column (Tab switches field, Enter save, Esc cancel)".into();
+                return Ok(false);
+            }
             KeyCode::Char('n') => {
-                self.mode = Mode::Creating(NoteForm::new());
-                self.status =
-                    "Creating new task (Tab/Shift-Tab move, Ctrl+Enter save, Esc cancel)".into();
+                let mut form = NoteForm::new();
+                if self.view == ViewMode::Timeline && self.timeline.focus == TimelineFocus::Calendar
+                {
+                    let day = self.timeline.calendar_cursor.format("%Y.%m.%d").to_string();
+                    form.due = FieldValue::new(&day);
+                    self.status = format!(
+                        "Creating task due {} (Tab/Shift-Tab move, Ctrl+Enter save, Esc cancel)",
+                        day
+                    );
+                } else {
+                    self.status =
+                        "Creating new task (Tab/Shift-Tab move, Ctrl+Enter save, Esc cancel)"
+                            .into();
+                }
+                self.mode = Mode::Creating(form);
                 return Ok(false);
             }
             KeyCode::Char('e') => {
                 if let Some((id, note)) = self.current_note() {
                     let id_owned = id.to_string();
-                    let form = NoteForm::from_note(note);
+                    let form = NoteForm::from_note(note, self.zone);
                     self.mode = Mode::Editing {
                         note_id: id_owned.clone(),
                         form,
@@ -317,11 +1075,34 @@ impl App {
                 }
                 return Ok(false);
             }
+            KeyCode::Char('C') => {
+                self.copy_to_other_board();
+                return Ok(false);
+            }
+            KeyCode::Char('S') => {
+                self.start_split();
+                return Ok(false);
+            }
+            KeyCode::Char('P') => {
+                self.start_promote();
+                return Ok(false);
+            }
+            KeyCode::Char('J') => {
+                self.start_demote();
+                return Ok(false);
+            }
             KeyCode::Char('d') => {
-                if let Some((id, _)) = self.current_note() {
+                if !self.marked.is_empty() {
+                    let note_ids = self.marked_in_board_order();
+                    self.status = format!(
+                        "Delete {} selected notes? (y to confirm, n/Esc to cancel)",
+                        note_ids.len()
+                    );
+                    self.mode = Mode::ConfirmDelete { note_ids };
+                } else if let Some((id, _)) = self.current_note() {
                     let id_owned = id.to_string();
                     self.mode = Mode::ConfirmDelete {
-                        note_id: id_owned.clone(),
+                        note_ids: vec![id_owned.clone()],
                     };
                     self.status = format!("Delete {}? (y to confirm, n/Esc to cancel)", id_owned);
                 } else {
@@ -329,6 +1110,73 @@ impl App {
                 }
                 return Ok(false);
             }
+            KeyCode::Char(' ')
+                if self.view == ViewMode::Project && self.project.focus == ProjectFocus::Tags =>
+            {
+                self.toggle_project_tag();
+                return Ok(false);
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_marked();
+                return Ok(false);
+            }
+            KeyCode::Char('M') => {
+                self.merge_marked()?;
+                return Ok(false);
+            }
+            KeyCode::Char('B') => {
+                self.mode = Mode::Stats;
+                return Ok(false);
+            }
+            KeyCode::Char('L') => {
+                self.mode = Mode::StatusLog {
+                    filter: FieldValue::new(""),
+                    scroll: 0,
+                };
+                return Ok(false);
+            }
+            KeyCode::Char('a') => {
+                self.open_attachment();
+                return Ok(false);
+            }
+            KeyCode::Char('D') => {
+                self.clear_due()?;
+                return Ok(false);
+            }
+            KeyCode::Char('I') => {
+                self.start_triage();
+                return Ok(false);
+            }
+            KeyCode::Char('t') | KeyCode::Char('w')
+                if self.view == ViewMode::Timeline
+                    && self.timeline.focus == TimelineFocus::Unassigned =>
+            {
+                self.quick_due(key.code == KeyCode::Char('w'))?;
+                return Ok(false);
+            }
+            KeyCode::Char('t') => {
+                self.date_only = !self.date_only;
+                self.status = if self.date_only {
+                    "Due dates: day only".into()
+                } else {
+                    "Due dates: day and time".into()
+                };
+                return Ok(false);
+            }
+            KeyCode::Esc if !self.marked.is_empty() => {
+                self.marked.clear();
+                self.status = "Selection cleared".into();
+                return Ok(false);
+            }
+            KeyCode::Esc if self.view == ViewMode::Board && self.tag_filter.is_some() => {
+                let selected = self.current_board_note().map(|(id, _)| id.to_string());
+                self.tag_filter = None;
+                if let Some(id) = selected {
+                    self.select_board_note(&id);
+                }
+                self.status = "Tag filter cleared".into();
+                return Ok(false);
+            }
             _ => {}
         }
 
@@ -340,19 +1188,59 @@ impl App {
     }
 
     fn handle_board_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
         match key.code {
+            KeyCode::Left if ctrl => self.shift_column(-1)?,
+            KeyCode::Right if ctrl => self.shift_column(1)?,
             KeyCode::Left | KeyCode::Char('h') => self.prev_column(),
             KeyCode::Right | KeyCode::Char('l') => self.next_column(),
             KeyCode::Up | KeyCode::Char('k') => self.prev_note(),
             KeyCode::Down | KeyCode::Char('j') => self.next_note(),
+            KeyCode::PageUp | KeyCode::PageDown => {
+                let card_height = if self.compact { 1 } else { NOTE_CARD_HEIGHT };
+                let page = self.page_rows / card_height;
+                let len = self.visible_note_ids(self.selected_column).len();
+                self.selected_note =
+                    page_index(self.selected_note, len, page, key.code == KeyCode::PageDown);
+            }
             KeyCode::Char('m') | KeyCode::Char('>') => self.move_selected(1)?,
             KeyCode::Char('b') | KeyCode::Char('<') => self.move_selected(-1)?,
+            KeyCode::Char('u') => self.move_back()?,
+            KeyCode::Char('s') => self.cycle_swimlanes(),
+            KeyCode::Char('c') => self.cycle_column_color()?,
+            KeyCode::Char('i') => self.mode = Mode::ColumnInfo,
+            KeyCode::Char('o') => self.cycle_column_sort()?,
+            KeyCode::Char('x') => self.toggle_done()?,
+            KeyCode::Char('w') => self.select_next_actionable(),
+            KeyCode::Char('W') => self.select_next_due_today(),
+            KeyCode::Char('T') => self.start_tag_edit(),
+            KeyCode::Char('g') => self.open_column_picker(),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.step_priority(true)?,
+            KeyCode::Char('-') => self.step_priority(false)?,
+            KeyCode::Char('f') => {
+                self.stay_after_move = !self.stay_after_move;
+                self.status = if self.stay_after_move {
+                    "After moves: stay in column".into()
+                } else {
+                    "After moves: follow note".into()
+                };
+            }
+            KeyCode::Char('H') => self.toggle_hide_done(),
+            KeyCode::Char('v') => {
+                self.compact = !self.compact;
+                self.status = if self.compact {
+                    "Compact cards".into()
+                } else {
+                    "Full cards".into()
+                };
+            }
             _ => {}
         }
         Ok(false)
     }
 
     fn handle_timeline_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let focus = self.timeline.focus;
         match key.code {
             KeyCode::Tab => self.timeline.next_focus(),
             KeyCode::BackTab => self.timeline.prev_focus(),
@@ -384,22 +1272,54 @@ impl App {
                 TimelineFocus::Assigned => self.timeline.assigned_idx += 1,
                 TimelineFocus::Calendar => self.shift_calendar(7),
             },
-            KeyCode::Enter => {
-                if self.timeline.focus == TimelineFocus::Calendar {
-                    if let Some(idx) = self.first_due_on_cursor() {
-                        self.timeline.assigned_idx = idx;
-                        self.timeline.focus = TimelineFocus::Assigned;
-                        self.status = format!(
-                            "Viewing tasks due {}",
-                            self.timeline.calendar_cursor.format("%Y-%m-%d").to_string()
-                        );
-                    } else {
-                        self.status = "No tasks due on that day".into();
+            KeyCode::PageUp | KeyCode::PageDown => {
+                let down = key.code == KeyCode::PageDown;
+                let (unassigned_len, assigned_len) = {
+                    let (unassigned, assigned) = self.timeline_lists();
+                    (unassigned.len(), assigned.len())
+                };
+                let page = self.page_rows;
+                match self.timeline.focus {
+                    TimelineFocus::Unassigned => {
+                        self.timeline.unassigned_idx =
+                            page_index(self.timeline.unassigned_idx, unassigned_len, page, down)
+                    }
+                    TimelineFocus::Assigned => {
+                        self.timeline.assigned_idx =
+                            page_index(self.timeline.assigned_idx, assigned_len, page, down)
                     }
+                    TimelineFocus::Calendar => {}
+                }
+            }
+            KeyCode::Char('o') => {
+                self.timeline.overdue_only = !self.timeline.overdue_only;
+                self.timeline.assigned_idx = 0;
+                self.timeline.assigned_offset = 0;
+                self.status = if self.timeline.overdue_only {
+                    "Showing overdue tasks only (o shows all)".into()
+                } else {
+                    "Showing all assigned tasks".into()
+                };
+            }
+            KeyCode::Enter if self.timeline.focus == TimelineFocus::Calendar => {
+                if let Some(idx) = self.first_due_on_cursor() {
+                    self.timeline.assigned_idx = idx;
+                    self.timeline.focus = TimelineFocus::Assigned;
+                    self.status = format!(
+                        "Viewing tasks due {}",
+                        self.timeline.calendar_cursor.format("%Y-%m-%d")
+                    );
+                } else {
+                    self.status = "No tasks due on that day".into();
                 }
             }
             _ => {}
         }
+        // `t` means something else here, so say so on the way in.
+        if focus != self.timeline.focus && self.timeline.focus == TimelineFocus::Unassigned {
+            self.status =
+                "Unassigned: t/w set due end of today/week (t toggles day-only elsewhere)".into();
+        }
         self.ensure_timeline_bounds();
         Ok(false)
     }
@@ -412,6 +1332,34 @@ impl App {
             },
             KeyCode::Left | KeyCode::Char('h') => self.project.focus_tags(),
             KeyCode::Right | KeyCode::Char('l') => self.project.focus_notes(),
+            KeyCode::Char('o') => self.toggle_tag_order(),
+            KeyCode::Char('u') => {
+                self.project.match_all = !self.project.match_all;
+                self.project.note_idx = 0;
+                self.status = if self.project.match_all {
+                    "Showing notes with all picked tags".into()
+                } else {
+                    "Showing notes with any picked tag".into()
+                };
+            }
+            KeyCode::Esc if !self.project.selected_tags.is_empty() => {
+                self.project.selected_tags.clear();
+                self.project.note_idx = 0;
+                self.status = "Tag picks cleared".into();
+            }
+            KeyCode::Enter if self.project.focus == ProjectFocus::Tags => {
+                let tag = self
+                    .project_tags(self.project.tag_order)
+                    .get(self.project.tag_idx)
+                    .map(|(tag, _)| tag.clone());
+                if let Some(tag) = tag {
+                    self.set_view(ViewMode::Board);
+                    self.status = format!("Board filtered to {} (Esc clears)", tag);
+                    self.tag_filter = Some(tag);
+                    self.selected_note = 0;
+                    self.ensure_board_bounds();
+                }
+            }
             KeyCode::Up | KeyCode::Char('k') => match self.project.focus {
                 ProjectFocus::Tags => {
                     if self.project.tag_idx > 0 {
@@ -434,6 +1382,26 @@ impl App {
                     self.project.note_idx += 1;
                 }
             },
+            KeyCode::PageUp | KeyCode::PageDown => {
+                let down = key.code == KeyCode::PageDown;
+                let (tag_count, note_count) = {
+                    let tags = self.project_tags(self.project.tag_order);
+                    (tags.len(), self.project_notes(&tags).len())
+                };
+                match self.project.focus {
+                    ProjectFocus::Tags => {
+                        let idx = page_index(self.project.tag_idx, tag_count, self.page_rows, down);
+                        if idx != self.project.tag_idx {
+                            self.project.tag_idx = idx;
+                            self.project.note_idx = 0;
+                        }
+                    }
+                    ProjectFocus::Notes => {
+                        self.project.note_idx =
+                            page_index(self.project.note_idx, note_count, self.page_rows, down)
+                    }
+                }
+            }
             _ => {}
         }
         self.ensure_project_bounds();
@@ -441,34 +1409,313 @@ impl App {
     }
 
     fn handle_form_key(&mut self, key: KeyEvent) -> Result<bool> {
-        let mut close_form = false;
+        let mut outcome = FormOutcome::Stay;
         let mut mode = std::mem::replace(&mut self.mode, Mode::Normal);
         match &mut mode {
             Mode::Creating(form) => {
-                close_form = self.process_form_key(FormAction::Create, form, key)?;
+                outcome = self.process_form_key(FormAction::Create, form, key)?;
             }
             Mode::Editing { note_id, form } => {
                 let id = note_id.clone();
-                close_form = self.process_form_key(FormAction::Edit(id), form, key)?;
+                outcome = self.process_form_key(FormAction::Edit(id), form, key)?;
             }
-            Mode::ConfirmDelete { .. } => {}
-            Mode::Normal => {}
+            Mode::EditingTags { .. }
+            | Mode::ConfirmDelete { .. }
+            | Mode::StatusLog { .. }
+            | Mode::NewColumn(_)
+            | Mode::ColumnInfo
+            | Mode::Stats
+            | Mode::ConfirmClearWaiting { .. }
+            | Mode::ConfirmSplit { .. }
+            | Mode::PromoteItem { .. }
+            | Mode::DemoteNote { .. }
+            | Mode::ColumnPicker { .. }
+            | Mode::Triage(_)
+            | Mode::Normal => {}
         }
-        self.mode = if close_form { Mode::Normal } else { mode };
+        self.mode = match outcome {
+            FormOutcome::Stay => mode,
+            FormOutcome::Close => Mode::Normal,
+            FormOutcome::Reopen(note_id) => match self.board.notes.get(&note_id) {
+                Some(note) => {
+                    let mut form = NoteForm::from_note(note, self.zone);
+                    if let Mode::Creating(old) | Mode::Editing { form: old, .. } = &mode {
+                        form.field = old.field;
+                    }
+                    self.status = format!("{}; still editing", self.status);
+                    Mode::Editing { note_id, form }
+                }
+                None => Mode::Normal,
+            },
+        };
         Ok(false)
     }
 
-    fn handle_confirm_key(&mut self, key: KeyEvent) -> Result<bool> {
-        let note_id = match &self.mode {
-            Mode::ConfirmDelete { note_id } => note_id.clone(),
-            _ => return Ok(false),
+    fn handle_column_form_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::NewColumn(form) = &mut self.mode else {
+            return Ok(false);
+        };
+        let field = match form.field {
+            ColumnFormField::Id => &mut form.id,
+            ColumnFormField::Name => &mut form.name,
+            ColumnFormField::WipLimit => &mut form.wip_limit,
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = "Canceled".into();
+            }
+            KeyCode::Tab => {
+                form.field = match form.field {
+                    ColumnFormField::Id => ColumnFormField::Name,
+                    ColumnFormField::Name => ColumnFormField::WipLimit,
+                    ColumnFormField::WipLimit => ColumnFormField::Id,
+                }
+            }
+            KeyCode::BackTab => {
+                form.field = match form.field {
+                    ColumnFormField::Id => ColumnFormField::WipLimit,
+                    ColumnFormField::Name => ColumnFormField::Id,
+                    ColumnFormField::WipLimit => ColumnFormField::Name,
+                }
+            }
+            KeyCode::Enter => {
+                let id = form.id.value.trim().to_string();
+                let name = Some(form.name.value.trim().to_string()).filter(|n| !n.is_empty());
+                match parse_wip_limit(&form.wip_limit.value) {
+                    Ok(wip_limit) => self.add_column_after_selected(id, name, wip_limit)?,
+                    Err(message) => {
+                        self.status = message;
+                        self.status_error = true;
+                    }
+                }
+            }
+            KeyCode::Left => field.move_left(),
+            KeyCode::Right => field.move_right(),
+            KeyCode::Backspace => field.backspace(),
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                field.insert_char(c);
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    /// Inserts an empty column to the right of the selected one and selects
+    /// it. Problems keep the form open with an error status.
+    fn add_column_after_selected(
+        &mut self,
+        id: String,
+        name: Option<String>,
+        wip_limit: Option<usize>,
+    ) -> Result<()> {
+        if id.is_empty() || id.chars().any(char::is_whitespace) {
+            self.status = "Column id must be a single word".into();
+            self.status_error = true;
+            return Ok(());
+        }
+        // The Unfiled bucket sits past the last column, so clamp to the end.
+        let index = (self.selected_column + 1).min(self.board.columns.len());
+        if let Err(err) = self.board.insert_column(index, id.clone(), name) {
+            self.status = format!("Could not add column: {}", err);
+            self.status_error = true;
+            return Ok(());
+        }
+        self.board.columns[index].wip_limit = wip_limit;
+        self.mode = Mode::Normal;
+        self.scroll_offsets
+            .insert(index.min(self.scroll_offsets.len()), 0);
+        self.selected_column = index;
+        self.selected_note = 0;
+        self.persist(format!("Added column {}", id))
+    }
+
+    fn handle_tags_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::EditingTags { note_id, tags } = &mut self.mode else {
+            return Ok(false);
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = "Canceled".into();
+            }
+            KeyCode::Enter => {
+                let note_id = note_id.clone();
+                let new_tags = parse_tags(&tags.value);
+                self.mode = Mode::Normal;
+                self.board
+                    .update_note(&note_id, |note| note.tags = new_tags.clone())?;
+                if self.swimlanes.is_some() {
+                    self.select_board_note(&note_id);
+                }
+                self.persist(format!("Updated tags on {}", note_id))?;
+            }
+            KeyCode::Left => tags.move_left(),
+            KeyCode::Right => tags.move_right(),
+            KeyCode::Backspace => tags.backspace(),
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                tags.insert_char(c);
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn handle_triage_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::Triage(mut state) = std::mem::replace(&mut self.mode, Mode::Normal) else {
+            return Ok(false);
+        };
+        let Some(note_id) = self.triage_note_id(state.skipped) else {
+            self.status = "Inbox is empty".into();
+            return Ok(false);
+        };
+        if let Some(input) = &mut state.input {
+            let field = match input {
+                TriageInput::Tags(field) | TriageInput::Due(field) => field,
+            };
+            match key.code {
+                KeyCode::Esc => state.input = None,
+                KeyCode::Enter => {
+                    let applied = match input {
+                        TriageInput::Tags(field) => {
+                            let tags = parse_tags(&field.value);
+                            self.board
+                                .update_note(&note_id, |note| note.tags = tags.clone())
+                                .map_err(|err| anyhow!(err))
+                        }
+                        TriageInput::Due(field) => {
+                            parse_due(&field.value, self.due_time, self.zone).and_then(|due| {
+                                let precision = due_precision(&field.value);
+                                self.board
+                                    .update_note(&note_id, |note| {
+                                        note.due = due;
+                                        note.due_precision = precision;
+                                    })
+                                    .map_err(|err| anyhow!(err))
+                            })
+                        }
+                    };
+                    match applied {
+                        Ok(()) => {
+                            state.input = None;
+                            self.persist(format!("Updated {}", note_id))?;
+                        }
+                        Err(err) => self.status = format!("Could not update: {}", err),
+                    }
+                }
+                KeyCode::Left => field.move_left(),
+                KeyCode::Right => field.move_right(),
+                KeyCode::Backspace => field.backspace(),
+                KeyCode::Char(c)
+                    if !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    field.insert_char(c)
+                }
+                _ => {}
+            }
+            self.mode = Mode::Triage(state);
+            return Ok(false);
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.status = "Triage finished".into();
+                return Ok(false);
+            }
+            KeyCode::Char('s') | KeyCode::Char('j') | KeyCode::Down => state.skipped += 1,
+            KeyCode::Char('g') => {
+                let tags = self
+                    .board
+                    .notes
+                    .get(&note_id)
+                    .map(|n| n.tags.join(" "))
+                    .unwrap_or_default();
+                state.input = Some(TriageInput::Tags(FieldValue::new(&tags)));
+            }
+            KeyCode::Char('u') => {
+                let due = self
+                    .board
+                    .notes
+                    .get(&note_id)
+                    .map(|note| due_field_text(note, self.zone))
+                    .unwrap_or_default();
+                state.input = Some(TriageInput::Due(FieldValue::new(&due)));
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let pick = c as usize - '1' as usize;
+                if let Some(dest) = self.triage_targets().get(pick).cloned() {
+                    let from = self
+                        .board
+                        .inbox_column_index()
+                        .map(|idx| self.board.columns[idx].id.clone())
+                        .unwrap_or_default();
+                    // A refused move (a full column, say) keeps triage open.
+                    match self.board.move_note(&note_id, &dest) {
+                        Ok(()) => {
+                            self.queue_event(
+                                &note_id,
+                                LogOp::Move {
+                                    from,
+                                    to: dest.clone(),
+                                },
+                            );
+                            self.persist(format!("Triaged {} to {}", note_id, dest))?;
+                        }
+                        Err(err) => {
+                            self.status = format!("Could not triage: {}", err);
+                            self.status_error = true;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        if self.triage_note_id(state.skipped).is_some() {
+            self.mode = Mode::Triage(state);
+        } else {
+            self.status = "Inbox empty; triage finished".into();
+        }
+        Ok(false)
+    }
+
+    fn handle_confirm_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let note_ids = match &self.mode {
+            Mode::ConfirmDelete { note_ids } => note_ids.clone(),
+            _ => return Ok(false),
         };
         match key.code {
             KeyCode::Char('y') | KeyCode::Enter => {
-                if let Err(err) = self.delete_note(&note_id) {
-                    self.status = format!("Delete failed: {}", err);
+                let mut deleted = 0;
+                let mut failed = Vec::new();
+                for note_id in &note_ids {
+                    match self.delete_note(note_id) {
+                        Ok(()) => deleted += 1,
+                        Err(_) => failed.push(note_id.clone()),
+                    }
+                }
+                self.marked.clear();
+                if deleted == 0 {
+                    self.status = format!("Delete failed: {}", failed.join(", "));
+                } else if note_ids.len() == 1 {
+                    self.persist(format!("Deleted {}", note_ids[0]))?;
+                } else if failed.is_empty() {
+                    self.persist(format!("Deleted {} notes", deleted))?;
                 } else {
-                    self.persist(format!("Deleted {}", note_id))?;
+                    self.persist(format!(
+                        "Deleted {} notes; failed: {}",
+                        deleted,
+                        failed.join(", ")
+                    ))?;
                 }
                 self.mode = Mode::Normal;
             }
@@ -481,6 +1728,148 @@ impl App {
         Ok(false)
     }
 
+    fn handle_clear_waiting_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::ConfirmClearWaiting { note_id } = &self.mode else {
+            return Ok(false);
+        };
+        let note_id = note_id.clone();
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                self.mode = Mode::Normal;
+                self.board
+                    .update_note(&note_id, |note| note.waiting_on = None)?;
+                self.persist(format!("Cleared waiting on for {}", note_id))?;
+            }
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = format!("Kept waiting on for {}", note_id);
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn handle_split_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::ConfirmSplit { note_id } = &self.mode else {
+            return Ok(false);
+        };
+        let note_id = note_id.clone();
+        let keep = match key.code {
+            KeyCode::Char('k') | KeyCode::Enter => true,
+            KeyCode::Char('d') => false,
+            KeyCode::Char('n') | KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = "Split canceled".into();
+                return Ok(false);
+            }
+            _ => return Ok(false),
+        };
+        self.mode = Mode::Normal;
+        self.split_note(&note_id, keep)?;
+        Ok(false)
+    }
+
+    fn handle_promote_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::PromoteItem { note_id } = &self.mode else {
+            return Ok(false);
+        };
+        let note_id = note_id.clone();
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = "Promote canceled".into();
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                self.mode = Mode::Normal;
+                let index = c as usize - '1' as usize;
+                self.promote_item(&note_id, index)?;
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn handle_column_picker_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::ColumnPicker { query, selected } = &mut self.mode else {
+            return Ok(false);
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = "Move canceled".into();
+                return Ok(false);
+            }
+            KeyCode::Up => *selected = selected.saturating_sub(1),
+            KeyCode::Down => *selected += 1,
+            KeyCode::Left => query.move_left(),
+            KeyCode::Right => query.move_right(),
+            KeyCode::Backspace => {
+                query.backspace();
+                *selected = 0;
+            }
+            KeyCode::Enter => {
+                let (query, selected) = (query.value.clone(), *selected);
+                let matches =
+                    column_picker_matches(&self.board.columns, &self.recent_columns, &query);
+                let Some(&target) = matches.get(selected) else {
+                    self.status = "No column matches".into();
+                    return Ok(false);
+                };
+                self.mode = Mode::Normal;
+                return self.move_selected_to(target).map(|()| false);
+            }
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                query.insert_char(c);
+                *selected = 0;
+            }
+            _ => {}
+        }
+        if let Mode::ColumnPicker { query, .. } = &self.mode {
+            let last =
+                column_picker_matches(&self.board.columns, &self.recent_columns, &query.value)
+                    .len()
+                    .saturating_sub(1);
+            if let Mode::ColumnPicker { selected, .. } = &mut self.mode {
+                *selected = (*selected).min(last);
+            }
+        }
+        Ok(false)
+    }
+
+    fn handle_demote_key(&mut self, key: KeyEvent) -> Result<bool> {
+        let Mode::DemoteNote { note_id, parent } = &mut self.mode else {
+            return Ok(false);
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.status = "Canceled".into();
+            }
+            KeyCode::Enter => {
+                let note_id = note_id.clone();
+                let parent_id = parent.value.trim().to_string();
+                self.mode = Mode::Normal;
+                self.demote_note(&note_id, &parent_id)?;
+            }
+            KeyCode::Left => parent.move_left(),
+            KeyCode::Right => parent.move_right(),
+            KeyCode::Backspace => parent.backspace(),
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                parent.insert_char(c);
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
     fn set_view(&mut self, view: ViewMode) {
         if self.view != view {
             self.view = view;
@@ -495,11 +1884,11 @@ impl App {
         action: FormAction,
         form: &mut NoteForm,
         key: KeyEvent,
-    ) -> Result<bool> {
-        let mut close_form = false;
+    ) -> Result<FormOutcome> {
+        let mut outcome = FormOutcome::Stay;
         match key.code {
             KeyCode::Esc => {
-                close_form = true;
+                outcome = FormOutcome::Close;
                 self.status = "Canceled".into();
             }
             KeyCode::Tab => form.next_field(),
@@ -510,78 +1899,123 @@ impl App {
             KeyCode::Down => form.active_field_mut().move_down(),
             KeyCode::Enter => {
                 let control = key.modifiers.contains(KeyModifiers::CONTROL);
-                if form.field == FormField::Body && !control {
+                // Ctrl+Shift+Enter saves and stays; Alt+Enter does the same
+                // in terminals that cannot tell Shift apart on Enter.
+                let reopen = key.modifiers.contains(KeyModifiers::ALT)
+                    || (control && key.modifiers.contains(KeyModifiers::SHIFT));
+                if form.field == FormField::Body && !control && !reopen {
                     form.active_field_mut().insert_char('\n');
                 } else {
-                    close_form = self.try_submit(action, form)?;
+                    outcome = self.try_submit(action, form, reopen)?;
                 }
             }
             KeyCode::Backspace => form.active_field_mut().backspace(),
-            KeyCode::Char(c) => {
+            KeyCode::Char(c)
                 if !key
                     .modifiers
-                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
-                {
-                    form.active_field_mut().insert_char(c);
-                }
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                form.active_field_mut().insert_char(c);
             }
             _ => {}
         }
-        Ok(close_form)
+        Ok(outcome)
     }
 
-    fn try_submit(&mut self, action: FormAction, form: &mut NoteForm) -> Result<bool> {
-        match action {
-            FormAction::Create => {
-                if let Err(err) = self.create_note_from_form(form) {
-                    self.status = format!("Could not create: {}", err);
-                    Ok(false)
-                } else {
-                    Ok(true)
-                }
-            }
-            FormAction::Edit(note_id) => {
-                if let Err(err) = self.edit_note_from_form(&note_id, form) {
-                    self.status = format!("Could not edit: {}", err);
-                    Ok(false)
-                } else {
-                    Ok(true)
-                }
+    /// Saves the form; with `reopen` the form comes back on the saved note.
+    fn try_submit(
+        &mut self,
+        action: FormAction,
+        form: &mut NoteForm,
+        reopen: bool,
+    ) -> Result<FormOutcome> {
+        let saved = match action {
+            FormAction::Create => self
+                .create_note_from_form(form)
+                .map_err(|err| format!("Could not create: {}", err)),
+            FormAction::Edit(note_id) => self
+                .edit_note_from_form(&note_id, form)
+                .map(|()| note_id)
+                .map_err(|err| format!("Could not edit: {}", err)),
+        };
+        Ok(match saved {
+            Err(status) => {
+                self.status = status;
+                self.status_error = true;
+                FormOutcome::Stay
             }
-        }
+            Ok(note_id) if reopen => FormOutcome::Reopen(note_id),
+            Ok(_) => FormOutcome::Close,
+        })
     }
 
     fn draw(&mut self, f: &mut ratatui::Frame<'_>) {
-        let layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),
-                Constraint::Min(8),
-                Constraint::Length(4),
-            ])
-            .split(f.size());
-
-        self.draw_header(f, layout[0]);
-        match self.view {
-            ViewMode::Board => self.draw_board(f, layout[1]),
-            ViewMode::Timeline => self.draw_timeline(f, layout[1]),
-            ViewMode::Project => self.draw_project(f, layout[1]),
+        let size = f.size();
+        if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+            let msg = Paragraph::new(format!(
+                "Terminal too small ({}x{}); need at least {}x{}",
+                size.width, size.height, MIN_WIDTH, MIN_HEIGHT
+            ))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+            f.render_widget(msg, size);
+            degrade_buffer(f.buffer_mut(), self.caps);
+            return;
+        }
+        if self.zen {
+            match self.view {
+                ViewMode::Board => self.draw_board(f, size),
+                ViewMode::Timeline | ViewMode::Project => self.draw_zen_note(f, size),
+            }
+        } else {
+            let footer = if size.height >= TALL_FOOTER_HEIGHT {
+                7
+            } else {
+                4
+            };
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Min(8),
+                    Constraint::Length(footer),
+                ])
+                .split(size);
+
+            self.draw_header(f, layout[0]);
+            match self.view {
+                ViewMode::Board => self.draw_board(f, layout[1]),
+                ViewMode::Timeline => self.draw_timeline(f, layout[1]),
+                ViewMode::Project => self.draw_project(f, layout[1]),
+            }
+            self.draw_footer(f, layout[2]);
         }
-        self.draw_footer(f, layout[2]);
 
         match &self.mode {
             Mode::Creating(form) => self.draw_form(f, "New Task", form),
             Mode::Editing { form, .. } => self.draw_form(f, "Edit Task", form),
-            Mode::ConfirmDelete { note_id } => self.draw_confirm(f, note_id),
+            Mode::EditingTags { tags, .. } => self.draw_tags_dialog(f, tags),
+            Mode::ConfirmDelete { note_ids } => self.draw_confirm(f, note_ids),
+            Mode::Triage(state) => self.draw_triage(f, state),
+            Mode::StatusLog { filter, scroll } => self.draw_status_log(f, filter, *scroll),
+            Mode::NewColumn(form) => self.draw_column_form(f, form),
+            Mode::ColumnInfo => self.draw_column_info(f),
+            Mode::Stats => self.draw_stats(f),
+            Mode::ConfirmClearWaiting { note_id } => self.draw_clear_waiting(f, note_id),
+            Mode::ConfirmSplit { note_id } => self.draw_split(f, note_id),
+            Mode::PromoteItem { note_id } => self.draw_promote(f, note_id),
+            Mode::DemoteNote { note_id, parent } => self.draw_demote(f, note_id, parent),
+            Mode::ColumnPicker { query, selected } => self.draw_column_picker(f, query, *selected),
+            Mode::Normal if self.board.notes.is_empty() && !self.onboarding_dismissed => {
+                self.draw_onboarding(f)
+            }
             Mode::Normal => {}
         }
+        degrade_buffer(f.buffer_mut(), self.caps);
     }
 
     fn draw_header(&self, f: &mut ratatui::Frame<'_>, area: Rect) {
-        let scope = match self.location.scope {
-            crate::storage::BoardScope::Project => "project",
-            crate::storage::BoardScope::Global => "global",
-        };
+        let scope = self.location.scope.label();
         let title = Line::from(vec![
             Span::styled(
                 "postit ",
@@ -610,6 +2044,15 @@ impl App {
                 format!("view {}", self.view.label().to_lowercase()),
                 Style::default().fg(Color::Magenta),
             ),
+            Span::raw("  •  "),
+            Span::styled(
+                format!(
+                    "{} {:.0}% done",
+                    ratio_gauge(self.board.completion_ratio(), 10),
+                    self.board.completion_ratio() * 100.0
+                ),
+                Style::default().fg(Color::LightGreen),
+            ),
         ]);
 
         let block = Block::default()
@@ -631,15 +2074,77 @@ impl App {
             return;
         }
 
-        if self.scroll_offsets.len() < self.board.columns.len() {
-            self.scroll_offsets.resize(self.board.columns.len(), 0);
-        }
+        let area = match &self.tag_filter {
+            Some(tag) => {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(1), Constraint::Min(0)])
+                    .split(area);
+                let banner = Paragraph::new(Line::from(vec![
+                    Span::styled(
+                        format!(" Tag filter: {} ", tag),
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled("  Esc shows all notes", Style::default().fg(Color::Gray)),
+                ]));
+                f.render_widget(banner, rows[0]);
+                rows[1]
+            }
+            None => area,
+        };
 
-        let chunk_constraints = self
+        let mut headers = self
             .board
             .columns
             .iter()
-            .map(|_| Constraint::Percentage((100 / self.board.columns.len() as u16).max(1)))
+            .enumerate()
+            .map(|(idx, column)| {
+                let count = self.column_note_ids(idx).len();
+                let wip = column
+                    .wip_limit
+                    .filter(|&limit| limit > 0)
+                    .map(|limit| (column.note_ids.len(), limit));
+                // Plain ASCII mode leaves icons out rather than drawing emoji.
+                let label = match column.icon.as_deref().filter(|_| self.caps.unicode) {
+                    Some(icon) => format!("{} {}", icon, column.label()),
+                    None => column.label().to_string(),
+                };
+                let mut title = match wip {
+                    Some((held, limit)) => format!("{} ({}/{})", label, held, limit),
+                    None => format!("{} ({})", label, count),
+                };
+                if let Some(sort) = column.sort {
+                    title.push_str(&format!(" ↓{}", sort));
+                }
+                if column.is_inbox {
+                    title.push_str(" [inbox]");
+                }
+                (title, column_color(column, idx), wip)
+            })
+            .collect::<Vec<_>>();
+        if !self.board.orphan_notes().is_empty() {
+            let orphans = self.column_note_ids(self.board.columns.len()).len();
+            headers.push((format!("Unfiled ({})", orphans), Color::LightRed, None));
+        }
+
+        if self.scroll_offsets.len() < headers.len() {
+            self.scroll_offsets.resize(headers.len(), 0);
+        }
+
+        // Focus mode gives the whole width to the selected column.
+        let shown: Vec<usize> = if self.zen {
+            vec![self.selected_column.min(headers.len() - 1)]
+        } else {
+            (0..headers.len())
+                .filter(|&idx| !self.column_hidden(idx))
+                .collect()
+        };
+        let chunk_constraints = shown
+            .iter()
+            .map(|_| Constraint::Percentage((100 / shown.len() as u16).max(1)))
             .collect::<Vec<_>>();
 
         let chunks = Layout::default()
@@ -647,32 +2152,94 @@ impl App {
             .constraints(chunk_constraints)
             .split(area);
 
-        for (idx, column) in self.board.columns.iter().enumerate() {
-            let accent = color_for_index(idx);
-            let note_width = chunks[idx].width.saturating_sub(2);
-            let notes = column
-                .note_ids
+        let lanes = self.board_lanes();
+        let slot = |idx: usize| shown.iter().position(|&shown_idx| shown_idx == idx);
+        if let Some(chunk) = slot(self.selected_column).map(|s| chunks[s]) {
+            let rows = chunk.height.saturating_sub(2) as usize;
+            self.page_rows = if self.swimlanes.is_some() {
+                (rows / lanes.len().max(1)).saturating_sub(1)
+            } else {
+                rows
+            };
+        }
+
+        for (idx, (title, accent, wip)) in headers.into_iter().enumerate() {
+            let Some(area) = slot(idx).map(|s| chunks[s]) else {
+                continue;
+            };
+            // The gauge takes whatever title room is left after the text and a space;
+            // measured in cells, since icons are often double-width emoji.
+            let room = (area.width as usize).saturating_sub(Span::raw(title.as_str()).width() + 3);
+            let mut title = vec![Span::styled(
+                title,
+                Style::default()
+                    .fg(accent)
+                    .add_modifier(if idx == self.selected_column {
+                        Modifier::BOLD | Modifier::UNDERLINED
+                    } else {
+                        Modifier::BOLD
+                    }),
+            )];
+            if let Some((held, limit)) = wip {
+                let gauge = wip_gauge(held, limit, room);
+                if !gauge.is_empty() {
+                    let color = if held >= limit { Color::Red } else { accent };
+                    title.push(Span::raw(" "));
+                    title.push(Span::styled(gauge, Style::default().fg(color)));
+                }
+            }
+            let block = Block::default()
+                .title(Line::from(title))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(accent))
+                .style(Style::default().bg(Color::Rgb(16, 18, 24)));
+
+            if self.swimlanes.is_some() {
+                let inner = block.inner(area);
+                f.render_widget(block, area);
+                self.draw_column_lanes(f, inner, idx, &lanes, accent);
+                continue;
+            }
+
+            let note_width = area.width.saturating_sub(2);
+            let ids = self.column_note_ids(idx);
+            let cap = self
+                .board
+                .columns
+                .get(idx)
+                .and_then(|c| self.config.column_caps.get(&c.id).copied());
+            let selection = (idx == self.selected_column).then_some(self.selected_note);
+            let hidden = hidden_past_cap(ids.len(), cap, selection);
+            let mut notes = ids[..ids.len() - hidden]
                 .iter()
-                .filter_map(|id| self.board.notes.get(id))
+                .filter_map(|id| self.board.notes.get(*id))
                 .enumerate()
                 .map(|(n_idx, note)| {
-                    note_item(
+                    self.board_item(
                         note,
                         note_width,
                         idx == self.selected_column && n_idx == self.selected_note,
                     )
                 })
                 .collect::<Vec<_>>();
+            if hidden > 0 {
+                notes.push(ListItem::new(Line::from(Span::styled(
+                    format!("  +{} more", hidden),
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC),
+                ))));
+            }
             let mut state = ListState::default();
             let mut offset = *self.scroll_offsets.get(idx).unwrap_or(&0);
-            let viewport = chunks[idx].height.saturating_sub(2) as usize;
+            let viewport = area.height.saturating_sub(2) as usize;
             let selected = if idx == self.selected_column {
                 Some(self.selected_note)
             } else {
                 None
             };
             if let Some(sel) = selected {
-                offset = adjust_offset(sel, offset, viewport, 1, notes.len());
+                offset = adjust_offset(sel, offset, viewport, self.config.scrolloff, notes.len());
                 self.scroll_offsets[idx] = offset;
                 state.select(Some(sel));
                 *state.offset_mut() = offset;
@@ -680,26 +2247,93 @@ impl App {
                 *state.offset_mut() = offset.min(notes.len().saturating_sub(1));
             }
 
-            let mut title = format!("{}", column.id);
-            title.push_str(&format!(" ({})", column.note_ids.len()));
+            let list = List::new(notes).block(block);
+            f.render_stateful_widget(list, area, &mut state);
+        }
+    }
+
+    fn render_opts(&self) -> RenderOpts<'_> {
+        RenderOpts {
+            card_titles: if self.config.wrap_titles {
+                TitleFit::Wrap
+            } else {
+                TitleFit::Truncate
+            },
+            date_only: self.date_only,
+            zone: self.zone,
+            tag_colors: &self.tag_colors,
+        }
+    }
+
+    fn board_item(&self, note: &Note, width: u16, selected: bool) -> ListItem<'static> {
+        let marked = self.marked.contains(&note.id);
+        if self.compact {
+            compact_note_item(note, width, selected, marked, &self.render_opts())
+        } else {
+            note_item(note, width, selected, marked, &self.render_opts())
+        }
+    }
+
+    fn draw_column_lanes(
+        &self,
+        f: &mut ratatui::Frame<'_>,
+        area: Rect,
+        col_idx: usize,
+        lanes: &[String],
+        accent: Color,
+    ) {
+        if lanes.is_empty() {
+            f.render_widget(Paragraph::new("(empty)").alignment(Alignment::Center), area);
+            return;
+        }
+        let constraints = lanes
+            .iter()
+            .map(|_| Constraint::Ratio(1, lanes.len() as u32))
+            .collect::<Vec<_>>();
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
 
+        for (lane_idx, lane) in lanes.iter().enumerate() {
+            let ids = self.lane_note_ids(col_idx, lane);
+            let active = col_idx == self.selected_column && lane_idx == self.selected_lane;
+            let notes = ids
+                .iter()
+                .filter_map(|id| self.board.notes.get(*id))
+                .enumerate()
+                .map(|(n_idx, note)| {
+                    self.board_item(note, area.width, active && n_idx == self.selected_note)
+                })
+                .collect::<Vec<_>>();
+            let mut state = ListState::default();
+            if active {
+                let viewport = rows[lane_idx].height.saturating_sub(1) as usize;
+                let offset = adjust_offset(
+                    self.selected_note,
+                    0,
+                    viewport,
+                    self.config.scrolloff,
+                    notes.len(),
+                );
+                state.select(Some(self.selected_note));
+                *state.offset_mut() = offset;
+            }
             let block = Block::default()
                 .title(Span::styled(
-                    title,
+                    format!("{} ({})", lane, ids.len()),
                     Style::default()
-                        .fg(accent)
-                        .add_modifier(if idx == self.selected_column {
-                            Modifier::BOLD | Modifier::UNDERLINED
-                        } else {
+                        .fg(if active { accent } else { Color::Gray })
+                        .add_modifier(if active {
                             Modifier::BOLD
+                        } else {
+                            Modifier::DIM
                         }),
                 ))
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(accent))
-                .style(Style::default().bg(Color::Rgb(16, 18, 24)));
-
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::DarkGray));
             let list = List::new(notes).block(block);
-            f.render_stateful_widget(list, chunks[idx], &mut state);
+            f.render_stateful_widget(list, rows[lane_idx], &mut state);
         }
     }
 
@@ -728,7 +2362,11 @@ impl App {
         let assigned_offset = self.draw_timeline_column(
             f,
             left[1],
-            "Assigned Tasks",
+            if self.timeline.overdue_only {
+                "Overdue Tasks"
+            } else {
+                "Assigned Tasks"
+            },
             &assigned,
             self.timeline.focus == TimelineFocus::Assigned,
             self.timeline.assigned_offset,
@@ -736,6 +2374,12 @@ impl App {
             true,
         );
 
+        let focused = match self.timeline.focus {
+            TimelineFocus::Unassigned => left[0],
+            TimelineFocus::Assigned => left[1],
+            TimelineFocus::Calendar => outer[1],
+        };
+
         let counts = self.timeline_due_counts();
         self.draw_timeline_calendar(
             f,
@@ -747,8 +2391,10 @@ impl App {
         drop(assigned);
         self.timeline.unassigned_offset = unassigned_offset;
         self.timeline.assigned_offset = assigned_offset;
+        self.page_rows = focused.height.saturating_sub(2) as usize;
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn draw_timeline_column(
         &self,
         f: &mut ratatui::Frame<'_>,
@@ -763,18 +2409,27 @@ impl App {
         let mut state = ListState::default();
         let viewport = area.height.saturating_sub(2) as usize;
         let effective_idx = selected_idx.min(notes.len().saturating_sub(1));
-        let new_offset = adjust_offset(effective_idx, offset, viewport, 1, notes.len());
+        let new_offset = adjust_offset(
+            effective_idx,
+            offset,
+            viewport,
+            self.config.scrolloff,
+            notes.len(),
+        );
         *state.offset_mut() = new_offset;
         if focused && !notes.is_empty() {
             state.select(Some(effective_idx));
         }
 
+        let opts = self.render_opts();
         let items = if notes.is_empty() {
             vec![ListItem::new("No tasks")]
         } else {
             notes
                 .iter()
-                .map(|(id, note)| timeline_list_item(id, note, show_due))
+                .map(|(id, note)| {
+                    timeline_list_item(note, show_due, self.marked.contains(*id), &opts)
+                })
                 .collect()
         };
         let block = Block::default()
@@ -811,7 +2466,8 @@ impl App {
         let month_start =
             NaiveDate::from_ymd_opt(cursor.year(), cursor.month(), 1).unwrap_or(cursor);
         let days = days_in_month(month_start.year(), month_start.month());
-        let start_offset = month_start.weekday().num_days_from_monday();
+        let week_start = self.config.week_start;
+        let start_offset = first_cell_offset(month_start, week_start);
         let mut lines = Vec::new();
         lines.push(Line::from(Span::styled(
             format!("{} {}", month_start.format("%B"), month_start.year()),
@@ -819,7 +2475,7 @@ impl App {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )));
-        let headings = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+        let headings = weekday_headings(week_start);
         let header_spans: Vec<Span<'static>> = headings
             .iter()
             .map(|h| Span::styled(format!("{:^8}", h), Style::default().fg(Color::Gray)))
@@ -854,6 +2510,8 @@ impl App {
                     let text = format!("{:^8}", content);
                     let mut style = Style::default().fg(if count > 0 {
                         Color::LightYellow
+                    } else if self.timeline.overdue_only {
+                        Color::DarkGray
                     } else {
                         Color::Gray
                     });
@@ -892,13 +2550,18 @@ impl App {
 
     fn draw_project(&mut self, f: &mut ratatui::Frame<'_>, area: Rect) {
         self.ensure_project_bounds();
-        let tags = self.project_tags();
+        let tags = self.project_tags(self.project.tag_order);
         let sections = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
             .split(area);
         self.draw_project_tags(f, sections[0], &tags);
         self.draw_project_notes(f, sections[1], &tags);
+        let focused = match self.project.focus {
+            ProjectFocus::Tags => sections[0],
+            ProjectFocus::Notes => sections[1],
+        };
+        self.page_rows = focused.height.saturating_sub(2) as usize;
     }
 
     fn draw_project_tags(
@@ -910,7 +2573,7 @@ impl App {
         let mut state = ListState::default();
         let viewport = area.height.saturating_sub(2) as usize;
         let selected = self.project.tag_idx.min(tags.len().saturating_sub(1));
-        let offset = adjust_offset(selected, 0, viewport, 1, tags.len());
+        let offset = adjust_offset(selected, 0, viewport, self.config.scrolloff, tags.len());
         *state.offset_mut() = offset;
         if self.project.focus == ProjectFocus::Tags && !tags.is_empty() {
             state.select(Some(selected));
@@ -921,8 +2584,17 @@ impl App {
         } else {
             tags.iter()
                 .map(|(tag, notes)| {
-                    ListItem::new(format!("{} ({})", tag, notes.len()))
-                        .style(Style::default().fg(Color::White))
+                    let color = if tag == UNTAGGED {
+                        Color::White
+                    } else {
+                        tag_color(tag, &self.tag_colors)
+                    };
+                    let mut spans = Vec::new();
+                    if self.project.selected_tags.contains(tag) {
+                        spans.push(mark_span());
+                    }
+                    spans.push(Span::raw(format!("{} ({})", tag, notes.len())));
+                    ListItem::new(Line::from(spans)).style(Style::default().fg(color))
                 })
                 .collect()
         };
@@ -960,31 +2632,47 @@ impl App {
         area: Rect,
         tags: &[(String, Vec<(&str, &Note)>)],
     ) {
-        let notes = tags
-            .get(self.project.tag_idx)
-            .map(|(_, notes)| notes.as_slice())
-            .unwrap_or(&[]);
+        let notes = self.project_notes(tags);
         let mut state = ListState::default();
         let viewport = area.height.saturating_sub(2) as usize;
         let selected = self.project.note_idx.min(notes.len().saturating_sub(1));
-        let offset = adjust_offset(selected, 0, viewport, 1, notes.len());
+        let offset = adjust_offset(selected, 0, viewport, self.config.scrolloff, notes.len());
         *state.offset_mut() = offset;
         if self.project.focus == ProjectFocus::Notes && !notes.is_empty() {
             state.select(Some(selected));
         }
 
+        let opts = self.render_opts();
         let items = if notes.is_empty() {
-            vec![ListItem::new("No tasks for this tag")]
+            vec![ListItem::new(if self.project.selected_tags.is_empty() {
+                "No tasks for this tag"
+            } else {
+                "No tasks match the picked tags"
+            })]
         } else {
             notes
                 .iter()
-                .map(|(id, note)| project_note_item(id, note))
+                .map(|(id, note)| project_note_item(id, note, self.marked.contains(*id), &opts))
                 .collect()
         };
 
+        let title = if self.project.selected_tags.is_empty() {
+            "Tagged Tasks".to_string()
+        } else {
+            format!(
+                "Tasks with {} of: {}",
+                if self.project.match_all { "all" } else { "any" },
+                self.project
+                    .selected_tags
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
         let block = Block::default()
             .title(Span::styled(
-                "Tagged Tasks",
+                title,
                 Style::default()
                     .fg(if self.project.focus == ProjectFocus::Notes {
                         Color::Cyan
@@ -1013,7 +2701,7 @@ impl App {
     fn draw_footer(&self, f: &mut ratatui::Frame<'_>, area: Rect) {
         let rows = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(2), Constraint::Length(2)])
+            .constraints([Constraint::Length(2), Constraint::Min(2)])
             .split(area);
 
         let help_bar = Paragraph::new(self.footer_help_line())
@@ -1030,27 +2718,67 @@ impl App {
             .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
             .split(rows[1]);
 
-        let status = Paragraph::new(self.status.clone())
+        let status_style = if self.status_error {
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let mut status_block = Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::DarkGray));
+        if self.view == ViewMode::Board {
+            let (due_today, overdue) = self.due_summary();
+            status_block = status_block.title(Line::from(vec![
+                Span::styled(
+                    format!("today {}", due_today.len()),
+                    Style::default().fg(if due_today.is_empty() {
+                        Color::Gray
+                    } else {
+                        Color::LightYellow
+                    }),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    format!("overdue {}", overdue),
+                    Style::default().fg(if overdue == 0 {
+                        Color::Gray
+                    } else {
+                        Color::LightRed
+                    }),
+                ),
+            ]));
+        }
+        let status = Paragraph::new(Span::styled(self.status.clone(), status_style))
             .wrap(Wrap { trim: true })
-            .block(
-                Block::default()
-                    .borders(Borders::TOP)
-                    .border_style(Style::default().fg(Color::DarkGray)),
-            );
+            .block(status_block);
         f.render_widget(status, bottom[0]);
 
-        let (detail_lines, title) = self.detail_content();
-        let detail = Paragraph::new(detail_lines)
-            .wrap(Wrap { trim: true })
-            .block(
-                Block::default()
-                    .borders(Borders::TOP)
-                    .border_style(Style::default().fg(Color::DarkGray))
-                    .title(title),
-            );
+        // Bodies come pre-wrapped, so each line is one row and the cut is exact.
+        let (detail_lines, title) = self.detail_content(bottom[1].width as usize);
+        let rows = bottom[1].height.saturating_sub(1) as usize;
+        let detail = Paragraph::new(fit_lines(detail_lines, rows)).block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::DarkGray))
+                .title(title),
+        );
         f.render_widget(detail, bottom[1]);
     }
 
+    /// Focus-mode view for timeline/project: the selected note's details full-screen.
+    fn draw_zen_note(&self, f: &mut ratatui::Frame<'_>, area: Rect) {
+        let (lines, title) = self.detail_content(area.width.saturating_sub(2) as usize);
+        let detail = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray))
+                .title(title),
+        );
+        f.render_widget(detail, area);
+    }
+
     fn footer_help_line(&self) -> Line<'static> {
         let mut spans = vec![
             Span::styled("1", Style::default().fg(Color::LightCyan)),
@@ -1060,41 +2788,85 @@ impl App {
             Span::styled("3", Style::default().fg(Color::LightCyan)),
             Span::raw(" project  "),
         ];
+        // Red move keys warn that the target column is at its WIP limit and will refuse the move.
+        let move_key = |delta| {
+            if self.move_target_full(delta) {
+                Style::default().fg(Color::LightRed)
+            } else {
+                Style::default().fg(Color::LightGreen)
+            }
+        };
         match self.view {
             ViewMode::Board => spans.extend([
                 Span::styled("←↑↓→ / h j k l", Style::default().fg(Color::LightCyan)),
                 Span::raw(" move  "),
-                Span::styled("m/>", Style::default().fg(Color::LightGreen)),
+                Span::styled("m/>", move_key(1)),
                 Span::raw(" forward  "),
-                Span::styled("b/<", Style::default().fg(Color::LightGreen)),
+                Span::styled("b/<", move_key(-1)),
                 Span::raw(" back  "),
+                Span::styled("s", Style::default().fg(Color::LightCyan)),
+                Span::raw(" lanes  "),
+                Span::styled("Space", Style::default().fg(Color::LightGreen)),
+                Span::raw(" select  "),
+                Span::styled("c", Style::default().fg(Color::LightCyan)),
+                Span::raw(" color  "),
+                Span::styled("o", Style::default().fg(Color::LightCyan)),
+                Span::raw(" sort  "),
+                Span::styled("v", Style::default().fg(Color::LightCyan)),
+                Span::raw(" compact  "),
                 Span::styled("n", Style::default().fg(Color::LightMagenta)),
                 Span::raw(" new  "),
                 Span::styled("e", Style::default().fg(Color::LightYellow)),
                 Span::raw(" edit  "),
+                Span::styled("g", Style::default().fg(Color::LightGreen)),
+                Span::raw(" to column  "),
+                Span::styled("T", Style::default().fg(Color::LightYellow)),
+                Span::raw(" tags  "),
+                Span::styled("+/-", Style::default().fg(Color::LightYellow)),
+                Span::raw(" priority  "),
+                Span::styled("D", Style::default().fg(Color::LightYellow)),
+                Span::raw(" clear due  "),
                 Span::styled("d", Style::default().fg(Color::LightRed)),
                 Span::raw(" delete  "),
                 Span::styled("q", Style::default().fg(Color::LightRed)),
                 Span::raw(" quit"),
             ]),
-            ViewMode::Timeline => spans.extend([
-                Span::styled("Tab", Style::default().fg(Color::LightCyan)),
-                Span::raw(" focus  "),
-                Span::styled("←→", Style::default().fg(Color::LightCyan)),
-                Span::raw(" move focus/day  "),
-                Span::styled("↑↓", Style::default().fg(Color::LightCyan)),
-                Span::raw(" browse  "),
-                Span::styled("Enter", Style::default().fg(Color::LightYellow)),
-                Span::raw(" jump to day  "),
-                Span::styled("n", Style::default().fg(Color::LightMagenta)),
-                Span::raw(" new  "),
-                Span::styled("e", Style::default().fg(Color::LightYellow)),
-                Span::raw(" edit  "),
-                Span::styled("d", Style::default().fg(Color::LightRed)),
-                Span::raw(" delete  "),
-                Span::styled("q", Style::default().fg(Color::LightRed)),
-                Span::raw(" quit"),
-            ]),
+            ViewMode::Timeline => {
+                spans.extend([
+                    Span::styled("Tab", Style::default().fg(Color::LightCyan)),
+                    Span::raw(" focus  "),
+                    Span::styled("←→", Style::default().fg(Color::LightCyan)),
+                    Span::raw(" move focus/day  "),
+                    Span::styled("↑↓", Style::default().fg(Color::LightCyan)),
+                    Span::raw(" browse  "),
+                    Span::styled("Enter", Style::default().fg(Color::LightYellow)),
+                    Span::raw(" jump to day  "),
+                ]);
+                // In the Unassigned list `t` sets a due date instead of toggling day-only.
+                spans.extend(if self.timeline.focus == TimelineFocus::Unassigned {
+                    [
+                        Span::styled("t/w", Style::default().fg(Color::LightYellow)),
+                        Span::raw(" due today/week  "),
+                    ]
+                } else {
+                    [
+                        Span::styled("t", Style::default().fg(Color::LightCyan)),
+                        Span::raw(" day-only  "),
+                    ]
+                });
+                spans.extend([
+                    Span::styled("n", Style::default().fg(Color::LightMagenta)),
+                    Span::raw(" new  "),
+                    Span::styled("e", Style::default().fg(Color::LightYellow)),
+                    Span::raw(" edit  "),
+                    Span::styled("D", Style::default().fg(Color::LightYellow)),
+                    Span::raw(" clear due  "),
+                    Span::styled("d", Style::default().fg(Color::LightRed)),
+                    Span::raw(" delete  "),
+                    Span::styled("q", Style::default().fg(Color::LightRed)),
+                    Span::raw(" quit"),
+                ]);
+            }
             ViewMode::Project => spans.extend([
                 Span::styled("Tab", Style::default().fg(Color::LightCyan)),
                 Span::raw(" focus  "),
@@ -1106,6 +2878,8 @@ impl App {
                 Span::raw(" new  "),
                 Span::styled("e", Style::default().fg(Color::LightYellow)),
                 Span::raw(" edit  "),
+                Span::styled("D", Style::default().fg(Color::LightYellow)),
+                Span::raw(" clear due  "),
                 Span::styled("d", Style::default().fg(Color::LightRed)),
                 Span::raw(" delete  "),
                 Span::styled("q", Style::default().fg(Color::LightRed)),
@@ -1115,23 +2889,27 @@ impl App {
         Line::from(spans)
     }
 
-    fn detail_content(&self) -> (Vec<Line<'static>>, String) {
+    /// Lines for the detail pane, with note bodies wrapped to `width`.
+    fn detail_content(&self, width: usize) -> (Vec<Line<'static>>, String) {
         match self.view {
-            ViewMode::Board => self.board_detail_content(),
-            ViewMode::Timeline => self.timeline_detail_content(),
-            ViewMode::Project => self.project_detail_content(),
+            ViewMode::Board => self.board_detail_content(width),
+            ViewMode::Timeline => self.timeline_detail_content(width),
+            ViewMode::Project => self.project_detail_content(width),
         }
     }
 
-    fn board_detail_content(&self) -> (Vec<Line<'static>>, String) {
+    fn board_detail_content(&self, width: usize) -> (Vec<Line<'static>>, String) {
         if let Some((_, note)) = self.current_note() {
-            (vec![selected_note_detail(note)], "Selected".into())
+            (
+                selected_note_detail(note, &self.render_opts(), width),
+                "Selected".into(),
+            )
         } else {
             (vec![Line::from("No note selected")], "Selected".into())
         }
     }
 
-    fn timeline_detail_content(&self) -> (Vec<Line<'static>>, String) {
+    fn timeline_detail_content(&self, width: usize) -> (Vec<Line<'static>>, String) {
         if self.timeline.focus == TimelineFocus::Calendar {
             let date = self.timeline.calendar_cursor;
             let mut lines = vec![Line::from(Span::styled(
@@ -1150,17 +2928,23 @@ impl App {
             }
             (lines, "Calendar".into())
         } else if let Some((_, note)) = self.current_timeline_note() {
-            (vec![selected_note_detail(note)], "Selected".into())
+            (
+                selected_note_detail(note, &self.render_opts(), width),
+                "Selected".into(),
+            )
         } else {
             (vec![Line::from("No note selected")], "Selected".into())
         }
     }
 
-    fn project_detail_content(&self) -> (Vec<Line<'static>>, String) {
-        let tags = self.project_tags();
+    fn project_detail_content(&self, width: usize) -> (Vec<Line<'static>>, String) {
+        let tags = self.project_tags(self.project.tag_order);
         if self.project.focus == ProjectFocus::Notes {
             if let Some((_, note)) = self.current_project_note() {
-                return (vec![selected_note_detail(note)], "Selected".into());
+                return (
+                    selected_note_detail(note, &self.render_opts(), width),
+                    "Selected".into(),
+                );
             }
             return (vec![Line::from("No task selected")], "Selected".into());
         }
@@ -1180,36 +2964,224 @@ impl App {
     }
     fn draw_form(&self, f: &mut ratatui::Frame<'_>, title: &str, form: &NoteForm) {
         let area = centered_rect(70, 60, f.size());
+        // Fields are wrapped here rather than by the paragraph so that
+        // `form_hit` can map clicks back onto the same rows.
+        let width = area.width.saturating_sub(2) as usize;
         let mut fields = Vec::new();
-        fields.extend(field_lines(
-            "Title",
-            &form.title,
-            form.field == FormField::Title,
-        ));
-        fields.extend(field_lines(
-            "Body",
-            &form.body,
-            form.field == FormField::Body,
-        ));
-        fields.extend(field_lines(
-            "Tags",
-            &form.tags,
-            form.field == FormField::Tags,
+        for (label, value, field) in form.fields() {
+            fields.extend(form_field_lines(label, value, form.field == field, width));
+        }
+        fields.extend(
+            wrap_text(
+                "Ctrl+Enter to save • Alt+Enter to save and keep editing • Esc to cancel • Tab/Shift-Tab to move • Enter adds newline in Body",
+                width,
+            )
+            .into_iter()
+            .map(|line| Line::from(Span::styled(line, Style::default().fg(Color::Gray)))),
+        );
+        let dialog = Paragraph::new(fields).block(
+            Block::default()
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    /// Same numbers as `postit stats`, for the board as it is now.
+    fn draw_stats(&self, f: &mut ratatui::Frame<'_>) {
+        let stats = board_stats(&self.board, Utc::now(), self.zone);
+        let area = centered_rect(50, 60, f.size());
+        let label = Style::default().fg(Color::Gray);
+        let row = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{:<12}", name), label),
+                Span::raw(value),
+            ])
+        };
+        let mut lines = vec![
+            row(
+                "Notes",
+                format!(
+                    "{} ({} done, {:.0}%)",
+                    stats.notes,
+                    stats.done,
+                    stats.completion * 100.0
+                ),
+            ),
+            Line::from(vec![
+                Span::styled(format!("{:<12}", "Overdue"), label),
+                Span::styled(
+                    stats.overdue.to_string(),
+                    if stats.overdue > 0 {
+                        Style::default().fg(Color::Red)
+                    } else {
+                        Style::default()
+                    },
+                ),
+            ]),
+            row("Due today", stats.due_today.to_string()),
+            row("Untagged", stats.untagged.to_string()),
+        ];
+        if stats.unfiled > 0 {
+            lines.push(row("Unfiled", stats.unfiled.to_string()));
+        }
+        lines.push(Line::from(""));
+        for column in &stats.columns {
+            lines.push(row(&format!("  {}", column.id), column.notes.to_string()));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Press any key to close",
+            Style::default().fg(Color::Gray),
+        )));
+        let dialog = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!("Stats: {}", stats.board),
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_column_info(&self, f: &mut ratatui::Frame<'_>) {
+        let area = centered_rect(50, 30, f.size());
+        let (title, description) = match self.board.columns.get(self.selected_column) {
+            Some(column) => (
+                column.label().to_string(),
+                match &column.description {
+                    Some(text) => Span::raw(text.clone()),
+                    None => Span::styled(
+                        format!(
+                            "No description. Add one with `postit column describe {} <text>`",
+                            column.id
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                },
+            ),
+            None => (
+                "Unfiled".to_string(),
+                Span::raw("Notes that no column lists; move one to file it."),
+            ),
+        };
+        let lines = vec![
+            Line::from(description),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press any key to close",
+                Style::default().fg(Color::Gray),
+            )),
+        ];
+        let dialog = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        title,
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_onboarding(&self, f: &mut ratatui::Frame<'_>) {
+        let area = centered_rect(50, 40, f.size());
+        let key = |k: &'static str| {
+            Span::styled(
+                k,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        let lines = vec![
+            Line::from("This board has no notes yet."),
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("Press "),
+                key("n"),
+                Span::raw(" to add your first note"),
+            ]),
+            Line::from(vec![
+                Span::raw("Press "),
+                key("1"),
+                Span::raw(" / "),
+                key("2"),
+                Span::raw(" / "),
+                key("3"),
+                Span::raw(" for board, timeline, and project views"),
+            ]),
+            Line::from("The bar at the bottom lists the keys for each view"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press any key to dismiss",
+                Style::default().fg(Color::Gray),
+            )),
+        ];
+        let dialog = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Welcome to postit",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_column_form(&self, f: &mut ratatui::Frame<'_>, form: &ColumnForm) {
+        let area = centered_rect(60, 30, f.size());
+        let mut lines = field_lines("Id", &form.id, form.field == ColumnFormField::Id);
+        lines.extend(field_lines(
+            "Name (optional)",
+            &form.name,
+            form.field == ColumnFormField::Name,
         ));
-        fields.extend(field_lines(
-            "Due (YYYY.MM.DD@hh:mm)",
-            &form.due,
-            form.field == FormField::Due,
+        lines.extend(field_lines(
+            "WIP limit (optional)",
+            &form.wip_limit,
+            form.field == ColumnFormField::WipLimit,
         ));
-        fields.push(Line::from(Span::styled(
-            "Ctrl+Enter to save • Esc to cancel • Tab/Shift-Tab to move • Enter adds newline in Body",
+        lines.push(Line::from(Span::styled(
+            "Enter to save • Esc to cancel • Tab to switch field",
             Style::default().fg(Color::Gray),
         )));
-        let dialog = Paragraph::new(fields)
+        let dialog = Paragraph::new(lines)
             .block(
                 Block::default()
                     .title(Span::styled(
-                        title,
+                        "New Column",
                         Style::default()
                             .fg(Color::Cyan)
                             .add_modifier(Modifier::BOLD),
@@ -1223,17 +3195,173 @@ impl App {
         f.render_widget(dialog, area);
     }
 
-    fn draw_confirm(&self, f: &mut ratatui::Frame<'_>, note_id: &str) {
-        let area = centered_rect(50, 30, f.size());
-        let title = self
+    fn draw_tags_dialog(&self, f: &mut ratatui::Frame<'_>, tags: &FieldValue) {
+        let area = centered_rect(60, 20, f.size());
+        let mut lines = field_lines("Tags (space separated)", tags, true);
+        lines.push(Line::from(Span::styled(
+            "Enter to save • Esc to cancel",
+            Style::default().fg(Color::Gray),
+        )));
+        let dialog = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Edit Tags",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_triage(&self, f: &mut ratatui::Frame<'_>, state: &TriageState) {
+        let area = centered_rect(60, 50, f.size());
+        let remaining = self
             .board
-            .notes
-            .get(note_id)
-            .map(|n| n.title.clone())
-            .unwrap_or_else(|| note_id.to_string());
+            .inbox_column_index()
+            .map(|idx| self.board.columns[idx].note_ids.len())
+            .unwrap_or(0);
+        let mut lines = Vec::new();
+        if let Some(note) = self
+            .triage_note_id(state.skipped)
+            .and_then(|id| self.board.notes.get(&id))
+        {
+            lines.push(Line::from(Span::styled(
+                note.title.clone(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            if let Some(body) = &note.body {
+                lines.push(Line::from(Span::styled(
+                    body.clone(),
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+            let opts = self.render_opts();
+            let mut meta = tag_spans(&note.tags, opts.tag_colors, usize::MAX);
+            if let Some(due) = &note.due {
+                meta.push(Span::raw("  "));
+                meta.push(Span::styled(
+                    format!("due {}", due_label(due, note.due_precision, &opts)),
+                    Style::default().fg(Color::LightYellow),
+                ));
+            }
+            lines.push(Line::from(meta));
+        }
+        lines.push(Line::from(""));
+        match &state.input {
+            Some(TriageInput::Tags(field)) => {
+                lines.extend(field_lines("Tags", field, true));
+                lines.push(Line::from("Enter to save • Esc to go back"));
+            }
+            Some(TriageInput::Due(field)) => {
+                lines.extend(field_lines("Due (YYYY.MM.DD@hh:mm)", field, true));
+                lines.push(Line::from("Enter to save • Esc to go back"));
+            }
+            None => {
+                let mut targets = Vec::new();
+                for (idx, id) in self.triage_targets().iter().enumerate() {
+                    targets.push(Span::styled(
+                        format!("{}", idx + 1),
+                        Style::default().fg(Color::LightCyan),
+                    ));
+                    targets.push(Span::raw(format!(" {}  ", id)));
+                }
+                lines.push(Line::from(targets));
+                lines.push(Line::from(Span::styled(
+                    "g tags • u due • s skip • Esc done",
+                    Style::default().fg(Color::Gray),
+                )));
+            }
+        }
+        let dialog = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!("Triage Inbox ({} left)", remaining),
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: true });
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_status_log(&self, f: &mut ratatui::Frame<'_>, filter: &FieldValue, scroll: usize) {
+        let area = centered_rect(70, 60, f.size());
+        let block = Block::default()
+            .title(Span::styled(
+                "Status log (type to filter, ↑↓ scroll, Esc close)",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(inner);
+        f.render_widget(
+            Paragraph::new(field_lines("Filter", filter, true)).wrap(Wrap { trim: false }),
+            rows[0],
+        );
+
+        let matches = self.status_log_matches(&filter.value);
+        let items = if matches.is_empty() {
+            vec![ListItem::new("No messages")]
+        } else {
+            matches
+                .iter()
+                .map(|(at, msg)| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            at.format("%H:%M:%S ").to_string(),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                        Span::raw(msg.clone()),
+                    ]))
+                })
+                .collect()
+        };
+        let viewport = rows[1].height as usize;
+        let mut state = ListState::default();
+        *state.offset_mut() = scroll.min(items.len().saturating_sub(viewport));
+        f.render_stateful_widget(List::new(items), rows[1], &mut state);
+    }
+
+    fn draw_confirm(&self, f: &mut ratatui::Frame<'_>, note_ids: &[String]) {
+        let area = centered_rect(50, 30, f.size());
+        let prompt = match note_ids {
+            [note_id] => {
+                let title = self
+                    .board
+                    .notes
+                    .get(note_id)
+                    .map(|n| n.title.clone())
+                    .unwrap_or_else(|| note_id.to_string());
+                format!("Delete \"{}\"?", title)
+            }
+            _ => format!("Delete {} selected notes?", note_ids.len()),
+        };
         let body = vec![
             Line::from(Span::styled(
-                format!("Delete \"{}\"?", title),
+                prompt,
                 Style::default()
                     .fg(Color::LightRed)
                     .add_modifier(Modifier::BOLD),
@@ -1256,104 +3384,1183 @@ impl App {
         f.render_widget(dialog, area);
     }
 
-    fn prev_column(&mut self) {
-        if self.selected_column > 0 {
-            self.selected_column -= 1;
-            self.selected_note = 0;
-        }
-    }
-
-    fn next_column(&mut self) {
-        if self.selected_column + 1 < self.board.columns.len() {
-            self.selected_column += 1;
-            self.selected_note = 0;
+    fn draw_clear_waiting(&self, f: &mut ratatui::Frame<'_>, note_id: &str) {
+        let area = centered_rect(50, 30, f.size());
+        let waiting_on = self
+            .board
+            .notes
+            .get(note_id)
+            .and_then(|n| n.waiting_on.clone())
+            .unwrap_or_default();
+        let body = vec![
+            Line::from(Span::styled(
+                format!("Still waiting on \"{}\". Clear it?", waiting_on),
+                Style::default()
+                    .fg(Color::LightYellow)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from("Press y to clear, n or Esc to keep"),
+        ];
+        let dialog = Paragraph::new(body)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Left Waiting",
+                        Style::default()
+                            .fg(Color::LightYellow)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::LightYellow)),
+            );
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_split(&self, f: &mut ratatui::Frame<'_>, note_id: &str) {
+        let area = centered_rect(50, 30, f.size());
+        let (title, items) = self
+            .board
+            .notes
+            .get(note_id)
+            .map(|n| {
+                let items = checklist_items(n.body.as_deref().unwrap_or_default()).len();
+                (n.title.clone(), items)
+            })
+            .unwrap_or_default();
+        let body = vec![
+            Line::from(Span::styled(
+                format!("Split \"{}\" into {} notes?", title, items),
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from("k or Enter keeps the original, d deletes it, Esc cancels"),
+        ];
+        let dialog = Paragraph::new(body)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "Split Note",
+                        Style::default()
+                            .fg(Color::LightCyan)
+                            .add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::LightCyan)),
+            );
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_promote(&self, f: &mut ratatui::Frame<'_>, note_id: &str) {
+        let area = centered_rect(50, 40, f.size());
+        let items = self
+            .board
+            .notes
+            .get(note_id)
+            .map(|n| checklist_items(n.body.as_deref().unwrap_or_default()))
+            .unwrap_or_default();
+        let mut lines: Vec<Line> = items
+            .iter()
+            .take(9)
+            .enumerate()
+            .map(|(idx, item)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", idx + 1),
+                        Style::default().fg(Color::LightCyan),
+                    ),
+                    Span::raw(item.clone()),
+                ])
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "1-9 turns that item into a note • Esc to cancel",
+            Style::default().fg(Color::Gray),
+        )));
+        let dialog = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .title(Span::styled(
+                    "Promote Item",
+                    Style::default()
+                        .fg(Color::LightCyan)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::LightCyan)),
+        );
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    fn draw_column_picker(&self, f: &mut ratatui::Frame<'_>, query: &FieldValue, selected: usize) {
+        let area = centered_rect(50, 50, f.size());
+        let block = Block::default()
+            .title(Span::styled(
+                "Move to column (type to filter, Enter move, Esc cancel)",
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::LightCyan));
+        let inner = block.inner(area);
+        f.render_widget(Clear, area);
+        f.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(inner);
+        f.render_widget(
+            Paragraph::new(field_lines("Filter", query, true)).wrap(Wrap { trim: false }),
+            rows[0],
+        );
+
+        let matches =
+            column_picker_matches(&self.board.columns, &self.recent_columns, &query.value);
+        if matches.is_empty() {
+            f.render_widget(Paragraph::new("No matching columns"), rows[1]);
+            return;
+        }
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|&idx| {
+                let column = &self.board.columns[idx];
+                let count = match column.wip_limit {
+                    Some(limit) => format!("{}/{}", column.note_ids.len(), limit),
+                    None => column.note_ids.len().to_string(),
+                };
+                let count_style = if column.at_wip_limit() {
+                    Style::default().fg(Color::LightRed)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let mut spans = vec![Span::raw(column.id.clone())];
+                if let Some(name) = &column.name {
+                    spans.push(Span::styled(
+                        format!("  {}", name),
+                        Style::default().fg(Color::Gray),
+                    ));
+                }
+                spans.push(Span::styled(format!("  {}", count), count_style));
+                if self.recent_columns.contains(&column.id) {
+                    spans.push(Span::styled(
+                        "  recent",
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let mut state = ListState::default();
+        state.select(Some(selected.min(items.len() - 1)));
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::LightCyan)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_stateful_widget(list, rows[1], &mut state);
+    }
+
+    fn draw_demote(&self, f: &mut ratatui::Frame<'_>, note_id: &str, parent: &FieldValue) {
+        let area = centered_rect(60, 20, f.size());
+        let mut lines = field_lines("Parent note id", parent, true);
+        let target = match self.board.notes.get(parent.value.trim()) {
+            Some(note) if note.id != note_id => Span::styled(
+                format!("-> {}", note.title),
+                Style::default().fg(Color::LightGreen),
+            ),
+            _ => Span::styled("no such note", Style::default().fg(Color::DarkGray)),
+        };
+        lines.push(Line::from(target));
+        lines.push(Line::from(Span::styled(
+            "Enter to fold the note into it • Esc to cancel",
+            Style::default().fg(Color::Gray),
+        )));
+        let dialog = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .title(Span::styled(
+                    format!("Make {} a Checklist Item", note_id),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        f.render_widget(Clear, area);
+        f.render_widget(dialog, area);
+    }
+
+    /// Real columns plus the "Unfiled" bucket while any orphaned notes exist.
+    fn board_column_count(&self) -> usize {
+        let unfiled = !self.board.orphan_notes().is_empty();
+        self.board.columns.len() + usize::from(unfiled)
+    }
+
+    fn is_unfiled(&self, col_idx: usize) -> bool {
+        col_idx == self.board.columns.len()
+    }
+
+    fn column_note_ids(&self, col_idx: usize) -> Vec<&str> {
+        let mut ids = if self.is_unfiled(col_idx) {
+            self.board.orphan_notes()
+        } else {
+            self.board.ordered_note_ids(col_idx)
+        };
+        if self.tag_filter.is_some() {
+            ids.retain(|id| {
+                self.board
+                    .notes
+                    .get(*id)
+                    .is_some_and(|n| self.passes_tag_filter(n))
+            });
+        }
+        ids
+    }
+
+    fn passes_tag_filter(&self, note: &Note) -> bool {
+        match self.tag_filter.as_deref() {
+            None => true,
+            Some(UNTAGGED) => note.tags.is_empty(),
+            Some(tag) => note.tags.iter().any(|t| t == tag),
+        }
+    }
+
+    /// Whether the board leaves out column `col_idx`: the done column while
+    /// it is hidden, unless it is the only column.
+    fn column_hidden(&self, col_idx: usize) -> bool {
+        self.hide_done
+            && self.board_column_count() > 1
+            && self
+                .board
+                .columns
+                .get(col_idx)
+                .is_some_and(|c| c.id == self.board.done_column_id())
+    }
+
+    /// Moves the selection off a hidden column, to its left neighbor if any.
+    fn leave_hidden_column(&mut self) {
+        if self.column_hidden(self.selected_column) {
+            self.selected_column = self.selected_column.checked_sub(1).unwrap_or(1);
+            self.selected_note = 0;
+        }
+    }
+
+    fn toggle_hide_done(&mut self) {
+        let current = self.current_board_note().map(|(id, _)| id.to_string());
+        self.hide_done = !self.hide_done;
+        self.leave_hidden_column();
+        if let Some(id) = current {
+            self.select_board_note(&id);
+        }
+        self.status = if self.hide_done {
+            format!(
+                "Column {} hidden (H shows it; moves there still work)",
+                self.board.done_column_id()
+            )
+        } else {
+            format!("Column {} shown", self.board.done_column_id())
+        };
+    }
+
+    fn prev_column(&mut self) {
+        self.step_column(false);
+    }
+
+    fn next_column(&mut self) {
+        self.step_column(true);
+    }
+
+    /// Selects the next or previous column, skipping a hidden one.
+    fn step_column(&mut self, forward: bool) {
+        let wrap = self.config.wrap_navigation;
+        let count = self.board_column_count();
+        let mut next = step_index(self.selected_column, count, forward, wrap);
+        if let Some(idx) = next.filter(|&idx| self.column_hidden(idx)) {
+            next = step_index(idx, count, forward, wrap);
+        }
+        if let Some(idx) = next.filter(|&idx| !self.column_hidden(idx)) {
+            self.selected_column = idx;
+            self.selected_note = 0;
         }
     }
 
     fn prev_note(&mut self) {
         if self.selected_note > 0 {
             self.selected_note -= 1;
+            return;
+        }
+        let lanes = if self.swimlanes.is_some() {
+            self.board_lanes().len()
+        } else {
+            1
+        };
+        let wrap = self.config.wrap_navigation;
+        if let Some(lane) = step_index(self.selected_lane, lanes, false, wrap) {
+            self.selected_lane = lane;
+            self.selected_note = self
+                .visible_note_ids(self.selected_column)
+                .len()
+                .saturating_sub(1);
         }
     }
 
     fn next_note(&mut self) {
-        if let Some(column) = self.board.columns.get(self.selected_column) {
-            if self.selected_note + 1 < column.note_ids.len() {
-                self.selected_note += 1;
+        let visible = self.visible_note_ids(self.selected_column).len();
+        if self.selected_note + 1 < visible {
+            self.selected_note += 1;
+            return;
+        }
+        let lanes = if self.swimlanes.is_some() {
+            self.board_lanes().len()
+        } else {
+            1
+        };
+        let wrap = self.config.wrap_navigation;
+        if let Some(lane) = step_index(self.selected_lane, lanes, true, wrap) {
+            self.selected_lane = lane;
+            self.selected_note = 0;
+        }
+    }
+
+    /// Moves the selected column left or right; the selection goes with it.
+    fn shift_column(&mut self, delta: isize) -> Result<()> {
+        let Some(column_id) = self.current_column_id() else {
+            return Ok(());
+        };
+        let from = self.selected_column;
+        let to = self.board.reorder_column(&column_id, delta)?;
+        if to == from {
+            return Ok(());
+        }
+        if from < self.scroll_offsets.len() && to < self.scroll_offsets.len() {
+            let offset = self.scroll_offsets.remove(from);
+            self.scroll_offsets.insert(to, offset);
+        }
+        self.selected_column = to;
+        self.persist(format!("Moved column {} to position {}", column_id, to + 1))
+    }
+
+    fn cycle_column_color(&mut self) -> Result<()> {
+        let column = match self.board.columns.get(self.selected_column) {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let next = next_column_color(column.color.as_deref());
+        let column_id = column.id.clone();
+        self.board
+            .set_column_color(&column_id, next.map(|c| c.to_string()))?;
+        self.persist(format!(
+            "Column {} color: {}",
+            column_id,
+            next.unwrap_or("default")
+        ))
+    }
+
+    fn cycle_column_sort(&mut self) -> Result<()> {
+        let Some(column) = self.board.columns.get(self.selected_column) else {
+            return Ok(());
+        };
+        let next = SortKey::cycle(column.sort);
+        let column_id = column.id.clone();
+        let current = self.current_board_note().map(|(id, _)| id.to_string());
+        self.board.set_column_sort(&column_id, next)?;
+        if let Some(id) = current {
+            self.select_board_note(&id);
+        }
+        self.persist(format!(
+            "Column {} sort: {}",
+            column_id,
+            next.map(|s| s.to_string())
+                .unwrap_or_else(|| "manual".into())
+        ))
+    }
+
+    fn cycle_swimlanes(&mut self) {
+        let current = self.current_board_note().map(|(id, _)| id.to_string());
+        self.swimlanes = LaneKey::cycle(self.swimlanes);
+        self.selected_lane = 0;
+        self.selected_note = 0;
+        if let Some(id) = current {
+            self.select_board_note(&id);
+        }
+        self.status = match self.swimlanes {
+            Some(key) => format!("Swimlanes by {}", key.label()),
+            None => "Swimlanes off".into(),
+        };
+    }
+
+    fn select_board_note(&mut self, note_id: &str) {
+        let col_idx = match self.board.find_note_column_index(note_id) {
+            Some(idx) if !self.column_hidden(idx) => idx,
+            _ => return,
+        };
+        self.selected_column = col_idx;
+        if let Some(key) = self.swimlanes {
+            if let Some(note) = self.board.notes.get(note_id) {
+                let (_, lane) = key.lane(note);
+                self.selected_lane = self
+                    .board_lanes()
+                    .iter()
+                    .position(|l| l == lane)
+                    .unwrap_or(0);
+            }
+        }
+        self.selected_note = self
+            .visible_note_ids(col_idx)
+            .iter()
+            .position(|id| *id == note_id)
+            .unwrap_or(0);
+    }
+
+    fn move_selected(&mut self, delta: isize) -> Result<()> {
+        let len = self.board.columns.len();
+        let cycle = self.config.cycle_move;
+        self.move_selected_with(|src| move_target(src, len, delta, cycle), 0)
+    }
+
+    /// Whether moving the selected note by `delta` columns lands it in a
+    /// column that already holds its WIP limit.
+    fn move_target_full(&self, delta: isize) -> bool {
+        if self.current_board_note().is_none() || self.is_unfiled(self.selected_column) {
+            return false;
+        }
+        let len = self.board.columns.len();
+        let target = move_target(self.selected_column, len, delta, self.config.cycle_move);
+        if target == self.selected_column {
+            return false;
+        }
+        self.board
+            .columns
+            .get(target)
+            .is_some_and(Column::at_wip_limit)
+    }
+
+    fn open_column_picker(&mut self) {
+        if self.marked.is_empty() && self.current_board_note().is_none() {
+            self.status = "No note selected to move".into();
+            return;
+        }
+        self.mode = Mode::ColumnPicker {
+            query: FieldValue::new(""),
+            selected: 0,
+        };
+    }
+
+    /// Moves the selected (or marked) notes straight to the column at `target_idx`.
+    fn move_selected_to(&mut self, target_idx: usize) -> Result<()> {
+        if target_idx >= self.board.columns.len() {
+            self.status = format!(
+                "No column {}; the board has {}",
+                target_idx + 1,
+                self.board.columns.len()
+            );
+            return Ok(());
+        }
+        let column_id = self.board.columns[target_idx].id.clone();
+        self.recent_columns.retain(|id| *id != column_id);
+        self.recent_columns.insert(0, column_id);
+        self.move_selected_with(|_| target_idx, target_idx)
+    }
+
+    /// Moves the selected (or marked) notes from column `src` to `target(src)`;
+    /// unfiled notes are filed into `file_idx`.
+    fn move_selected_with(
+        &mut self,
+        target: impl Fn(usize) -> usize,
+        file_idx: usize,
+    ) -> Result<()> {
+        if self.board.columns.is_empty() {
+            self.status = "No columns to move between".into();
+            return Ok(());
+        }
+        if !self.marked.is_empty() {
+            return self.move_marked(target);
+        }
+        if self.current_note().is_none() {
+            self.status = "No note selected to move".into();
+            return Ok(());
+        }
+        if self.is_unfiled(self.selected_column) {
+            return self.file_selected(file_idx);
+        }
+        let target = target(self.selected_column);
+        if target == self.selected_column {
+            return Ok(());
+        }
+        let from = self.board.columns[self.selected_column].id.clone();
+        if let Err(err) = self.move_to_column(target) {
+            self.status = format!("Move failed: {}", err);
+            self.status_error = true;
+            return Ok(());
+        }
+        let dest = self
+            .board
+            .columns
+            .get(target)
+            .map(|c| c.id.clone())
+            .unwrap_or_default();
+        self.persist(format!("Moved to {} (u moves it back)", dest))?;
+        if let Some((note_id, _)) = self.current_board_note() {
+            if self.board.stale_waiting_on(note_id, &from).is_some() {
+                self.mode = Mode::ConfirmClearWaiting {
+                    note_id: note_id.to_string(),
+                };
+            }
+        }
+        Ok(())
+    }
+
+    fn move_marked(&mut self, target: impl Fn(usize) -> usize) -> Result<()> {
+        // The selection stays put after a batch move whatever `stay_after_move`
+        // says; there is no single note to follow.
+        let mut moved = 0;
+        let mut failed = Vec::new();
+        for note_id in self.marked_in_board_order() {
+            let src = match self.board.find_note_column_index(&note_id) {
+                Some(idx) => idx,
+                None => {
+                    failed.push(note_id);
+                    continue;
+                }
+            };
+            let target = target(src);
+            if target == src {
+                continue;
+            }
+            let from = self.board.columns[src].id.clone();
+            let dest = self.board.columns[target].id.clone();
+            let origin = self.board.note_position(&note_id);
+            match self.board.move_note(&note_id, &dest) {
+                Ok(()) => {
+                    self.remember_move(&note_id, origin);
+                    moved += 1;
+                    self.queue_event(&note_id, LogOp::Move { from, to: dest });
+                }
+                Err(_) => failed.push(note_id),
+            }
+        }
+        self.marked.clear();
+        if failed.is_empty() {
+            self.persist(format!("Moved {} selected notes", moved))
+        } else {
+            self.persist(format!(
+                "Moved {} selected notes; failed: {}",
+                moved,
+                failed.join(", ")
+            ))?;
+            self.status_error = true;
+            Ok(())
+        }
+    }
+
+    /// Opens the selected note's first attachment with the OS default handler.
+    fn open_attachment(&mut self) {
+        let Some((_, note)) = self.current_note() else {
+            self.status = "No note selected".into();
+            return;
+        };
+        let Some(target) = note.attachments.first().cloned() else {
+            self.status = "Note has no attachments".into();
+            return;
+        };
+        self.status = if attachment_missing(&target) {
+            format!("Attachment missing: {}", target)
+        } else {
+            match open::that_detached(&target) {
+                Ok(()) => format!("Opened {}", target),
+                Err(err) => format!("Could not open {}: {}", target, err),
+            }
+        };
+    }
+
+    /// Merges every marked note into the note under the cursor.
+    fn merge_marked(&mut self) -> Result<()> {
+        let Some((keep, _)) = self.current_note() else {
+            self.status = "No note selected to merge into".into();
+            return Ok(());
+        };
+        let keep = keep.to_string();
+        let absorb: Vec<String> = self
+            .marked_in_board_order()
+            .into_iter()
+            .filter(|id| *id != keep)
+            .collect();
+        if absorb.is_empty() {
+            self.status = "Mark notes with Space, then press M on the note to keep".into();
+            return Ok(());
+        }
+        // Merge into a copy so a failure partway leaves the board as it was.
+        let mut board = self.board.clone();
+        let mut deleted = Vec::new();
+        let mut merged = None;
+        for note_id in &absorb {
+            let column = match board.find_note_column_index(note_id) {
+                Some(idx) => board.columns[idx].id.clone(),
+                None => UNFILED.to_string(),
+            };
+            let title = board.notes.get(note_id).map(|n| n.title.clone());
+            match board.merge_notes(&keep, note_id) {
+                Ok(note) => {
+                    deleted.push((note_id, column, title.unwrap_or_default()));
+                    merged = Some(note);
+                }
+                Err(err) => {
+                    self.status = format!("Merge failed: {}", err);
+                    self.status_error = true;
+                    return Ok(());
+                }
+            }
+        }
+        self.board = board;
+        for (note_id, column, title) in deleted {
+            self.queue_event(note_id, LogOp::Delete { column, title });
+        }
+        if let Some(note) = merged {
+            self.queue_event(&keep, LogOp::Edit { title: note.title });
+        }
+        self.marked.clear();
+        if self.view == ViewMode::Board {
+            self.select_board_note(&keep);
+        }
+        self.persist(format!("Merged {} into {}", absorb.join(", "), keep))
+    }
+
+    /// Offers to split the selected note's checklist into separate notes.
+    fn start_split(&mut self) {
+        let Some((note_id, note)) = self.current_note() else {
+            self.status = "No note selected to split".into();
+            return;
+        };
+        let note_id = note_id.to_string();
+        let items = checklist_items(note.body.as_deref().unwrap_or_default()).len();
+        if items == 0 {
+            self.status = format!("{} has no - or * items in its body to split", note_id);
+        } else if self.board.find_note_column_index(&note_id).is_none() {
+            self.status = format!("File {} into a column before splitting it", note_id);
+        } else {
+            self.status = format!(
+                "Split {} into {} notes? (k keep original, d delete it, Esc cancel)",
+                note_id, items
+            );
+            self.mode = Mode::ConfirmSplit { note_id };
+        }
+    }
+
+    /// Adds one note per checklist item to the note's column, optionally
+    /// removing the original, and saves them all at once.
+    fn split_note(&mut self, note_id: &str, keep: bool) -> Result<()> {
+        let Some(note) = self.board.notes.get(note_id) else {
+            return Ok(());
+        };
+        let Some(column_id) = self
+            .board
+            .find_note_column_index(note_id)
+            .map(|idx| self.board.columns[idx].id.clone())
+        else {
+            return Ok(());
+        };
+        let title = note.title.clone();
+        let issued = RefCell::new(HashSet::new());
+        let parts = note.split_items(|| {
+            let id = generate_id(&self.config, |id| {
+                self.board.id_in_use(id) || issued.borrow().contains(id)
+            });
+            issued.borrow_mut().insert(id.clone());
+            id
+        });
+        let mut board = self.board.clone();
+        for part in &parts {
+            board.add_note(part.clone(), &column_id)?;
+        }
+        if !keep {
+            board.remove_note(note_id)?;
+        }
+        self.board = board;
+        for part in &parts {
+            self.queue_event(
+                &part.id,
+                LogOp::Add {
+                    column: column_id.clone(),
+                    title: part.title.clone(),
+                },
+            );
+        }
+        if !keep {
+            self.queue_event(
+                note_id,
+                LogOp::Delete {
+                    column: column_id,
+                    title,
+                },
+            );
+        }
+        if let Some(first) = parts.first() {
+            self.select_board_note(&first.id);
+        }
+        self.persist(if keep {
+            format!("Split {} into {} notes", note_id, parts.len())
+        } else {
+            format!(
+                "Split {} into {} notes and deleted it",
+                note_id,
+                parts.len()
+            )
+        })
+    }
+
+    /// Asks which checklist item of the selected note to turn into a note.
+    fn start_promote(&mut self) {
+        let Some((note_id, note)) = self.current_note() else {
+            self.status = "No note selected".into();
+            return;
+        };
+        let note_id = note_id.to_string();
+        let items = checklist_items(note.body.as_deref().unwrap_or_default()).len();
+        if items == 0 {
+            self.status = format!("{} has no - or * items in its body to promote", note_id);
+        } else if self.board.find_note_column_index(&note_id).is_none() {
+            self.status = format!("File {} into a column before promoting its items", note_id);
+        } else {
+            self.status = "Promote which item? (1-9, Esc cancel)".into();
+            self.mode = Mode::PromoteItem { note_id };
+        }
+    }
+
+    fn promote_item(&mut self, note_id: &str, index: usize) -> Result<()> {
+        let new_id = generate_id(&self.config, |id| self.board.id_in_use(id));
+        let child = self.board.promote_item(note_id, index, new_id)?;
+        let column = self
+            .board
+            .find_note_column_index(&child.id)
+            .map(|idx| self.board.columns[idx].id.clone())
+            .unwrap_or_else(|| UNFILED.to_string());
+        self.queue_event(
+            &child.id,
+            LogOp::Add {
+                column,
+                title: child.title.clone(),
+            },
+        );
+        self.select_board_note(&child.id);
+        self.persist(format!(
+            "Promoted \"{}\" from {} to {}",
+            child.title, note_id, child.id
+        ))
+    }
+
+    /// Asks which note the selected one should become a checklist item of,
+    /// starting from the single marked note if there is one.
+    fn start_demote(&mut self) {
+        let Some((note_id, _)) = self.current_note() else {
+            self.status = "No note selected".into();
+            return;
+        };
+        let note_id = note_id.to_string();
+        let parent = match self.marked.iter().collect::<Vec<_>>().as_slice() {
+            [only] if **only != note_id => only.to_string(),
+            _ => String::new(),
+        };
+        self.status = format!("Make {} a checklist item of which note?", note_id);
+        self.mode = Mode::DemoteNote {
+            note_id,
+            parent: FieldValue::new(&parent),
+        };
+    }
+
+    fn demote_note(&mut self, note_id: &str, parent_id: &str) -> Result<()> {
+        let column = self
+            .board
+            .find_note_column_index(note_id)
+            .map(|idx| self.board.columns[idx].id.clone())
+            .unwrap_or_else(|| UNFILED.to_string());
+        let title = self.board.notes.get(note_id).map(|n| n.title.clone());
+        let parent = self.board.demote_note(note_id, parent_id)?;
+        self.marked.remove(note_id);
+        self.queue_event(
+            note_id,
+            LogOp::Delete {
+                column,
+                title: title.unwrap_or_default(),
+            },
+        );
+        self.queue_event(
+            parent_id,
+            LogOp::Edit {
+                title: parent.title,
+            },
+        );
+        self.select_board_note(parent_id);
+        self.persist(format!(
+            "Made {} a checklist item of {}",
+            note_id, parent_id
+        ))
+    }
+
+    fn start_triage(&mut self) {
+        if self.board.inbox_column_index().is_none() {
+            self.status = "No inbox column (run `postit column set-inbox <id>`)".into();
+        } else if self.triage_note_id(0).is_none() {
+            self.status = "Inbox is empty".into();
+        } else {
+            self.status = "Triage: 1-9 column, g tags, u due, s skip, Esc done".into();
+            self.mode = Mode::Triage(TriageState {
+                skipped: 0,
+                input: None,
+            });
+        }
+    }
+
+    fn triage_note_id(&self, skipped: usize) -> Option<String> {
+        let inbox = self.board.inbox_column_index()?;
+        let ids = self.board.ordered_note_ids(inbox);
+        if ids.is_empty() {
+            return None;
+        }
+        Some(ids[skipped % ids.len()].to_string())
+    }
+
+    /// Columns a triaged note can be sent to, numbered 1-9 in the dialog.
+    fn triage_targets(&self) -> Vec<String> {
+        self.board
+            .columns
+            .iter()
+            .filter(|c| !c.is_inbox)
+            .take(9)
+            .map(|c| c.id.clone())
+            .collect()
+    }
+
+    fn start_tag_edit(&mut self) {
+        let Some((id, note)) = self.current_note() else {
+            self.status = "No note selected".into();
+            return;
+        };
+        let note_id = id.to_string();
+        let tags = FieldValue::new(&note.tags.join(" "));
+        self.status = format!("Tags for {} (Enter save, Esc cancel)", note_id);
+        self.mode = Mode::EditingTags { note_id, tags };
+    }
+
+    fn clear_due(&mut self) -> Result<()> {
+        let (note_id, has_due) = match self.current_note() {
+            Some((id, note)) => (id.to_string(), note.due.is_some()),
+            None => {
+                self.status = "No note selected".into();
+                return Ok(());
+            }
+        };
+        if !has_due {
+            self.status = format!("{} has no due date", note_id);
+            return Ok(());
+        }
+        self.board.update_note(&note_id, |note| {
+            note.due = None;
+            note.due_precision = DuePrecision::default();
+        })?;
+        self.persist(format!("Cleared due date on {}", note_id))
+    }
+
+    fn step_priority(&mut self, up: bool) -> Result<()> {
+        let Some((note_id, note)) = self.current_board_note() else {
+            self.status = "No note selected".into();
+            return Ok(());
+        };
+        let note_id = note_id.to_string();
+        let priority = Priority::step(note.priority, up);
+        let title = note.title.clone();
+        self.board
+            .update_note(&note_id, |note| note.priority = priority)?;
+        if self.swimlanes == Some(LaneKey::Priority) {
+            self.select_board_note(&note_id);
+        }
+        self.queue_event(&note_id, LogOp::Edit { title });
+        self.persist(match priority {
+            Some(priority) => format!("{} priority: {}", note_id, priority.label()),
+            None => format!("{} priority cleared", note_id),
+        })
+    }
+
+    /// Gives the selected unassigned note a due date at the end of today, or
+    /// of the week when `week` is set, which moves it to Assigned.
+    fn quick_due(&mut self, week: bool) -> Result<()> {
+        let Some((note_id, _)) = self.current_timeline_note() else {
+            self.status = "No note selected".into();
+            return Ok(());
+        };
+        let note_id = note_id.to_string();
+        let now = Utc::now();
+        let days_ahead = if week {
+            let today = in_timezone(&now, self.zone).date_naive();
+            6 - i64::from(first_cell_offset(today, self.config.week_start))
+        } else {
+            0
+        };
+        let due = end_of_day(now, days_ahead, self.zone);
+        self.board.update_note(&note_id, |note| {
+            note.due = Some(due);
+            note.due_precision = DuePrecision::Date;
+        })?;
+        let until = if week { "end of week" } else { "end of today" };
+        let shown = format_due_as(&due, DuePrecision::Date, self.zone);
+        self.persist(format!("{} due {} ({})", note_id, shown, until))
+    }
+
+    fn toggle_marked(&mut self) {
+        let note_id = match self.current_note() {
+            Some((id, _)) => id.to_string(),
+            None => {
+                self.status = "No note selected".into();
+                return;
+            }
+        };
+        if !self.marked.remove(&note_id) {
+            self.marked.insert(note_id);
+        }
+        self.status = format!(
+            "{} selected (m/b move, d delete, Esc clear)",
+            self.marked.len()
+        );
+    }
+
+    fn marked_in_board_order(&self) -> Vec<String> {
+        self.board
+            .columns
+            .iter()
+            .flat_map(|c| c.note_ids.iter())
+            .filter(|id| self.marked.contains(*id))
+            .cloned()
+            .collect()
+    }
+
+    fn move_to_column(&mut self, target_idx: usize) -> Result<()> {
+        if self.board.columns.is_empty() {
+            return Ok(());
+        }
+        let note_id = match self.current_board_note() {
+            Some((id, _)) => id.to_string(),
+            None => return Ok(()),
+        };
+        let dest_id = self
+            .board
+            .columns
+            .get(target_idx)
+            .map(|c| c.id.clone())
+            .ok_or_else(|| anyhow!("unknown destination column"))?;
+        let from = self
+            .board
+            .find_note_column_index(&note_id)
+            .and_then(|idx| self.board.columns.get(idx))
+            .map(|c| c.id.clone())
+            .unwrap_or_default();
+        let origin = self.board.note_position(&note_id);
+        self.board.move_note(&note_id, &dest_id)?;
+        self.remember_move(&note_id, origin);
+        self.queue_event(&note_id, LogOp::Move { from, to: dest_id });
+        self.select_after_move(&note_id);
+        Ok(())
+    }
+
+    /// Records where a just-moved note was, as `(column index, index)`.
+    fn remember_move(&mut self, note_id: &str, origin: Option<(usize, usize)>) {
+        let (Some((col_idx, index)), Some(note)) = (origin, self.board.notes.get(note_id)) else {
+            return;
+        };
+        let origin = MoveOrigin {
+            column_id: self.board.columns[col_idx].id.clone(),
+            index,
+            moved_at: note.updated_at,
+        };
+        self.move_origins.insert(note_id.to_string(), origin);
+    }
+
+    /// Returns the selected note to the column and position it was moved from,
+    /// unless it has changed since.
+    fn move_back(&mut self) -> Result<()> {
+        let Some((note_id, note)) = self.current_board_note() else {
+            self.status = "No note selected to move back".into();
+            return Ok(());
+        };
+        let note_id = note_id.to_string();
+        let updated_at = note.updated_at;
+        let Some(origin) = self
+            .move_origins
+            .remove(&note_id)
+            .filter(|origin| origin.moved_at == updated_at)
+        else {
+            self.status = format!("No move to undo for {}", note_id);
+            return Ok(());
+        };
+        let from = self
+            .board
+            .find_note_column_index(&note_id)
+            .map(|idx| self.board.columns[idx].id.clone())
+            .unwrap_or_else(|| UNFILED.to_string());
+        if let Err(err) = self
+            .board
+            .move_note_to(&note_id, &origin.column_id, origin.index)
+        {
+            self.status = format!("Move back failed: {}", err);
+            return Ok(());
+        }
+        self.queue_event(
+            &note_id,
+            LogOp::Move {
+                from,
+                to: origin.column_id.clone(),
+            },
+        );
+        self.select_after_move(&note_id);
+        self.persist(format!("Moved {} back to {}", note_id, origin.column_id))
+    }
+
+    /// Selects the next note outside the done column, wrapping around the board.
+    fn select_next_actionable(&mut self) {
+        let current = self.current_board_note().map(|(id, _)| id.to_string());
+        let candidates = self.open_board_note_ids();
+        match next_in_cycle(&candidates, current.as_deref()).map(str::to_string) {
+            Some(id) => {
+                self.select_board_note(&id);
+                self.status = format!("Next up: {}", id);
+            }
+            None => self.status = "Nothing left to do".into(),
+        }
+    }
+
+    /// Selects the next note due today, wrapping around the board.
+    fn select_next_due_today(&mut self) {
+        let current = self.current_board_note().map(|(id, _)| id.to_string());
+        let (due_today, _) = self.due_summary();
+        match next_in_cycle(&due_today, current.as_deref()).map(str::to_string) {
+            Some(id) => {
+                self.select_board_note(&id);
+                self.status = format!("Due today: {}", id);
+            }
+            None => self.status = "Nothing due today".into(),
+        }
+    }
+
+    /// Notes outside the done column that pass the tag filter, in board order.
+    fn open_board_note_ids(&self) -> Vec<&str> {
+        self.board
+            .actionable_note_ids()
+            .into_iter()
+            .filter(|id| {
+                self.board
+                    .notes
+                    .get(*id)
+                    .is_some_and(|n| self.passes_tag_filter(n))
+            })
+            .collect()
+    }
+
+    /// Open notes due today, in board order, and how many are due on an
+    /// earlier day.
+    fn due_summary(&self) -> (Vec<&str>, usize) {
+        let today = due_day(&Utc::now(), self.zone);
+        let mut due_today = Vec::new();
+        let mut overdue = 0;
+        for id in self.open_board_note_ids() {
+            match self.board.notes[id]
+                .due
+                .as_ref()
+                .map(|due| due_day(due, self.zone))
+            {
+                Some(day) if day == today => due_today.push(id),
+                Some(day) if day < today => overdue += 1,
+                _ => {}
+            }
+        }
+        (due_today, overdue)
+    }
+
+    fn toggle_done(&mut self) -> Result<()> {
+        let Some((note_id, _)) = self.current_board_note() else {
+            self.status = "No note selected".into();
+            return Ok(());
+        };
+        let note_id = note_id.to_string();
+        match self.board.toggle_done(&note_id) {
+            Ok((from, to)) => {
+                self.queue_event(
+                    &note_id,
+                    LogOp::Move {
+                        from,
+                        to: to.clone(),
+                    },
+                );
+                self.select_after_move(&note_id);
+                let message = if to == self.board.done_column_id() {
+                    format!("Done: {}", note_id)
+                } else {
+                    format!("Reopened {} in {}", note_id, to)
+                };
+                self.persist(message)
+            }
+            Err(err) => {
+                self.status = format!("Could not toggle done: {}", err);
+                Ok(())
             }
         }
     }
 
-    fn move_selected(&mut self, delta: isize) -> Result<()> {
-        if self.board.columns.is_empty() {
-            self.status = "No columns to move between".into();
-            return Ok(());
-        }
-        if self.current_note().is_none() {
-            self.status = "No note selected to move".into();
-            return Ok(());
-        }
-        let current = self.selected_column as isize;
-        let max = (self.board.columns.len() as isize).saturating_sub(1);
-        let target = (current + delta).clamp(0, max) as usize;
-        if target == self.selected_column {
-            return Ok(());
-        }
-        if let Err(err) = self.move_to_column(target) {
-            self.status = format!("Move failed: {}", err);
-            return Ok(());
+    /// Follows a moved note to its new column, or with `stay_after_move` keeps
+    /// the current column and index (clamped later by `ensure_board_bounds`).
+    fn select_after_move(&mut self, note_id: &str) {
+        if !self.stay_after_move {
+            self.select_board_note(note_id);
         }
-        let dest = self
-            .board
-            .columns
-            .get(target)
-            .map(|c| c.id.clone())
-            .unwrap_or_default();
-        self.persist(format!("Moved to {}", dest))?;
-        Ok(())
     }
 
-    fn move_to_column(&mut self, target_idx: usize) -> Result<()> {
-        if self.board.columns.is_empty() {
+    fn file_selected(&mut self, dest_idx: usize) -> Result<()> {
+        let Some((note_id, _)) = self.current_board_note() else {
             return Ok(());
-        }
-        let current_col = match self.board.columns.get(self.selected_column) {
-            Some(c) => c,
-            None => return Ok(()),
         };
-        let note_id = match current_col.note_ids.get(self.selected_note) {
-            Some(id) => id.clone(),
-            None => return Ok(()),
+        let note_id = note_id.to_string();
+        let Some(dest) = self.board.columns.get(dest_idx).map(|c| c.id.clone()) else {
+            return Ok(());
         };
-        let dest_id = self
-            .board
-            .columns
-            .get(target_idx)
-            .map(|c| c.id.clone())
-            .ok_or_else(|| anyhow!("unknown destination column"))?;
-        self.board.move_note(&note_id, &dest_id)?;
-        self.selected_column = target_idx;
-        self.selected_note = self
-            .board
-            .columns
-            .get(target_idx)
-            .map(|c| c.note_ids.len().saturating_sub(1))
-            .unwrap_or(0);
-        Ok(())
+        self.board.file_note(&note_id, &dest)?;
+        self.queue_event(
+            &note_id,
+            LogOp::Move {
+                from: UNFILED.to_string(),
+                to: dest.clone(),
+            },
+        );
+        self.select_after_move(&note_id);
+        self.persist(format!("Filed {} into {}", note_id, dest))
     }
 
     fn delete_note(&mut self, note_id: &str) -> Result<()> {
-        let col_idx = self
-            .board
-            .find_note_column_index(note_id)
-            .ok_or_else(|| anyhow!("note {} not found", note_id))?;
-        self.board.columns[col_idx]
-            .note_ids
-            .retain(|id| id != note_id);
-        self.board.notes.remove(note_id);
+        let col_idx = self.selected_column;
+        let column = match self.board.find_note_column_index(note_id) {
+            Some(idx) => self.board.columns[idx].id.clone(),
+            None => UNFILED.to_string(),
+        };
+        let note = self.board.remove_note(note_id)?;
+        self.move_origins.remove(note_id);
+        self.queue_event(
+            note_id,
+            LogOp::Delete {
+                column,
+                title: note.title,
+            },
+        );
         self.selected_note = self
             .selected_note
-            .min(self.board.columns[col_idx].note_ids.len().saturating_sub(1));
+            .min(self.visible_note_ids(col_idx).len().saturating_sub(1));
         Ok(())
     }
 
@@ -1366,10 +4573,46 @@ impl App {
     }
 
     fn current_board_note(&self) -> Option<(&str, &Note)> {
-        let column = self.board.columns.get(self.selected_column)?;
-        let note_id = column.note_ids.get(self.selected_note)?;
+        let note_id = *self
+            .visible_note_ids(self.selected_column)
+            .get(self.selected_note)?;
         let note = self.board.notes.get(note_id)?;
-        Some((note_id.as_str(), note))
+        Some((note_id, note))
+    }
+
+    fn visible_note_ids(&self, col_idx: usize) -> Vec<&str> {
+        if self.swimlanes.is_none() {
+            return self.column_note_ids(col_idx);
+        }
+        match self.board_lanes().get(self.selected_lane) {
+            Some(lane) => self.lane_note_ids(col_idx, lane),
+            None => Vec::new(),
+        }
+    }
+
+    /// Lane labels in display order; none while swimlanes are off.
+    fn board_lanes(&self) -> Vec<String> {
+        let Some(key) = self.swimlanes else {
+            return Vec::new();
+        };
+        let lanes: BTreeSet<(u8, &str)> = self
+            .board
+            .notes
+            .values()
+            .filter(|note| self.passes_tag_filter(note))
+            .map(|note| key.lane(note))
+            .collect();
+        lanes.into_iter().map(|(_, l)| l.to_string()).collect()
+    }
+
+    fn lane_note_ids(&self, col_idx: usize, lane: &str) -> Vec<&str> {
+        let Some(key) = self.swimlanes else {
+            return self.column_note_ids(col_idx);
+        };
+        self.column_note_ids(col_idx)
+            .into_iter()
+            .filter(|id| self.board.notes.get(*id).map(|note| key.lane(note).1) == Some(lane))
+            .collect()
     }
 
     fn current_timeline_note(&self) -> Option<(&str, &Note)> {
@@ -1385,9 +4628,41 @@ impl App {
         if self.project.focus != ProjectFocus::Notes {
             return None;
         }
-        let tags = self.project_tags();
-        let (_, notes) = tags.get(self.project.tag_idx)?;
-        notes.get(self.project.note_idx).copied()
+        let tags = self.project_tags(self.project.tag_order);
+        self.project_notes(&tags)
+            .get(self.project.note_idx)
+            .copied()
+    }
+
+    /// Notes listed beside the tags: those of the picked tags, combined, or
+    /// else the highlighted tag's.
+    fn project_notes<'a>(&self, tags: &[(String, NoteRefs<'a>)]) -> NoteRefs<'a> {
+        if self.project.selected_tags.is_empty() {
+            return tags
+                .get(self.project.tag_idx)
+                .map(|(_, notes)| notes.clone())
+                .unwrap_or_default();
+        }
+        combine_tag_notes(tags, &self.project.selected_tags, self.project.match_all)
+    }
+
+    /// Adds the highlighted tag to the picked set, or takes it out again.
+    fn toggle_project_tag(&mut self) {
+        let Some(tag) = self
+            .project_tags(self.project.tag_order)
+            .get(self.project.tag_idx)
+            .map(|(tag, _)| tag.clone())
+        else {
+            return;
+        };
+        if !self.project.selected_tags.remove(&tag) {
+            self.project.selected_tags.insert(tag);
+        }
+        self.project.note_idx = 0;
+        self.status = format!(
+            "{} tag(s) picked (u any/all, Esc clear)",
+            self.project.selected_tags.len()
+        );
     }
 
     fn current_column_id(&self) -> Option<String> {
@@ -1397,55 +4672,114 @@ impl App {
             .map(|c| c.id.clone())
     }
 
-    fn timeline_lists(&self) -> (Vec<(&str, &Note)>, Vec<(&str, &Note)>) {
+    fn views(&self) -> Rc<DerivedViews> {
+        self.views
+            .borrow_mut()
+            .get_or_insert_with(|| Rc::new(self.build_views()))
+            .clone()
+    }
+
+    /// Must be called after any board mutation; `persist` does this for saved changes.
+    fn invalidate_views(&self) {
+        self.views.borrow_mut().take();
+    }
+
+    fn build_views(&self) -> DerivedViews {
+        let done: HashSet<&str> = self
+            .board
+            .columns
+            .iter()
+            .filter(|c| c.id == self.board.done_column_id())
+            .flat_map(|c| c.note_ids.iter().map(String::as_str))
+            .collect();
+        let open = || {
+            self.board
+                .notes
+                .iter()
+                .filter(|(id, _)| !done.contains(id.as_str()))
+        };
+
         let mut unassigned = Vec::new();
         let mut assigned = Vec::new();
-        for (id, note) in &self.board.notes {
-            if self.is_done(id) {
-                continue;
-            }
-            if note.due.is_some() {
-                assigned.push((id.as_str(), note));
+        let mut due_counts = HashMap::new();
+        for (id, note) in open() {
+            if let Some(due) = note.due {
+                *due_counts.entry(due_day(&due, self.zone)).or_insert(0) += 1;
+                assigned.push((id, note));
             } else {
-                unassigned.push((id.as_str(), note));
+                unassigned.push((id, note));
             }
         }
-        unassigned.sort_by_key(|(_, note)| (note.created_at, note.title.to_lowercase()));
-        assigned.sort_by_key(|(_, note)| {
-            (note.due.unwrap_or_else(Utc::now), note.title.to_lowercase())
+        unassigned.sort_by_cached_key(|(_, note)| {
+            (
+                note.created_at,
+                note.title.to_lowercase(),
+                note.sequence_key(),
+            )
+        });
+        assigned.sort_by_cached_key(|(_, note)| {
+            (note.due, note.title.to_lowercase(), note.sequence_key())
         });
-        (unassigned, assigned)
+
+        let tags = bucket_by_tag(open())
+            .into_iter()
+            .map(|(tag, mut notes)| {
+                notes.sort_by_cached_key(|(_, note)| {
+                    (
+                        note.updated_at,
+                        note.title.to_lowercase(),
+                        note.sequence_key(),
+                    )
+                });
+                (tag, notes.into_iter().map(|(id, _)| id.clone()).collect())
+            })
+            .collect();
+
+        let ids =
+            |notes: Vec<(&String, &Note)>| notes.into_iter().map(|(id, _)| id.clone()).collect();
+        DerivedViews {
+            unassigned: ids(unassigned),
+            assigned: ids(assigned),
+            tags,
+            due_counts,
+        }
+    }
+
+    fn note_refs<'a>(&'a self, ids: &[String]) -> NoteRefs<'a> {
+        ids.iter()
+            .filter_map(|id| self.board.notes.get_key_value(id))
+            .map(|(id, note)| (id.as_str(), note))
+            .collect()
+    }
+
+    /// Unassigned and assigned notes for the timeline; with the overdue
+    /// toggle on, the assigned list keeps only notes already past due.
+    fn timeline_lists(&self) -> (NoteRefs<'_>, NoteRefs<'_>) {
+        let views = self.views();
+        let mut assigned = self.note_refs(&views.assigned);
+        if self.timeline.overdue_only {
+            let now = Utc::now();
+            assigned.retain(|(_, note)| note.is_overdue(now));
+        }
+        (self.note_refs(&views.unassigned), assigned)
     }
 
     fn notes_due_on(&self, date: NaiveDate) -> Vec<(&str, &Note)> {
-        let mut notes = self
-            .board
-            .notes
-            .iter()
-            .filter_map(|(id, note)| {
-                if self.is_done(id) {
-                    return None;
-                }
-                if let Some(due) = note.due {
-                    if due.date_naive() == date {
-                        return Some((id.as_str(), note));
-                    }
-                }
-                None
-            })
-            .collect::<Vec<_>>();
-        notes.sort_by_key(|(_, note)| (note.due, note.title.to_lowercase()));
-        notes
+        let (_, assigned) = self.timeline_lists();
+        assigned
+            .into_iter()
+            .filter(|(_, note)| note.due.as_ref().map(|due| due_day(due, self.zone)) == Some(date))
+            .collect()
     }
 
     fn timeline_due_counts(&self) -> HashMap<NaiveDate, usize> {
+        if !self.timeline.overdue_only {
+            return self.views().due_counts.clone();
+        }
         let mut counts = HashMap::new();
-        for (id, note) in &self.board.notes {
-            if self.is_done(id) {
-                continue;
-            }
-            if let Some(due) = note.due {
-                *counts.entry(due.date_naive()).or_insert(0) += 1;
+        for (_, note) in self.timeline_lists().1 {
+            if let Some(due) = note.due.as_ref() {
+                *counts.entry(due_day(due, self.zone)).or_insert(0) += 1;
             }
         }
         counts
@@ -1454,46 +4788,58 @@ impl App {
     fn first_due_on_cursor(&self) -> Option<usize> {
         let (_, assigned) = self.timeline_lists();
         let target = self.timeline.calendar_cursor;
-        assigned
+        assigned.iter().position(|(_, note)| {
+            note.due.as_ref().map(|due| due_day(due, self.zone)) == Some(target)
+        })
+    }
+
+    fn project_tags(&self, order: TagOrder) -> Vec<(String, NoteRefs<'_>)> {
+        let mut tags: Vec<_> = self
+            .views()
+            .tags
             .iter()
-            .position(|(_, note)| note.due.map(|d| d.date_naive()) == Some(target))
+            .map(|(tag, ids)| (tag.clone(), self.note_refs(ids)))
+            .collect();
+        order_tags(&mut tags, order);
+        tags
     }
 
-    fn project_tags(&self) -> Vec<(String, Vec<(&str, &Note)>)> {
-        let mut buckets: BTreeMap<String, Vec<(&str, &Note)>> = BTreeMap::new();
-        for (id, note) in &self.board.notes {
-            if self.is_done(id) {
-                continue;
-            }
-            if note.tags.is_empty() {
-                buckets
-                    .entry("(untagged)".to_string())
-                    .or_default()
-                    .push((id.as_str(), note));
-            } else {
-                for tag in &note.tags {
-                    buckets
-                        .entry(tag.clone())
-                        .or_default()
-                        .push((id.as_str(), note));
-                }
+    /// Switches the project view between name and count order, keeping the selected tag.
+    fn toggle_tag_order(&mut self) {
+        let selected = self
+            .project_tags(self.project.tag_order)
+            .get(self.project.tag_idx)
+            .map(|(tag, _)| tag.clone());
+        self.project.tag_order = match self.project.tag_order {
+            TagOrder::Name => TagOrder::Count,
+            TagOrder::Count => TagOrder::Name,
+        };
+        if let Some(tag) = selected {
+            if let Some(idx) = self
+                .project_tags(self.project.tag_order)
+                .iter()
+                .position(|(t, _)| *t == tag)
+            {
+                self.project.tag_idx = idx;
             }
         }
-
-        let mut tags = Vec::new();
-        for (tag, mut notes) in buckets {
-            notes.sort_by_key(|(_, note)| (note.updated_at, note.title.to_lowercase()));
-            tags.push((tag, notes));
-        }
-        tags
+        self.status = match self.project.tag_order {
+            TagOrder::Name => "Tags sorted by name".into(),
+            TagOrder::Count => "Tags sorted by note count".into(),
+        };
     }
 
-    fn is_done(&self, note_id: &str) -> bool {
-        self.board
-            .find_note_column_index(note_id)
-            .and_then(|idx| self.board.columns.get(idx))
-            .map(|col| col.id == "done")
-            .unwrap_or(false)
+    fn ensure_board_bounds(&mut self) {
+        self.selected_column = self
+            .selected_column
+            .min(self.board_column_count().saturating_sub(1));
+        self.leave_hidden_column();
+        if self.swimlanes.is_some() {
+            let lane_count = self.board_lanes().len();
+            self.selected_lane = self.selected_lane.min(lane_count.saturating_sub(1));
+        }
+        let visible = self.visible_note_ids(self.selected_column).len();
+        self.selected_note = self.selected_note.min(visible.saturating_sub(1));
     }
 
     fn ensure_timeline_bounds(&mut self) {
@@ -1530,7 +4876,7 @@ impl App {
     }
 
     fn ensure_project_bounds(&mut self) {
-        let tags = self.project_tags();
+        let tags = self.project_tags(self.project.tag_order);
         let tag_count = tags.len();
         if tag_count == 0 {
             self.project.tag_idx = 0;
@@ -1539,9 +4885,14 @@ impl App {
             return;
         }
         let tag_idx = self.project.tag_idx.min(tag_count.saturating_sub(1));
-        let note_len = tags.get(tag_idx).map(|(_, notes)| notes.len()).unwrap_or(0);
+        let known: BTreeSet<String> = tags.iter().map(|(tag, _)| tag.clone()).collect();
         drop(tags);
         self.project.tag_idx = tag_idx;
+        self.project.selected_tags.retain(|tag| known.contains(tag));
+        let note_len = {
+            let tags = self.project_tags(self.project.tag_order);
+            self.project_notes(&tags).len()
+        };
         if note_len == 0 && self.project.focus == ProjectFocus::Notes {
             self.project.focus_tags();
         }
@@ -1562,7 +4913,7 @@ impl App {
         }
     }
 
-    fn create_note_from_form(&mut self, form: &NoteForm) -> Result<()> {
+    fn create_note_from_form(&mut self, form: &NoteForm) -> Result<String> {
         let title = form.title.value.trim();
         if title.is_empty() {
             return Err(anyhow!("title is required"));
@@ -1571,25 +4922,65 @@ impl App {
             .current_column_id()
             .ok_or_else(|| anyhow!("no columns available to place the note"))?;
         let tags = parse_tags(&form.tags.value);
-        let due = parse_due_string(&form.due.value)?;
+        let (due, due_note) = parse_due_noting(&form.due.value, self.due_time, self.zone)?;
         let body = if form.body.value.trim().is_empty() {
             None
         } else {
             Some(form.body.value.clone())
         };
-        let id = generate_id();
-        let note = Note::new(id.clone(), title.to_string(), body, tags, due);
+        let duplicates = self.board.notes_with_title(title);
+        let id = generate_id(&self.config, |id| self.board.id_in_use(id));
+        let mut note = Note::new(id.clone(), title.to_string(), body, tags, due);
+        note.due_precision = due_precision(&form.due.value);
+        note.waiting_on = form.waiting_on();
         self.board
             .add_note(note, &column_id)
             .map_err(|err| anyhow!(err))?;
-        self.selected_note = self
-            .board
-            .columns
-            .get(self.selected_column)
-            .map(|c| c.note_ids.len().saturating_sub(1))
-            .unwrap_or(0);
-        self.persist(format!("Created note {}", id))?;
-        Ok(())
+        self.queue_event(
+            &id,
+            LogOp::Add {
+                column: column_id,
+                title: title.to_string(),
+            },
+        );
+        self.select_board_note(&id);
+        if duplicates.is_empty() {
+            self.persist(format!("Created note {}", id))?;
+        } else {
+            self.persist(format!(
+                "Created note {} (same title as {})",
+                id,
+                duplicates.join(", ")
+            ))?;
+        }
+        self.note_due_remark(due_note);
+        self.note_mistyped_due(&id);
+        if let Some(idx) = self.timeline_lists().1.iter().position(|(n, _)| *n == id) {
+            self.timeline.assigned_idx = idx;
+        }
+        Ok(id)
+    }
+
+    /// Appends a remark about the entered due date to the status.
+    fn note_due_remark(&mut self, note: Option<String>) {
+        if let Some(note) = note {
+            self.status = format!("{} ({})", self.status, note);
+        }
+    }
+
+    /// Flags a due date on `note_id` that looks mistyped; the change is kept.
+    fn note_mistyped_due(&mut self, note_id: &str) {
+        let reason = self.board.notes.get(note_id).and_then(|note| {
+            let due = note.due?;
+            mistyped_due_reason(
+                due,
+                note.created_at,
+                Utc::now(),
+                self.config.past_due_warning_days,
+                self.zone,
+            )
+        });
+        self.note_due_remark(reason.map(|reason| format!("warning: {}", reason)));
     }
 
     fn edit_note_from_form(&mut self, note_id: &str, form: &NoteForm) -> Result<()> {
@@ -1598,7 +4989,17 @@ impl App {
             return Err(anyhow!("title is required"));
         }
         let tags = parse_tags(&form.tags.value);
-        let due = parse_due_string(&form.due.value)?;
+        // Untouched due text keeps the stored time, which a day-only due
+        // would otherwise lose to the default time.
+        let (due, precision, due_note) = match self.board.notes.get(note_id) {
+            Some(note) if due_field_text(note, self.zone) == form.due.value.trim() => {
+                (note.due, note.due_precision, None)
+            }
+            _ => {
+                let (due, due_note) = parse_due_noting(&form.due.value, self.due_time, self.zone)?;
+                (due, due_precision(&form.due.value), due_note)
+            }
+        };
         let body = if form.body.value.trim().is_empty() {
             None
         } else {
@@ -1607,7 +5008,9 @@ impl App {
         let title_owned = title.to_string();
         let body_owned = body.clone();
         let tags_owned = tags.clone();
-        let due_owned = due.clone();
+        let due_owned = due;
+        let waiting_on = form.waiting_on();
+        let due_changed = self.board.notes.get(note_id).map(|n| n.due) != Some(due);
 
         self.board
             .update_note(note_id, move |note| {
@@ -1615,17 +5018,106 @@ impl App {
                 note.body = body_owned.clone();
                 note.tags = tags_owned.clone();
                 note.due = due_owned;
+                note.due_precision = precision;
+                note.waiting_on = waiting_on.clone();
             })
             .map_err(|err| anyhow!(err))?;
+        if self.swimlanes.is_some() {
+            self.select_board_note(note_id);
+        }
+        self.queue_event(
+            note_id,
+            LogOp::Edit {
+                title: title.to_string(),
+            },
+        );
 
         self.persist(format!("Updated {}", note_id))?;
+        self.note_due_remark(due_note);
+        if due_changed {
+            self.note_mistyped_due(note_id);
+        }
         Ok(())
     }
 
+    /// Copies the selected note onto the other board (project or global),
+    /// leaving this one untouched.
+    fn copy_to_other_board(&mut self) {
+        let Some((_, note)) = self.current_note() else {
+            self.status = "No note selected to copy".into();
+            return;
+        };
+        let note = note.clone();
+        let to = match self.location.scope {
+            BoardScope::Project => BoardScope::Global,
+            BoardScope::Global => BoardScope::Project,
+        };
+        match self.copy_note_to(&note, to) {
+            Ok((id, column, warning)) => {
+                self.status = format!(
+                    "Copied {} to the {} board as {} in {}",
+                    note.id,
+                    to.label(),
+                    id,
+                    column
+                );
+                if let Some(warning) = warning {
+                    self.status = format!("{} ({})", self.status, warning);
+                }
+            }
+            Err(err) => {
+                self.status = format!("Copy failed: {:#}", err);
+                self.status_error = true;
+            }
+        }
+    }
+
+    /// Returns the new id, its column, and any git auto-commit warning.
+    fn copy_note_to(
+        &self,
+        note: &Note,
+        to: BoardScope,
+    ) -> Result<(String, String, Option<String>)> {
+        let dest = board_location(to, &std::env::current_dir()?)?;
+        let mut target = load_board_readonly(&dest)?;
+        let column = target
+            .default_column_id()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("the {} board has no columns", to.label()))?;
+        let id = generate_id(&self.config, |id| target.id_in_use(id));
+        target.add_note(note.copy_as(id.clone()), &column)?;
+        let warning = save_board_reporting(&dest, &target)?;
+        if self.config.event_log {
+            let op = LogOp::Add {
+                column: column.clone(),
+                title: note.title.clone(),
+            };
+            append_event(&dest, &LogEvent::new(&id, op))?;
+        }
+        Ok((id, column, warning))
+    }
+
+    fn queue_event(&mut self, note_id: &str, op: LogOp) {
+        if self.config.event_log {
+            self.pending_events.push(LogEvent::new(note_id, op));
+        }
+    }
+
     fn persist(&mut self, message: impl Into<String>) -> Result<()> {
-        save_board(&self.location, &self.board)?;
+        self.invalidate_views();
+        let warning = save_board_reporting(&self.location, &self.board)?;
         self.last_save = Instant::now();
         self.status = message.into();
+        if let Some(warning) = warning {
+            self.status = format!("{} ({})", self.status, warning);
+        }
+        for event in std::mem::take(&mut self.pending_events) {
+            if let Err(err) = append_event(&self.location, &event) {
+                self.status = format!("{} (event log failed: {})", self.status, err);
+                break;
+            }
+        }
+        self.ensure_board_bounds();
         self.ensure_timeline_bounds();
         self.ensure_project_bounds();
         Ok(())
@@ -1639,16 +5131,18 @@ impl NoteForm {
             body: FieldValue::new(""),
             tags: FieldValue::new(""),
             due: FieldValue::new(""),
+            waiting_on: FieldValue::new(""),
             field: FormField::Title,
         }
     }
 
-    fn from_note(note: &Note) -> Self {
+    fn from_note(note: &Note, zone: Zone) -> Self {
         NoteForm {
             title: FieldValue::new(&note.title),
             body: FieldValue::new(note.body.as_deref().unwrap_or_default()),
             tags: FieldValue::new(&note.tags.join(" ")),
-            due: FieldValue::new(&note.due.as_ref().map(|d| format_due(d)).unwrap_or_default()),
+            due: FieldValue::new(&due_field_text(note, zone)),
+            waiting_on: FieldValue::new(note.waiting_on.as_deref().unwrap_or_default()),
             field: FormField::Title,
         }
     }
@@ -1658,39 +5152,67 @@ impl NoteForm {
             FormField::Title => FormField::Body,
             FormField::Body => FormField::Tags,
             FormField::Tags => FormField::Due,
-            FormField::Due => FormField::Title,
+            FormField::Due => FormField::WaitingOn,
+            FormField::WaitingOn => FormField::Title,
         };
     }
 
     fn prev_field(&mut self) {
         self.field = match self.field {
-            FormField::Title => FormField::Due,
+            FormField::Title => FormField::WaitingOn,
             FormField::Body => FormField::Title,
             FormField::Tags => FormField::Body,
             FormField::Due => FormField::Tags,
+            FormField::WaitingOn => FormField::Due,
         };
     }
 
+    /// Label, value, and field of each input, top to bottom as drawn.
+    fn fields(&self) -> [(&'static str, &FieldValue, FormField); 5] {
+        [
+            ("Title", &self.title, FormField::Title),
+            ("Body", &self.body, FormField::Body),
+            ("Tags", &self.tags, FormField::Tags),
+            ("Due (YYYY.MM.DD@hh:mm)", &self.due, FormField::Due),
+            ("Waiting on", &self.waiting_on, FormField::WaitingOn),
+        ]
+    }
+
     fn active_field_mut(&mut self) -> &mut FieldValue {
-        match self.field {
+        self.field_mut(self.field)
+    }
+
+    fn field_mut(&mut self, field: FormField) -> &mut FieldValue {
+        match field {
             FormField::Title => &mut self.title,
             FormField::Body => &mut self.body,
             FormField::Tags => &mut self.tags,
             FormField::Due => &mut self.due,
+            FormField::WaitingOn => &mut self.waiting_on,
         }
     }
+
+    fn waiting_on(&self) -> Option<String> {
+        Some(self.waiting_on.value.trim().to_string()).filter(|text| !text.is_empty())
+    }
 }
 
-fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
+fn setup_terminal(mouse: bool) -> Result<Terminal<CrosstermBackend<Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = stdout();
     execute!(stdout, EnterAlternateScreen)?;
+    if mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
 }
 
-fn teardown_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
+fn teardown_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>, mouse: bool) -> Result<()> {
+    if mouse {
+        execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    }
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
@@ -1723,16 +5245,6 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-fn parse_due_string(input: &str) -> Result<Option<DateTime<Utc>>> {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
-        return Ok(None);
-    }
-    let dt = NaiveDateTime::parse_from_str(trimmed, "%Y.%m.%d@%H:%M")
-        .map_err(|_| anyhow!("invalid date format (use YYYY.MM.DD@hh:mm): {}", trimmed))?;
-    Ok(Some(Utc.from_utc_datetime(&dt)))
-}
-
 fn parse_tags(input: &str) -> Vec<String> {
     input
         .split(|c: char| c.is_whitespace() || c == ',')
@@ -1741,12 +5253,41 @@ fn parse_tags(input: &str) -> Vec<String> {
         .collect()
 }
 
-fn generate_id() -> String {
-    rand::thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(6)
-        .map(char::from)
-        .collect()
+/// Named colors cycled through by the board's column color key.
+const COLUMN_COLORS: [&str; 8] = [
+    "cyan",
+    "lightgreen",
+    "lightmagenta",
+    "lightblue",
+    "lightyellow",
+    "lightred",
+    "white",
+    "gray",
+];
+
+/// The color after `current` in [`COLUMN_COLORS`]; past the last (or from a
+/// color not in the list) the column goes back to its default.
+fn next_column_color(current: Option<&str>) -> Option<&'static str> {
+    match current {
+        None => Some(COLUMN_COLORS[0]),
+        Some(current) => COLUMN_COLORS
+            .iter()
+            .position(|name| *name == current)
+            .and_then(|idx| COLUMN_COLORS.get(idx + 1))
+            .copied(),
+    }
+}
+
+pub fn parse_color(name: &str) -> Option<Color> {
+    name.trim().parse::<Color>().ok()
+}
+
+fn column_color(column: &Column, idx: usize) -> Color {
+    column
+        .color
+        .as_deref()
+        .and_then(parse_color)
+        .unwrap_or_else(|| color_for_index(idx))
 }
 
 fn color_for_index(idx: usize) -> Color {
@@ -1761,6 +5302,77 @@ fn color_for_index(idx: usize) -> Color {
     palette[idx % palette.len()]
 }
 
+/// `Ctrl+O` (back) and `Ctrl+I` (forward). Most terminals send `Ctrl+I` as
+/// `Tab`, so a bare `Tab` also jumps forward on the board, where it is unused.
+fn is_jump_key(key: &KeyEvent, view: ViewMode) -> bool {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('o') | KeyCode::Char('i') => ctrl,
+        KeyCode::Tab => view == ViewMode::Board,
+        _ => false,
+    }
+}
+
+/// Selection after paging by `page` items, clamped to `0..len`. A page of at
+/// least one item is always moved so tiny viewports still make progress.
+fn page_index(selected: usize, len: usize, page: usize, down: bool) -> usize {
+    let page = page.max(1);
+    if down {
+        selected.saturating_add(page).min(len.saturating_sub(1))
+    } else {
+        selected.saturating_sub(page)
+    }
+}
+
+/// Notes to leave out of a column drawn with `cap`; none once the selection
+/// has moved past the cap, so every note stays reachable.
+fn hidden_past_cap(len: usize, cap: Option<usize>, selected: Option<usize>) -> usize {
+    match cap {
+        Some(cap) if len > cap && selected.is_none_or(|sel| sel < cap) => len - cap,
+        _ => 0,
+    }
+}
+
+/// Sorts tag groups in place: by name, or by descending note count with ties by name.
+fn order_tags<T>(tags: &mut [(String, Vec<T>)], order: TagOrder) {
+    match order {
+        TagOrder::Name => tags.sort_by(|a, b| a.0.cmp(&b.0)),
+        TagOrder::Count => {
+            tags.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)))
+        }
+    }
+}
+
+/// Cells in a column title's WIP gauge, at most one per allowed note.
+const WIP_GAUGE_CELLS: usize = 8;
+
+/// The new-column form's WIP limit: blank for none, otherwise a whole number.
+fn parse_wip_limit(text: &str) -> std::result::Result<Option<usize>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    text.parse()
+        .map(Some)
+        .map_err(|_| format!("WIP limit must be a whole number, not {:?}", text))
+}
+
+/// A `▇▇▂▂`-style gauge of `held` notes against `limit`, no wider than `width`;
+/// empty when there is no room.
+fn wip_gauge(held: usize, limit: usize, width: usize) -> String {
+    let cells = limit.min(WIP_GAUGE_CELLS).min(width);
+    if cells == 0 {
+        return String::new();
+    }
+    let filled = (held * cells).div_ceil(limit).min(cells);
+    "▇".repeat(filled) + &"▂".repeat(cells - filled)
+}
+
+/// Calendar day a due date falls on in the display zone.
+fn due_day(due: &DateTime<Utc>, zone: Zone) -> NaiveDate {
+    in_timezone(due, zone).date_naive()
+}
+
 fn adjust_offset(
     selected: usize,
     current_offset: usize,
@@ -1772,7 +5384,7 @@ fn adjust_offset(
         return 0;
     }
     let max_offset = len.saturating_sub(viewport);
-    let margin = scrolloff.min(viewport.saturating_sub(1));
+    let margin = scrolloff.min(viewport.saturating_sub(1) / 2);
     let mut offset = current_offset.min(max_offset);
     if selected < offset.saturating_add(margin) {
         offset = selected.saturating_sub(margin);
@@ -1787,6 +5399,21 @@ fn adjust_offset(
     offset.min(max_offset)
 }
 
+/// Blank cells before the 1st in a calendar row starting on `week_start`.
+fn first_cell_offset(month_start: NaiveDate, week_start: WeekStart) -> u32 {
+    match week_start {
+        WeekStart::Monday => month_start.weekday().num_days_from_monday(),
+        WeekStart::Sunday => month_start.weekday().num_days_from_sunday(),
+    }
+}
+
+fn weekday_headings(week_start: WeekStart) -> [&'static str; 7] {
+    match week_start {
+        WeekStart::Monday => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+        WeekStart::Sunday => ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"],
+    }
+}
+
 fn days_in_month(year: i32, month: u32) -> u32 {
     let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or_else(|| Utc::now().date_naive());
     let next = if month == 12 {
@@ -1798,10 +5425,6 @@ fn days_in_month(year: i32, month: u32) -> u32 {
     next.pred_opt().map(|d| d.day()).unwrap_or(28)
 }
 
-fn format_due(dt: &DateTime<Utc>) -> String {
-    dt.format("%Y.%m.%d@%H:%M").to_string()
-}
-
 fn prev_grapheme(cursor: usize, text: &str) -> usize {
     if cursor == 0 {
         return 0;
@@ -1855,20 +5478,42 @@ fn start_of_line(line_idx: usize, starts: &[usize]) -> usize {
 
 fn index_at_col(text: &str, start: usize, target_col: usize) -> usize {
     let slice = &text[start..];
-    let limit = slice
-        .find('\n')
-        .map(|idx| idx)
-        .unwrap_or_else(|| slice.len());
-    let mut col = 0;
-    for (idx, _) in slice[..limit].char_indices() {
+    let limit = slice.find('\n').unwrap_or(slice.len());
+    for (col, (idx, _)) in slice[..limit].char_indices().enumerate() {
         if col == target_col {
             return start + idx;
         }
-        col += 1;
     }
     start + limit
 }
 
+/// Column `delta` places from `src` among `len`: clamped to the ends, or
+/// wrapping around them with `cycle`.
+fn move_target(src: usize, len: usize, delta: isize, cycle: bool) -> usize {
+    if len == 0 {
+        return src;
+    }
+    let target = src as isize + delta;
+    if cycle {
+        target.rem_euclid(len as isize) as usize
+    } else {
+        target.clamp(0, len as isize - 1) as usize
+    }
+}
+
+/// One step from `idx` among `len` slots, or `None` at an end when not
+/// wrapping. A single slot wraps onto itself so lists restart from the top.
+fn step_index(idx: usize, len: usize, forward: bool, wrap: bool) -> Option<usize> {
+    match (forward, wrap) {
+        _ if len == 0 => None,
+        (true, _) if idx + 1 < len => Some(idx + 1),
+        (false, _) if idx > 0 => Some(idx - 1),
+        (true, true) => Some(0),
+        (false, true) => Some(len - 1),
+        (_, false) => None,
+    }
+}
+
 fn truncate_text(text: &str, max: usize) -> String {
     if max == 0 {
         return String::new();
@@ -1879,16 +5524,60 @@ fn truncate_text(text: &str, max: usize) -> String {
             out.push_str("...");
             break;
         }
-        out.push(ch);
+        out.push(ch);
+    }
+    if out.chars().count() > max {
+        out.truncate(max);
+    }
+    out
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum TitleFit {
+    /// Cut the title to one line ending in `...`.
+    Truncate,
+    /// Break the title into as many lines as it needs.
+    Wrap,
+}
+
+/// Word-wraps `text` into lines of at most `width` characters, splitting
+/// words that are longer than a line.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        let len = line.chars().count();
+        if len > 0 && len + 1 + word.len() <= width {
+            line.push(' ');
+            line.extend(word);
+            continue;
+        }
+        if len > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+        while word.len() > width {
+            lines.push(word.drain(..width).collect());
+        }
+        line.extend(word);
     }
-    if out.chars().count() > max {
-        out.truncate(max);
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
     }
-    out
+    lines
 }
 
-fn timeline_list_item(_id: &str, note: &Note, show_due: bool) -> ListItem<'static> {
+fn timeline_list_item(
+    note: &Note,
+    show_due: bool,
+    marked: bool,
+    opts: &RenderOpts,
+) -> ListItem<'static> {
     let mut spans = Vec::new();
+    if marked {
+        spans.push(mark_span());
+    }
     spans.push(Span::styled(
         truncate_text(&note.title, 44),
         Style::default()
@@ -1899,23 +5588,23 @@ fn timeline_list_item(_id: &str, note: &Note, show_due: bool) -> ListItem<'stati
         if let Some(due) = note.due.as_ref() {
             spans.push(Span::raw("  "));
             spans.push(Span::styled(
-                due.format("%Y-%m-%d@%H:%M").to_string(),
+                due_label(due, note.due_precision, opts),
                 Style::default().fg(Color::LightYellow),
             ));
         }
     }
     if !note.tags.is_empty() {
         spans.push(Span::raw("  "));
-        spans.push(Span::styled(
-            format!("#{}", note.tags.join(" #")),
-            Style::default().fg(Color::LightMagenta),
-        ));
+        spans.extend(tag_spans(&note.tags, opts.tag_colors, usize::MAX));
     }
     ListItem::new(Line::from(spans)).style(Style::default().fg(Color::Gray))
 }
 
-fn project_note_item(id: &str, note: &Note) -> ListItem<'static> {
+fn project_note_item(id: &str, note: &Note, marked: bool, opts: &RenderOpts) -> ListItem<'static> {
     let mut spans = Vec::new();
+    if marked {
+        spans.push(mark_span());
+    }
     spans.push(Span::styled(
         format!("[{}]", id),
         Style::default().fg(Color::DarkGray),
@@ -1930,48 +5619,201 @@ fn project_note_item(id: &str, note: &Note) -> ListItem<'static> {
     if let Some(due) = note.due.as_ref() {
         spans.push(Span::raw("  "));
         spans.push(Span::styled(
-            due.format("%Y-%m-%d").to_string(),
+            due_label(due, note.due_precision, opts),
             Style::default().fg(Color::LightYellow),
         ));
     }
-    let tag_text = if note.tags.is_empty() {
-        "(no tags)".to_string()
-    } else {
-        format!("#{}", note.tags.join(" #"))
-    };
     spans.push(Span::raw("  "));
+    if note.tags.is_empty() {
+        spans.push(Span::styled(
+            "(no tags)",
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else {
+        spans.extend(tag_spans(&note.tags, opts.tag_colors, usize::MAX));
+    }
+    ListItem::new(Line::from(spans)).style(Style::default().fg(Color::Gray))
+}
+
+/// Notes under any (or with `all`, every) tag in `selected`, each once, in
+/// the order they first appear in `tags`.
+fn combine_tag_notes<'a>(
+    tags: &[(String, NoteRefs<'a>)],
+    selected: &BTreeSet<String>,
+    all: bool,
+) -> NoteRefs<'a> {
+    let picked: Vec<&NoteRefs<'a>> = tags
+        .iter()
+        .filter(|(tag, _)| selected.contains(tag))
+        .map(|(_, notes)| notes)
+        .collect();
+    let mut seen = HashSet::new();
+    picked
+        .iter()
+        .flat_map(|notes| notes.iter().copied())
+        .filter(|(id, _)| {
+            !all || picked
+                .iter()
+                .all(|notes| notes.iter().any(|(other, _)| other == id))
+        })
+        .filter(|(id, _)| seen.insert(*id))
+        .collect()
+}
+
+fn mark_span() -> Span<'static> {
+    Span::styled(
+        "✓ ",
+        Style::default()
+            .fg(Color::LightGreen)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+fn compact_note_item(
+    note: &Note,
+    width: u16,
+    selected: bool,
+    marked: bool,
+    opts: &RenderOpts,
+) -> ListItem<'static> {
+    let mut spans = Vec::new();
+    if marked {
+        spans.push(mark_span());
+    }
     spans.push(Span::styled(
-        tag_text,
-        Style::default().fg(Color::LightMagenta),
+        format!("{} ", note.id),
+        Style::default().fg(note.priority.map_or(Color::DarkGray, priority_color)),
     ));
-    ListItem::new(Line::from(spans)).style(Style::default().fg(Color::Gray))
+    spans.push(Span::styled(
+        truncate_text(&note.title, width.saturating_sub(9) as usize),
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    ));
+    if let Some(due) = note.due.as_ref() {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            due_label(due, note.due_precision, opts),
+            Style::default().fg(Color::LightYellow),
+        ));
+    }
+    if !note.tags.is_empty() {
+        spans.push(Span::raw(" "));
+        spans.extend(tag_spans(&note.tags, opts.tag_colors, usize::MAX));
+    }
+    let style = if selected {
+        Style::default()
+            .bg(Color::Rgb(252, 214, 112))
+            .fg(Color::Black)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().bg(Color::Rgb(22, 24, 30)).fg(Color::Gray)
+    };
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+/// Indices of the columns whose id or name contains the letters of `query`
+/// in order, ignoring case and spaces. Columns in `recent` come first, in
+/// that order, then the rest in board order.
+fn column_picker_matches(columns: &[Column], recent: &[String], query: &str) -> Vec<usize> {
+    let fuzzy = |text: &str| {
+        let mut chars = text.chars().flat_map(char::to_lowercase);
+        query
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .all(|q| chars.any(|c| c == q))
+    };
+    let mut matches: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| fuzzy(&column.id) || column.name.as_deref().is_some_and(fuzzy))
+        .map(|(idx, _)| idx)
+        .collect();
+    matches.sort_by_key(|&idx| {
+        let rank = recent.iter().position(|id| *id == columns[idx].id);
+        (rank.unwrap_or(usize::MAX), idx)
+    });
+    matches
+}
+
+/// Accent for cards and rows of notes with a priority.
+fn priority_color(priority: Priority) -> Color {
+    match priority {
+        Priority::Low => Color::LightBlue,
+        Priority::Medium => Color::Yellow,
+        Priority::High => Color::LightRed,
+    }
 }
 
-fn note_item(note: &Note, width: u16, selected: bool) -> ListItem<'static> {
+/// Rows taken by one full (non-compact) card from `note_item`; cards with
+/// wrapped titles can be taller.
+const NOTE_CARD_HEIGHT: usize = 5;
+
+fn note_item(
+    note: &Note,
+    width: u16,
+    selected: bool,
+    marked: bool,
+    opts: &RenderOpts,
+) -> ListItem<'static> {
     let inner_width = width.saturating_sub(4).max(10) as usize;
     let border_char = if selected { "=" } else { "-" };
     let horiz = border_char.repeat(inner_width);
     let top = format!("+{}+", horiz);
-    let title = truncate_text(&note.title, inner_width.saturating_sub(2));
+    let title = if marked {
+        format!("✓ {}", note.title)
+    } else {
+        note.title.clone()
+    };
+    let title_width = inner_width.saturating_sub(2);
+    let titles = match opts.card_titles {
+        TitleFit::Truncate => vec![truncate_text(&title, title_width)],
+        TitleFit::Wrap => wrap_text(&title, title_width),
+    };
     let due_line = note
         .due
         .as_ref()
-        .map(|d| format!("due {}", format_due(d)))
+        .map(|d| format!("due {}", due_label(d, note.due_precision, opts)))
         .unwrap_or_default();
-    let tags_line = if note.tags.is_empty() {
-        String::new()
-    } else {
-        format!("#{}", note.tags.join(" #"))
-    };
     let due_line = truncate_text(&due_line, inner_width.saturating_sub(2));
-    let tags_line = truncate_text(&tags_line, inner_width.saturating_sub(2));
-    let lines = vec![
-        Line::raw(top.clone()),
-        Line::raw(format!("| {:width$} |", title, width = inner_width)),
+    let waiting_line = note.waiting_on.as_ref().map(|text| {
+        let text = truncate_text(
+            &format!("waiting on {}", text),
+            inner_width.saturating_sub(2),
+        );
+        Line::from(vec![
+            Span::raw("| "),
+            Span::styled(
+                format!("{:width$}", text, width = inner_width),
+                Style::default().fg(Color::LightYellow),
+            ),
+            Span::raw(" |"),
+        ])
+    });
+    let mut tags_line = vec![Span::raw("| ")];
+    let tags = tag_spans(&note.tags, opts.tag_colors, inner_width.saturating_sub(2));
+    let used: usize = tags.iter().map(|span| span.content.chars().count()).sum();
+    tags_line.extend(tags);
+    tags_line.push(Span::raw(format!(
+        "{} |",
+        " ".repeat(inner_width.saturating_sub(used))
+    )));
+    let border = note.priority.map_or_else(Style::default, |priority| {
+        Style::default().fg(priority_color(priority))
+    });
+    let mut lines = vec![Line::styled(top.clone(), border)];
+    lines.extend(
+        titles
+            .into_iter()
+            .map(|title| Line::raw(format!("| {:width$} |", title, width = inner_width))),
+    );
+    lines.extend(waiting_line);
+    lines.extend([
         Line::raw(format!("| {:width$} |", due_line, width = inner_width)),
-        Line::raw(format!("| {:width$} |", tags_line, width = inner_width)),
-        Line::raw(top),
-    ];
+        Line::from(tags_line),
+        Line::styled(top, border),
+    ]);
     let base = Style::default().bg(Color::Rgb(22, 24, 30)).fg(Color::Gray);
     let mut item = ListItem::new(lines).style(base);
     if selected {
@@ -2021,35 +5863,396 @@ fn field_lines(label: &str, field: &FieldValue, active: bool) -> Vec<Line<'stati
         .collect()
 }
 
-fn selected_note_detail(note: &Note) -> Line<'static> {
-    let mut spans = vec![Span::styled(
-        note.title.clone(),
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD),
-    )];
-    if let Some(due) = note.due.as_ref() {
-        spans.push(Span::raw("  "));
+/// A note form field as rows at most `width` cells wide: the label, then the
+/// value (with a caret when active) broken at newlines and at the row end.
+fn form_field_lines(
+    label: &str,
+    field: &FieldValue,
+    active: bool,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let label_style = Style::default()
+        .fg(Color::Gray)
+        .add_modifier(Modifier::BOLD | Modifier::DIM);
+    let value_style = Style::default().fg(if active { Color::Cyan } else { Color::White });
+    let (prefix, text, rows) = field_rows(label, field, active, width);
+    let spacer = " ".repeat(prefix.chars().count());
+    rows.into_iter()
+        .enumerate()
+        .map(|(idx, range)| {
+            Line::from(vec![
+                Span::styled(
+                    if idx == 0 {
+                        prefix.clone()
+                    } else {
+                        spacer.clone()
+                    },
+                    label_style,
+                ),
+                Span::styled(text[range].to_string(), value_style),
+            ])
+        })
+        .collect()
+}
+
+/// The label prefix, shown text, and byte range of each drawn row of a form
+/// field, shared by drawing and click mapping.
+fn field_rows(
+    label: &str,
+    field: &FieldValue,
+    active: bool,
+    width: usize,
+) -> (String, String, Vec<Range<usize>>) {
+    let prefix = format!("{}: ", label);
+    let text = if active {
+        field.with_caret()
+    } else {
+        field.value.clone()
+    };
+    let room = width.saturating_sub(prefix.chars().count()).max(1);
+    let mut rows = Vec::new();
+    let mut line_start = 0;
+    for line in text.split('\n') {
+        let mut start = line_start;
+        for (count, (idx, _)) in line.char_indices().enumerate() {
+            if count > 0 && count % room == 0 {
+                rows.push(start..line_start + idx);
+                start = line_start + idx;
+            }
+        }
+        rows.push(start..line_start + line.len());
+        line_start += line.len() + 1;
+    }
+    (prefix, text, rows)
+}
+
+/// The field and cursor byte offset for a click `col` cells right of and
+/// `row` rows below the top-left of the form's inside, as drawn at `width`.
+fn form_hit(form: &NoteForm, width: usize, col: usize, row: usize) -> Option<(FormField, usize)> {
+    let mut top = 0;
+    for (label, value, field) in form.fields() {
+        let active = form.field == field;
+        let (prefix, text, rows) = field_rows(label, value, active, width);
+        if row >= top + rows.len() {
+            top += rows.len();
+            continue;
+        }
+        let range = rows[row - top].clone();
+        let cells = col.saturating_sub(prefix.chars().count());
+        let shown = text[range.clone()]
+            .char_indices()
+            .nth(cells)
+            .map_or(range.end, |(idx, _)| range.start + idx);
+        // The caret is drawn but not part of the value.
+        let caret = '▌'.len_utf8();
+        let cursor = if active && shown > value.cursor {
+            shown.saturating_sub(caret).max(value.cursor)
+        } else {
+            shown
+        };
+        return Some((field, cursor));
+    }
+    None
+}
+
+/// What the terminal can display, detected once at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TermCaps {
+    color: bool,
+    unicode: bool,
+}
+
+impl TermCaps {
+    fn detect(config: &Config) -> Self {
+        TermCaps::from_env(
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+            config.ascii_only,
+        )
+    }
+
+    /// `NO_COLOR` (any non-empty value) drops colors; `TERM=dumb` drops colors and Unicode.
+    fn from_env(no_color: Option<&str>, term: Option<&str>, ascii_only: bool) -> Self {
+        let dumb = term == Some("dumb");
+        TermCaps {
+            color: !dumb && no_color.is_none_or(str::is_empty),
+            unicode: !dumb && !ascii_only,
+        }
+    }
+}
+
+/// Rewrites a rendered frame for limited terminals: strips colors (keeping
+/// bold/underline so selection stays visible) and swaps drawing glyphs for ASCII.
+/// Note text itself is left alone.
+fn degrade_buffer(buf: &mut Buffer, caps: TermCaps) {
+    if caps.color && caps.unicode {
+        return;
+    }
+    for cell in buf.content.iter_mut() {
+        if !caps.color {
+            cell.set_fg(Color::Reset);
+            cell.set_bg(Color::Reset);
+        }
+        if !caps.unicode {
+            if let Some(ascii) = ascii_fallback(cell.symbol()) {
+                cell.set_symbol(ascii);
+            }
+        }
+    }
+}
+
+fn ascii_fallback(symbol: &str) -> Option<&'static str> {
+    let ascii = match symbol {
+        "─" | "━" | "═" => "-",
+        "│" | "┃" | "║" => "|",
+        "┌" | "┐" | "└" | "┘" | "╭" | "╮" | "╰" | "╯" | "├" | "┤" | "┬" | "┴" | "┼" => {
+            "+"
+        }
+        "✓" | "•" => "*",
+        "█" | "▇" => "#",
+        "░" | "▂" => ".",
+        "▌" => "_",
+        "←" => "<",
+        "→" => ">",
+        "↑" => "^",
+        "↓" => "v",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Display settings shared by the free-standing item renderers.
+struct RenderOpts<'a> {
+    /// How full board cards fit long titles; one-line rows always truncate.
+    card_titles: TitleFit,
+    date_only: bool,
+    zone: Zone,
+    tag_colors: &'a HashMap<String, Color>,
+}
+
+/// `#tag` spans, each in its tag's color, cut off once `max` characters are used.
+fn tag_spans(
+    tags: &[String],
+    overrides: &HashMap<String, Color>,
+    max: usize,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut used = 0;
+    for (idx, tag) in tags.iter().enumerate() {
+        let sep = if idx == 0 { "" } else { " " };
+        let text = format!("{}#{}", sep, tag);
+        let len = text.chars().count();
+        if used + len > max {
+            let rest = truncate_text(&text, max - used);
+            if !rest.is_empty() {
+                spans.push(Span::styled(
+                    rest,
+                    Style::default().fg(tag_color(tag, overrides)),
+                ));
+            }
+            break;
+        }
+        used += len;
         spans.push(Span::styled(
-            format_due(due),
-            Style::default().fg(Color::LightRed),
+            text,
+            Style::default().fg(tag_color(tag, overrides)),
         ));
     }
-    if !note.tags.is_empty() {
-        spans.push(Span::raw("  "));
-        spans.push(Span::styled(
-            format!("#{}", note.tags.join(" #")),
-            Style::default().fg(Color::LightMagenta),
+    spans
+}
+
+const TAG_PALETTE: [Color; 10] = [
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightRed,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+];
+
+/// Stable color for a tag: the configured override, else an FNV-1a hash into
+/// the palette (std's hasher isn't guaranteed stable across releases).
+fn tag_color(tag: &str, overrides: &HashMap<String, Color>) -> Color {
+    if let Some(color) = overrides.get(tag) {
+        return *color;
+    }
+    let hash = tag.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    TAG_PALETTE[(hash % TAG_PALETTE.len() as u64) as usize]
+}
+
+/// Due date as shown in the TUI; storage always keeps the full timestamp.
+fn due_label(due: &DateTime<Utc>, precision: DuePrecision, opts: &RenderOpts) -> String {
+    if opts.date_only {
+        in_timezone(due, opts.zone).format("%Y-%m-%d").to_string()
+    } else {
+        format_due_as(due, precision, opts.zone)
+    }
+}
+
+/// A note's due date as typed into forms, without a time for day-only ones.
+fn due_field_text(note: &Note, zone: Zone) -> String {
+    note.due
+        .as_ref()
+        .map(|due| format_due_as(due, note.due_precision, zone))
+        .unwrap_or_default()
+}
+
+/// A note's fields on labeled lines, body last and wrapped to `width`.
+/// History entries shown in the detail pane, so they leave room for the body.
+const DETAIL_HISTORY_LEN: usize = 3;
+
+fn selected_note_detail(note: &Note, opts: &RenderOpts, width: usize) -> Vec<Line<'static>> {
+    let label =
+        |name: &str| Span::styled(format!("{:<7}", name), Style::default().fg(Color::DarkGray));
+    let mut title = vec![
+        label("Title"),
+        Span::styled(
+            note.title.clone(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if let Some(waiting_on) = &note.waiting_on {
+        title.push(Span::raw("  "));
+        title.push(Span::styled(
+            format!("waiting on {}", waiting_on),
+            Style::default()
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
         ));
     }
-    if let Some(body) = &note.body {
-        spans.push(Span::raw("  "));
-        spans.push(Span::styled(
-            body.to_string(),
-            Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+    if let Some(priority) = note.priority {
+        title.push(Span::raw("  "));
+        title.push(Span::styled(
+            format!("{} priority", priority.label()),
+            Style::default().fg(priority_color(priority)),
         ));
     }
-    Line::from(spans)
+    let mut lines = vec![Line::from(title)];
+    if let Some(due) = note.due.as_ref() {
+        lines.push(Line::from(vec![
+            label("Due"),
+            Span::styled(
+                due_label(due, note.due_precision, opts),
+                Style::default().fg(Color::LightRed),
+            ),
+        ]));
+    }
+    if !note.tags.is_empty() {
+        let mut spans = vec![label("Tags")];
+        spans.extend(tag_spans(&note.tags, opts.tag_colors, usize::MAX));
+        lines.push(Line::from(spans));
+    }
+    if !note.attachments.is_empty() {
+        let mut spans = vec![label("Files")];
+        for target in &note.attachments {
+            if spans.len() > 1 {
+                spans.push(Span::raw("  "));
+            }
+            if attachment_missing(target) {
+                spans.push(Span::styled(
+                    format!("[{} (missing)]", target),
+                    Style::default().fg(Color::LightRed),
+                ));
+            } else {
+                spans.push(Span::styled(
+                    format!("[{}]", target),
+                    Style::default().fg(Color::LightBlue),
+                ));
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+    // The newest events first, as many as DETAIL_HISTORY_LEN; `show --history`
+    // lists them all.
+    let recent = note.history.len().saturating_sub(DETAIL_HISTORY_LEN);
+    for (idx, event) in note.history[recent..].iter().rev().enumerate() {
+        lines.push(Line::from(vec![
+            label(if idx == 0 { "Events" } else { "" }),
+            Span::styled(
+                format!("{} {}", format_due(&event.at, opts.zone), event.kind),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    if let Some(body) = note.body.as_deref().filter(|b| !b.trim().is_empty()) {
+        lines.push(Line::from(label("Body")));
+        let style = Style::default().fg(Color::Gray);
+        for paragraph in body.lines() {
+            for row in wrap_text(paragraph, width.saturating_sub(2)) {
+                lines.push(Line::from(Span::styled(format!("  {}", row), style)));
+            }
+        }
+    }
+    lines
+}
+
+/// Ids of notes outside the done column whose due time falls in
+/// `(after, until]` and that were not announced yet.
+fn newly_due(
+    board: &Board,
+    after: DateTime<Utc>,
+    until: DateTime<Utc>,
+    announced: &HashSet<String>,
+) -> Vec<String> {
+    let done = board.done_column_id();
+    board
+        .columns
+        .iter()
+        .filter(|column| column.id != done)
+        .flat_map(|column| column.note_ids.iter())
+        .filter(|id| !announced.contains(*id))
+        .filter(|id| {
+            board
+                .notes
+                .get(*id)
+                .and_then(|note| note.due)
+                .is_some_and(|due| due > after && due <= until)
+        })
+        .cloned()
+        .collect()
+}
+
+/// The first `rows` of `lines`; when some do not fit, the last row left
+/// says how many were cut instead.
+fn fit_lines(mut lines: Vec<Line<'static>>, rows: usize) -> Vec<Line<'static>> {
+    if lines.len() <= rows || rows == 0 {
+        return lines;
+    }
+    let hidden = lines.len() - rows + 1;
+    lines.truncate(rows - 1);
+    lines.push(Line::from(Span::styled(
+        format!("… {} more line(s); Ctrl+Z shows all", hidden),
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines
+}
+
+/// Fixed-width text bar such as `███░░░░░░░` for a 0.0..=1.0 ratio.
+fn ratio_gauge(ratio: f64, width: usize) -> String {
+    let filled = ((ratio.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Time until something on screen changes by itself: the "saved … ago" label
+/// ticking over (each second, then minute, then hour) or the next due check.
+/// Never shorter than `poll`, so a busy clock doesn't spin the loop.
+fn idle_wait(since_save: Duration, until_due_check: Duration, poll: Duration) -> Duration {
+    let unit = match since_save.as_secs() {
+        0..=59 => Duration::from_secs(1),
+        60..=3599 => Duration::from_secs(60),
+        _ => Duration::from_secs(3600),
+    };
+    let into_unit = since_save.as_nanos() % unit.as_nanos();
+    let until_label = unit - Duration::from_nanos(into_unit as u64);
+    until_label.min(until_due_check).max(poll)
 }
 
 fn format_elapsed(last: Instant) -> String {
@@ -2062,3 +6265,376 @@ fn format_elapsed(last: Instant) -> String {
         format!("{}h ago", secs / 3600)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Widget;
+
+    #[test]
+    fn view_names_parse_ignoring_case() {
+        assert_eq!(ViewMode::from_name("Timeline"), Some(ViewMode::Timeline));
+        assert_eq!(ViewMode::from_name(" project "), Some(ViewMode::Project));
+        assert_eq!(ViewMode::from_name("board"), Some(ViewMode::Board));
+        assert_eq!(ViewMode::from_name("calendar"), None);
+    }
+
+    #[test]
+    fn picked_tags_combine_as_union_or_intersection_without_repeats() {
+        let note = |id: &str| Note::new(id.into(), id.into(), None, Vec::new(), None);
+        let (a, b, c) = (note("a"), note("b"), note("c"));
+        let tags = vec![
+            ("focus".to_string(), vec![("a", &a), ("b", &b)]),
+            ("home".to_string(), vec![("b", &b), ("c", &c)]),
+            ("work".to_string(), vec![("c", &c)]),
+        ];
+        let picked: BTreeSet<String> = ["focus", "home"].map(String::from).into();
+        let ids = |all| {
+            combine_tag_notes(&tags, &picked, all)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(false), ["a", "b", "c"]);
+        assert_eq!(ids(true), ["b"]);
+    }
+
+    #[test]
+    fn adjust_offset_keeps_scrolloff_context_near_edges() {
+        // 20 items, 5 visible rows, 2 rows of context.
+        assert_eq!(adjust_offset(0, 0, 5, 2, 20), 0);
+        assert_eq!(adjust_offset(2, 0, 5, 2, 20), 0);
+        assert_eq!(adjust_offset(3, 0, 5, 2, 20), 1);
+        assert_eq!(adjust_offset(11, 10, 5, 2, 20), 9);
+        assert_eq!(adjust_offset(12, 10, 5, 2, 20), 10);
+        assert_eq!(adjust_offset(0, 10, 5, 2, 20), 0);
+        assert_eq!(adjust_offset(19, 0, 5, 2, 20), 15);
+    }
+
+    #[test]
+    fn column_cap_hides_overflow_until_selection_passes_it() {
+        assert_eq!(hidden_past_cap(10, None, None), 0);
+        assert_eq!(hidden_past_cap(10, Some(3), None), 7);
+        assert_eq!(hidden_past_cap(10, Some(3), Some(2)), 7);
+        assert_eq!(hidden_past_cap(10, Some(3), Some(3)), 0);
+        assert_eq!(hidden_past_cap(3, Some(3), None), 0);
+        assert_eq!(hidden_past_cap(4, Some(0), None), 4);
+    }
+
+    #[test]
+    fn tags_order_by_name_or_busiest_first() {
+        let group = |tag: &str, n: usize| (tag.to_string(), vec![(); n]);
+        let mut tags = vec![group("web", 2), group("api", 1), group("docs", 2)];
+        let names = |tags: &[(String, Vec<()>)]| -> Vec<String> {
+            tags.iter().map(|(t, _)| t.clone()).collect()
+        };
+        order_tags(&mut tags, TagOrder::Count);
+        assert_eq!(names(&tags), ["docs", "web", "api"]);
+        order_tags(&mut tags, TagOrder::Name);
+        assert_eq!(names(&tags), ["api", "docs", "web"]);
+    }
+
+    #[test]
+    fn column_colors_cycle_back_to_the_default() {
+        assert_eq!(next_column_color(None), Some("cyan"));
+        assert_eq!(next_column_color(Some("cyan")), Some("lightgreen"));
+        assert_eq!(next_column_color(Some("gray")), None);
+        assert_eq!(next_column_color(Some("#ff8800")), None);
+    }
+
+    #[test]
+    fn swimlanes_cycle_and_rank_priorities_high_first() {
+        assert_eq!(LaneKey::cycle(None), Some(LaneKey::Tag));
+        assert_eq!(LaneKey::cycle(Some(LaneKey::Tag)), Some(LaneKey::Priority));
+        assert_eq!(LaneKey::cycle(Some(LaneKey::Priority)), None);
+
+        let mut note = Note::new("a".into(), "a".into(), None, vec!["web".into()], None);
+        assert_eq!(LaneKey::Tag.lane(&note), (0, "web"));
+        assert_eq!(LaneKey::Priority.lane(&note), (3, NO_PRIORITY));
+        note.priority = Some(Priority::High);
+        assert_eq!(LaneKey::Priority.lane(&note), (0, "high"));
+        note.priority = Some(Priority::Low);
+        assert_eq!(LaneKey::Priority.lane(&note), (2, "low"));
+        note.tags.clear();
+        assert_eq!(LaneKey::Tag.lane(&note), (0, UNTAGGED));
+    }
+
+    #[test]
+    fn wip_limit_field_is_blank_or_a_whole_number() {
+        assert_eq!(parse_wip_limit(""), Ok(None));
+        assert_eq!(parse_wip_limit(" 3 "), Ok(Some(3)));
+        assert!(parse_wip_limit("three").is_err());
+        assert!(parse_wip_limit("-1").is_err());
+    }
+
+    #[test]
+    fn new_column_from_the_unfiled_bucket_goes_last() {
+        use crate::storage::{BoardLocation, BoardScope};
+        let dir = std::env::temp_dir().join(format!("postit-ui-{}", std::process::id()));
+        let location = BoardLocation {
+            path: dir.join("board.yml"),
+            scope: BoardScope::Project,
+        };
+        let mut board = Board::default_named("test");
+        let lost = Note::new("lost".into(), "lost".into(), None, Vec::new(), None);
+        board.notes.insert("lost".into(), lost);
+        let columns = board.columns.len();
+        let mut app = App::new(board, location, Config::default(), None, ViewMode::Board);
+        app.selected_column = columns;
+        assert!(app.is_unfiled(app.selected_column));
+
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        app.handle_key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL))
+            .unwrap();
+        for c in "review".chars() {
+            app.handle_key(key(KeyCode::Char(c))).unwrap();
+        }
+        app.handle_key(key(KeyCode::BackTab)).unwrap();
+        app.handle_key(key(KeyCode::Char('2'))).unwrap();
+        app.handle_key(key(KeyCode::Enter)).unwrap();
+
+        let added = app.board.columns.last().unwrap();
+        assert_eq!((added.id.as_str(), added.wip_limit), ("review", Some(2)));
+        assert_eq!(app.selected_column, columns);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn wip_gauge_fills_toward_the_limit_within_its_width() {
+        assert_eq!(wip_gauge(0, 4, 20), "▂▂▂▂");
+        assert_eq!(wip_gauge(2, 4, 20), "▇▇▂▂");
+        assert_eq!(wip_gauge(5, 4, 20), "▇▇▇▇");
+        assert_eq!(wip_gauge(1, 20, 20), "▇▂▂▂▂▂▂▂");
+        assert_eq!(wip_gauge(3, 4, 2), "▇▇");
+        assert_eq!(wip_gauge(3, 4, 0), "");
+    }
+
+    #[test]
+    fn calendar_offset_follows_week_start() {
+        // September 2024 starts on a Sunday, October 2026 on a Thursday.
+        let sunday = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let thursday = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        assert_eq!(first_cell_offset(sunday, WeekStart::Monday), 6);
+        assert_eq!(first_cell_offset(sunday, WeekStart::Sunday), 0);
+        assert_eq!(first_cell_offset(thursday, WeekStart::Monday), 3);
+        assert_eq!(first_cell_offset(thursday, WeekStart::Sunday), 4);
+        for start in [WeekStart::Monday, WeekStart::Sunday] {
+            let offset = first_cell_offset(thursday, start) as usize;
+            assert_eq!(weekday_headings(start)[offset], "Th");
+        }
+    }
+
+    #[test]
+    fn wrap_text_breaks_on_words_and_splits_long_ones() {
+        assert_eq!(
+            wrap_text("fix the login bug", 8),
+            vec!["fix the", "login", "bug"]
+        );
+        assert_eq!(wrap_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap_text("", 4), vec![""]);
+        assert_eq!(wrap_text("a  b", 10), vec!["a b"]);
+    }
+
+    #[test]
+    fn step_index_stops_or_wraps_at_the_ends() {
+        assert_eq!(step_index(0, 4, true, false), Some(1));
+        assert_eq!(step_index(3, 4, true, false), None);
+        assert_eq!(step_index(0, 4, false, false), None);
+        assert_eq!(step_index(3, 4, true, true), Some(0));
+        assert_eq!(step_index(0, 4, false, true), Some(3));
+        assert_eq!(step_index(0, 1, true, true), Some(0));
+        assert_eq!(step_index(0, 0, true, true), None);
+    }
+
+    #[test]
+    fn move_target_clamps_or_cycles() {
+        assert_eq!(move_target(1, 4, 1, false), 2);
+        assert_eq!(move_target(3, 4, 1, false), 3);
+        assert_eq!(move_target(0, 4, -1, false), 0);
+        assert_eq!(move_target(3, 4, 1, true), 0);
+        assert_eq!(move_target(0, 4, -1, true), 3);
+        assert_eq!(move_target(2, 4, 1, true), 3);
+        assert_eq!(move_target(0, 0, 1, true), 0);
+    }
+
+    #[test]
+    fn page_index_clamps_at_both_ends() {
+        assert_eq!(page_index(0, 20, 5, true), 5);
+        assert_eq!(page_index(17, 20, 5, true), 19);
+        assert_eq!(page_index(19, 20, 5, true), 19);
+        assert_eq!(page_index(7, 20, 5, false), 2);
+        assert_eq!(page_index(2, 20, 5, false), 0);
+        assert_eq!(page_index(0, 0, 5, true), 0);
+        assert_eq!(page_index(3, 20, 0, true), 4);
+    }
+
+    #[test]
+    fn adjust_offset_clamps_scrolloff_to_viewport() {
+        assert_eq!(adjust_offset(3, 0, 5, 10, 20), 1);
+        assert_eq!(adjust_offset(19, 0, 5, 10, 20), 15);
+        assert_eq!(adjust_offset(4, 0, 1, 10, 20), 4);
+    }
+
+    #[test]
+    fn tag_color_is_stable_and_spread() {
+        let none = HashMap::new();
+        assert_eq!(tag_color("backend", &none), tag_color("backend", &none));
+        let used: HashSet<String> = (0..40)
+            .map(|i| format!("{:?}", tag_color(&format!("tag{}", i), &none)))
+            .collect();
+        assert!(used.len() >= TAG_PALETTE.len() / 2, "only {:?}", used);
+    }
+
+    #[test]
+    fn term_caps_from_env() {
+        let full = TermCaps {
+            color: true,
+            unicode: true,
+        };
+        assert_eq!(
+            TermCaps::from_env(None, Some("xterm-256color"), false),
+            full
+        );
+        assert_eq!(TermCaps::from_env(Some(""), None, false), full);
+        assert!(!TermCaps::from_env(Some("1"), None, false).color);
+        assert!(!TermCaps::from_env(None, None, true).unicode);
+        assert_eq!(
+            TermCaps::from_env(None, Some("dumb"), false),
+            TermCaps {
+                color: false,
+                unicode: false
+            }
+        );
+    }
+
+    #[test]
+    fn degrade_buffer_falls_back_to_plain_ascii() {
+        let area = Rect::new(0, 0, 8, 3);
+        let mut buf = Buffer::empty(area);
+        Paragraph::new("✓ café")
+            .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL))
+            .render(area, &mut buf);
+
+        degrade_buffer(
+            &mut buf,
+            TermCaps {
+                color: false,
+                unicode: false,
+            },
+        );
+
+        let row = |y| (0..8).map(|x| buf.get(x, y).symbol()).collect::<String>();
+        assert_eq!(row(0), "+------+");
+        assert_eq!(row(1), "|* café|");
+        assert_eq!(buf.get(1, 1).fg, Color::Reset);
+        assert!(buf.get(1, 1).modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn newly_due_skips_done_announced_and_earlier_notes() {
+        let now = Utc::now();
+        let mut board = Board::default_named("test");
+        for (id, column, minutes_ago) in [
+            ("a", "todo", 1),
+            ("b", "done", 1),
+            ("c", "todo", 60),
+            ("d", "doing", 2),
+        ] {
+            let due = Some(now - ChronoDuration::minutes(minutes_ago));
+            let note = Note::new(id.into(), id.into(), None, vec![], due);
+            board.add_note(note, column).unwrap();
+        }
+        let after = now - ChronoDuration::minutes(5);
+        let announced = HashSet::from(["d".to_string()]);
+        assert_eq!(newly_due(&board, after, now, &announced), vec!["a"]);
+        assert!(newly_due(&board, now, now, &HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn form_clicks_map_to_field_and_cursor() {
+        let mut form = NoteForm::new();
+        form.title = FieldValue::new("abcdefghij");
+        form.body = FieldValue::new("one\ntwo");
+        form.field = FormField::Body;
+        // "Title: " takes 7 cells, leaving 5 per row at width 12.
+        assert_eq!(form_hit(&form, 12, 7, 0), Some((FormField::Title, 0)));
+        assert_eq!(form_hit(&form, 12, 9, 1), Some((FormField::Title, 7)));
+        assert_eq!(form_hit(&form, 12, 0, 1), Some((FormField::Title, 5)));
+        // The body's caret sits after "two"; clicks past it land at the end.
+        assert_eq!(form_hit(&form, 12, 7, 2), Some((FormField::Body, 1)));
+        assert_eq!(form_hit(&form, 12, 11, 3), Some((FormField::Body, 7)));
+        form.body.cursor = 1;
+        assert_eq!(form_hit(&form, 12, 9, 2), Some((FormField::Body, 2)));
+        assert_eq!(form_hit(&form, 12, 6, 4), Some((FormField::Tags, 0)));
+        assert_eq!(form_hit(&form, 12, 0, 9), None);
+    }
+
+    #[test]
+    fn idle_wait_sleeps_until_the_next_visible_change() {
+        let (secs, ms) = (Duration::from_secs, Duration::from_millis);
+        let poll = ms(200);
+        assert_eq!(idle_wait(ms(5_300), secs(15), poll), ms(700));
+        assert_eq!(idle_wait(ms(5_950), secs(15), poll), poll);
+        assert_eq!(idle_wait(secs(90), secs(15), poll), secs(15));
+        assert_eq!(idle_wait(secs(90), secs(60), poll), secs(30));
+        assert_eq!(idle_wait(secs(7_000), secs(0), poll), poll);
+    }
+
+    #[test]
+    fn column_picker_filters_fuzzily_and_puts_recent_columns_first() {
+        let mut review = Column::new("review");
+        review.name = Some("Code Review".into());
+        let columns = vec![
+            Column::new("todo"),
+            Column::new("doing"),
+            review,
+            Column::new("done"),
+        ];
+        assert_eq!(column_picker_matches(&columns, &[], ""), vec![0, 1, 2, 3]);
+        assert_eq!(column_picker_matches(&columns, &[], "dn"), vec![1, 3]);
+        assert_eq!(column_picker_matches(&columns, &[], "CODE rv"), vec![2]);
+        let recent = vec!["done".to_string(), "todo".to_string()];
+        assert_eq!(
+            column_picker_matches(&columns, &recent, ""),
+            vec![3, 0, 1, 2]
+        );
+        assert_eq!(
+            column_picker_matches(&columns, &recent, "do"),
+            vec![3, 0, 1]
+        );
+    }
+
+    #[test]
+    fn fit_lines_notes_what_was_cut() {
+        let lines = |n: usize| {
+            (0..n)
+                .map(|i| Line::from(i.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(fit_lines(lines(3), 3).len(), 3);
+        let fitted = fit_lines(lines(5), 3);
+        assert_eq!(fitted.len(), 3);
+        assert_eq!(fitted[1].spans[0].content, "1");
+        assert!(fitted[2].spans[0].content.starts_with("… 3 more"));
+    }
+
+    #[test]
+    fn tag_color_honors_overrides() {
+        let overrides = HashMap::from([("urgent".to_string(), Color::Red)]);
+        assert_eq!(tag_color("urgent", &overrides), Color::Red);
+    }
+
+    #[test]
+    fn tag_spans_respect_width() {
+        let tags = vec!["alpha".to_string(), "beta".to_string()];
+        let width = |max| {
+            tag_spans(&tags, &HashMap::new(), max)
+                .iter()
+                .map(|span| span.content.chars().count())
+                .sum::<usize>()
+        };
+        assert_eq!(width(usize::MAX), "#alpha #beta".len());
+        assert_eq!(width(9), 9);
+    }
+}
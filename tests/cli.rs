@@ -0,0 +1,753 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_SANDBOX: AtomicUsize = AtomicUsize::new(0);
+
+/// Scratch working directory with its own data/config dirs so tests never
+/// touch the real global board.
+struct Sandbox {
+    dir: PathBuf,
+    tz: &'static str,
+}
+
+impl Sandbox {
+    fn new() -> Self {
+        let dir = env::temp_dir().join(format!(
+            "postit-test-{}-{}",
+            std::process::id(),
+            NEXT_SANDBOX.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).expect("create sandbox");
+        Sandbox { dir, tz: "UTC" }
+    }
+
+    /// Runs postit with `TZ` set to `tz` instead of UTC.
+    fn with_tz(mut self, tz: &'static str) -> Self {
+        self.tz = tz;
+        self
+    }
+
+    fn run(&self, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_postit"))
+            .args(args)
+            .current_dir(&self.dir)
+            .env("HOME", &self.dir)
+            .env("XDG_DATA_HOME", self.dir.join("data"))
+            .env("XDG_CONFIG_HOME", self.dir.join("config"))
+            .env("TZ", self.tz)
+            .output()
+            .expect("run postit")
+    }
+
+    fn run_ok(&self, args: &[&str]) -> String {
+        let output = self.run(args);
+        assert!(
+            output.status.success(),
+            "postit {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    }
+
+    fn add(&self, args: &[&str]) -> String {
+        let mut full = vec!["add"];
+        full.extend_from_slice(args);
+        let stdout = self.run_ok(&full);
+        stdout
+            .split_whitespace()
+            .nth(2)
+            .expect("note id in add output")
+            .to_string()
+    }
+
+    fn board_bytes(&self) -> Vec<u8> {
+        fs::read(self.dir.join(".postit/board.yml")).expect("read board")
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn dry_run_leaves_board_file_untouched() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["first", "--tag", "a"]);
+    let before = sandbox.board_bytes();
+
+    let out = sandbox.run_ok(&["--dry-run", "add", "second"]);
+    assert!(out.starts_with("Would add note"));
+    let out = sandbox.run_ok(&["--dry-run", "move", &id, "doing"]);
+    assert!(out.contains("todo -> doing"));
+    let out = sandbox.run_ok(&["edit", &id, "--title", "renamed", "--dry-run"]);
+    assert!(out.contains("first") && out.contains("renamed"));
+    sandbox.run_ok(&["--dry-run", "delete", &id]);
+    let out = sandbox.run_ok(&["--dry-run", "touch", &id]);
+    assert_eq!(out.trim(), format!("Would touch {}", id));
+
+    assert_eq!(before, sandbox.board_bytes());
+
+    let fresh = Sandbox::new();
+    let out = fresh.run_ok(&["--dry-run", "add", "first"]);
+    assert!(out.starts_with("Would add note"), "{}", out);
+    assert_eq!(fresh.run(&["list"]).status.code(), Some(4));
+}
+
+#[test]
+fn list_without_board_creates_nothing() {
+    let sandbox = Sandbox::new();
+
+    let output = sandbox.run(&["list"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("postit init"));
+
+    let leftovers: Vec<_> = fs::read_dir(&sandbox.dir).expect("read sandbox").collect();
+    assert!(leftovers.is_empty(), "list created {:?}", leftovers);
+}
+
+#[test]
+fn move_and_edit_without_board_create_nothing() {
+    let sandbox = Sandbox::new();
+    for args in [
+        &["move", "abc123", "done"][..],
+        &["edit", "abc123", "--title", "renamed"],
+    ] {
+        let output = sandbox.run(args);
+        assert_eq!(output.status.code(), Some(4), "{:?}", args);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("run `postit init`"),
+            "{:?}: {}",
+            args,
+            stderr
+        );
+        assert!(!stderr.contains("note not found"), "{:?}: {}", args, stderr);
+    }
+    let leftovers: Vec<_> = fs::read_dir(&sandbox.dir).expect("read sandbox").collect();
+    assert!(leftovers.is_empty(), "move/edit created {:?}", leftovers);
+}
+
+#[test]
+fn edit_append_body_adds_a_line() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let bare = sandbox.add(&["bare"]);
+    let noted = sandbox.add(&["noted", "--body", "first line"]);
+
+    sandbox.run_ok(&["edit", &bare, "--append-body", "only line"]);
+    sandbox.run_ok(&["edit", &noted, "--append-body", "second line"]);
+
+    assert!(sandbox.run_ok(&["show", &bare]).contains("    only line\n"));
+    assert!(sandbox
+        .run_ok(&["show", &noted])
+        .contains("    first line\nsecond line\n"));
+
+    let both = sandbox.run(&["edit", &noted, "--body", "x", "--append-body", "y"]);
+    assert!(!both.status.success());
+}
+
+#[test]
+fn move_create_column_only_with_flag() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["ship it"]);
+
+    let missing = sandbox.run(&["move", &id, "in-review"]);
+    assert!(!missing.status.success());
+    assert!(!String::from_utf8_lossy(&sandbox.board_bytes()).contains("in-review"));
+
+    sandbox.run_ok(&["move", &id, "in-review", "--create-column"]);
+    let board = String::from_utf8_lossy(&sandbox.board_bytes()).into_owned();
+    assert!(board.contains("id: in-review") && board.contains("name: In Review"));
+    assert!(sandbox
+        .run_ok(&["list", "--column", "in-review"])
+        .contains("ship it"));
+}
+
+#[test]
+fn info_reports_missing_board_without_creating_it() {
+    let sandbox = Sandbox::new();
+
+    let out = sandbox.run_ok(&["info"]);
+    assert!(out.contains("missing (run `postit init`"));
+    let leftovers: Vec<_> = fs::read_dir(&sandbox.dir).expect("read sandbox").collect();
+    assert!(leftovers.is_empty(), "info created {:?}", leftovers);
+
+    sandbox.run_ok(&["init"]);
+    sandbox.add(&["one"]);
+    let out = sandbox.run_ok(&["info"]);
+    assert!(out.contains("(project)") && out.contains("notes: 1"));
+}
+
+#[test]
+fn attachments_flag_missing_paths() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    fs::write(sandbox.dir.join("spec.md"), "spec").expect("write attachment");
+    let id = sandbox.add(&["read spec", "--attach", "spec.md"]);
+    sandbox.run_ok(&[
+        "edit",
+        &id,
+        "--attach",
+        "gone.txt",
+        "--attach",
+        "https://x.y",
+    ]);
+
+    let shown = sandbox.run_ok(&["show", &id]);
+    assert!(shown.contains("attachment: spec.md\n"));
+    assert!(shown.contains("attachment: gone.txt (missing)"));
+    assert!(shown.contains("attachment: https://x.y\n"));
+
+    sandbox.run_ok(&["edit", &id, "--detach", "gone.txt"]);
+    assert!(!sandbox.run_ok(&["show", &id]).contains("gone.txt"));
+}
+
+#[test]
+fn edit_due_shift_moves_existing_due() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let dated = sandbox.add(&["dated", "--due", "2024.01.30@10:00"]);
+    let undated = sandbox.add(&["undated"]);
+
+    sandbox.run_ok(&["edit", &dated, "--due-shift", "+3d"]);
+    assert!(sandbox
+        .run_ok(&["show", &dated])
+        .contains("due: 2024.02.02@10:00"));
+    sandbox.run_ok(&["edit", &dated, "--due-shift", "-1w"]);
+    assert!(sandbox
+        .run_ok(&["show", &dated])
+        .contains("due: 2024.01.26@10:00"));
+
+    assert!(!sandbox
+        .run(&["edit", &undated, "--due-shift", "1d"])
+        .status
+        .success());
+    sandbox.run_ok(&["edit", &undated, "--due-shift", "1d", "--from-now"]);
+    assert!(sandbox.run_ok(&["show", &undated]).contains("due: "));
+}
+
+#[test]
+fn import_previews_then_replaces_board() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let old = sandbox.add(&["old note"]);
+    let before = sandbox.board_bytes();
+
+    let other = Sandbox::new();
+    other.run_ok(&["init"]);
+    other.add(&["incoming note"]);
+    let file = sandbox.dir.join("incoming.yml");
+    fs::write(&file, other.board_bytes()).expect("write import file");
+    let file = file.to_str().expect("utf-8 path");
+
+    let preview = sandbox.run_ok(&["--dry-run", "import", file]);
+    assert!(preview.contains("+ ") && preview.contains("incoming note"));
+    assert!(preview.contains(&format!("- {}: old note", old)));
+    assert_eq!(before, sandbox.board_bytes());
+
+    let declined = sandbox.run_ok(&["import", file]);
+    assert!(declined.contains("Import canceled"));
+    assert_eq!(before, sandbox.board_bytes());
+
+    sandbox.run_ok(&["import", file, "--yes"]);
+    assert_eq!(other.board_bytes(), sandbox.board_bytes());
+    assert!(sandbox.run_ok(&["import", file]).contains("No changes"));
+
+    let limited = String::from_utf8(other.board_bytes()).expect("utf-8 board");
+    let limited = limited.replacen("- id: doing\n", "- id: doing\n  wip_limit: 1\n", 1);
+    fs::write(file, limited).expect("write import file");
+    let imported = sandbox.run_ok(&["import", file, "--yes"]);
+    assert!(imported.contains("~ doing (wip_limit)"), "{}", imported);
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.contains("wip_limit: 1"), "{}", board);
+}
+
+#[test]
+fn restore_lists_backups_and_restores_one() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    assert!(sandbox.run_ok(&["restore"]).contains("No backups"));
+    let before = sandbox.board_bytes();
+    sandbox.add(&["oops"]);
+    let after = sandbox.board_bytes();
+
+    let listing = sandbox.run_ok(&["restore"]);
+    assert!(listing.contains("0 notes"), "{}", listing);
+    let stamp = listing.split_whitespace().next().expect("backup stamp");
+
+    let declined = sandbox.run_ok(&["restore", stamp]);
+    assert!(declined.contains("Restore canceled"));
+    assert_eq!(after, sandbox.board_bytes());
+
+    sandbox.run_ok(&["restore", stamp, "--yes"]);
+    assert_eq!(before, sandbox.board_bytes());
+    // The board that was replaced is itself kept as a backup.
+    assert!(sandbox.run_ok(&["restore"]).contains("1 note"));
+    assert!(!sandbox.run(&["restore", "nope"]).status.success());
+}
+
+#[test]
+fn column_describe_sets_and_clears_description() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    sandbox.run_ok(&["column", "describe", "doing", "Limit 3: we context switch"]);
+    assert!(sandbox
+        .run_ok(&["list"])
+        .contains("(Limit 3: we context switch)"));
+    sandbox.run_ok(&["column", "describe", "doing"]);
+    assert!(!sandbox.run_ok(&["list"]).contains("Limit 3"));
+    assert!(!sandbox
+        .run(&["column", "describe", "nope", "text"])
+        .status
+        .success());
+}
+
+#[test]
+fn column_icon_is_saved_and_removed() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    sandbox.run_ok(&["column", "set-icon", "doing", "🚧"]);
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.contains("icon: 🚧"), "{}", board);
+    sandbox.run_ok(&["column", "set-icon", "doing"]);
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(!board.contains("icon:"), "{}", board);
+    assert_eq!(
+        sandbox
+            .run(&["column", "set-icon", "nope", "x"])
+            .status
+            .code(),
+        Some(3)
+    );
+}
+
+#[test]
+fn mistyped_column_suggests_closest_id() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["task"]);
+    let output = sandbox.run(&["move", &id, "doign"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("did you mean `doing`?"));
+    let output = sandbox.run(&["add", "other", "--column", "dnoe"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("did you mean `done`?"));
+}
+
+#[test]
+fn stats_json_and_snapshot() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    sandbox.add(&["one"]);
+    let json: serde_json::Value =
+        serde_json::from_str(&sandbox.run_ok(&["stats", "--json"])).expect("stats json");
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["notes"], 1);
+
+    let log = sandbox.dir.join(".postit").join("stats.jsonl");
+    sandbox.run_ok(&["stats", "--snapshot"]);
+    sandbox.run_ok(&["stats", "--json", "--snapshot"]);
+    let lines = fs::read_to_string(&log).expect("stats log");
+    assert_eq!(lines.lines().count(), 2);
+}
+
+#[test]
+fn finished_lists_notes_recently_moved_to_done() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    assert!(sandbox.run_ok(&["finished"]).contains("Nothing finished"));
+    let shipped = sandbox.add(&["ship it", "--tag", "release"]);
+    sandbox.add(&["still open"]);
+    sandbox.run_ok(&["move", &shipped, "done"]);
+
+    let out = sandbox.run_ok(&["finished", "--since", "2h"]);
+    assert!(out.contains(&format!("- ship it #release ({})", shipped)));
+    assert!(!out.contains("still open"));
+    let json: serde_json::Value =
+        serde_json::from_str(&sandbox.run_ok(&["finished", "--json"])).expect("finished json");
+    assert_eq!(json["column"], "done");
+    assert_eq!(json["notes"][0]["id"], shipped.as_str());
+}
+
+#[test]
+fn normalize_cleans_notes_only_when_confirmed() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["  padded  ", "--tag", " Work", "--tag", "work"]);
+    let before = sandbox.board_bytes();
+    assert!(sandbox
+        .run_ok(&["normalize"])
+        .contains("Normalize canceled"));
+    assert_eq!(sandbox.board_bytes(), before);
+
+    assert!(sandbox
+        .run_ok(&["normalize", "--yes"])
+        .contains("Normalized 1 of 1 note(s)"));
+    let shown = sandbox.run_ok(&["show", &id, "--history"]);
+    assert!(shown.contains("padded") && !shown.contains("  padded  "));
+    assert!(shown.contains(" edited"), "{}", shown);
+    assert!(sandbox
+        .run_ok(&["normalize"])
+        .contains("Nothing to normalize"));
+}
+
+#[test]
+fn board_settings_drive_defaults_and_timezone() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    assert!(sandbox.run_ok(&["settings"]).contains("timezone: (unset)"));
+    assert!(!sandbox.run(&["settings", "colour", "x"]).status.success());
+    assert!(!sandbox
+        .run(&["settings", "default_column", "doign"])
+        .status
+        .success());
+    assert!(!sandbox
+        .run(&["settings", "timezone", "mars"])
+        .status
+        .success());
+
+    sandbox.run_ok(&["settings", "default_column", "doing"]);
+    sandbox.run_ok(&["settings", "timezone", "+02:00"]);
+    assert_eq!(sandbox.run_ok(&["settings", "timezone"]).trim(), "+02:00");
+    let id = sandbox.add(&["task", "--due", "2024.03.09@17:00"]);
+    let listing = sandbox.run_ok(&["list", "--column", "doing"]);
+    assert!(listing.contains(&id), "{}", listing);
+    assert!(listing.contains("due: 2024.03.09@17:00"), "{}", listing);
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.contains("2024-03-09T15:00:00Z"), "{}", board);
+
+    let config = sandbox.dir.join("config/postit");
+    fs::create_dir_all(&config).unwrap();
+    fs::write(config.join("config.yml"), "timezone: \"-01:00\"\n").unwrap();
+    assert!(sandbox.run_ok(&["list"]).contains("due: 2024.03.09@17:00"));
+
+    sandbox.run_ok(&["settings", "timezone", "--unset"]);
+    assert!(sandbox.run_ok(&["list"]).contains("due: 2024.03.09@14:00"));
+}
+
+#[test]
+fn local_due_times_across_clock_changes_resolve_with_a_warning() {
+    let sandbox = Sandbox::new().with_tz("America/New_York");
+    sandbox.run_ok(&["init"]);
+    sandbox.run_ok(&["settings", "timezone", "local"]);
+
+    let skipped = sandbox.run(&["add", "gap", "--due", "2024.03.10@02:30"]);
+    assert!(skipped.status.success());
+    let stderr = String::from_utf8_lossy(&skipped.stderr);
+    assert!(stderr.contains("does not exist"), "{}", stderr);
+    assert!(stderr.contains("2024.03.10@03:30 -04:00"), "{}", stderr);
+
+    let repeated = sandbox.run(&["add", "overlap", "--due", "2024.11.03@01:30"]);
+    assert!(repeated.status.success());
+    let stderr = String::from_utf8_lossy(&repeated.stderr);
+    assert!(stderr.contains("happens twice"), "{}", stderr);
+
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.contains("2024-03-10T07:30:00Z"), "{}", board);
+    assert!(board.contains("2024-11-03T06:30:00Z"), "{}", board);
+    let listing = sandbox.run_ok(&["list"]);
+    assert!(listing.contains("due: 2024.03.10@03:30"), "{}", listing);
+    assert!(listing.contains("due: 2024.11.03@01:30"), "{}", listing);
+
+    let plain = sandbox.run(&["add", "summer", "--due", "2024.07.01@09:00"]);
+    assert!(!String::from_utf8_lossy(&plain.stderr).contains("clocks"));
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.contains("2024-07-01T13:00:00Z"), "{}", board);
+}
+
+#[test]
+fn waiting_on_is_set_shown_and_flagged_when_the_note_moves_on() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["ship it", "--column", "waiting"]);
+    sandbox.run_ok(&["edit", &id, "--waiting-on", "review from Sam"]);
+    assert!(sandbox
+        .run_ok(&["show", &id])
+        .contains("waiting on: review from Sam"));
+
+    let moved = sandbox.run_ok(&["move", &id, "doing"]);
+    assert!(
+        moved.contains("still waiting on \"review from Sam\""),
+        "{}",
+        moved
+    );
+    sandbox.run_ok(&["edit", &id, "--clear-waiting-on"]);
+    assert!(!sandbox.run_ok(&["show", &id]).contains("waiting on"));
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(!board.contains("waiting_on"), "{}", board);
+}
+
+#[test]
+fn copy_puts_a_fresh_note_on_the_global_board() {
+    let sandbox = Sandbox::new();
+    assert!(!sandbox.run(&["copy", "abc123", "global"]).status.success());
+    sandbox.add(&["global chore"]);
+    let global_path = sandbox.dir.join("data/postit/board.yml");
+    assert!(global_path.exists());
+
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["shared task", "--tag", "infra", "--column", "doing"]);
+    let project_before = sandbox.board_bytes();
+    let err = sandbox.run(&["copy", &id, "project"]);
+    assert!(String::from_utf8_lossy(&err.stderr).contains("already on the project board"));
+    assert!(!sandbox
+        .run(&["copy", &id, "global", "--column", "doign"])
+        .status
+        .success());
+
+    let out = sandbox.run_ok(&["copy", &id, "global"]);
+    let new_id = out
+        .split_whitespace()
+        .nth(8)
+        .expect("new id in copy output");
+    assert_ne!(new_id, id);
+    assert_eq!(sandbox.board_bytes(), project_before);
+    let global = fs::read_to_string(&global_path).expect("read global board");
+    assert!(global.contains(&format!("id: {}", new_id)), "{}", global);
+    assert!(global.contains("shared task"), "{}", global);
+    assert!(global.contains("infra"), "{}", global);
+}
+
+#[test]
+fn init_keeps_an_existing_board_unless_forced() {
+    let sandbox = Sandbox::new();
+    assert!(sandbox.run_ok(&["init"]).starts_with("Initialized board"));
+    let id = sandbox.add(&["keep me"]);
+    let before = sandbox.board_bytes();
+
+    let out = sandbox.run_ok(&["init", "--name", "other"]);
+    assert!(out.contains("already exists"), "{}", out);
+    assert!(out.contains("nothing changed"), "{}", out);
+    assert_eq!(sandbox.board_bytes(), before);
+
+    let backups = sandbox.run_ok(&["restore"]);
+    let out = sandbox.run_ok(&["--dry-run", "init", "--force"]);
+    assert!(out.starts_with("Would reinitialize"), "{}", out);
+    assert!(out.contains("would be backed up"), "{}", out);
+    assert_eq!(sandbox.board_bytes(), before);
+    assert_eq!(sandbox.run_ok(&["restore"]), backups);
+
+    let out = sandbox.run_ok(&["init", "--force", "--name", "fresh"]);
+    assert!(out.starts_with("Reinitialized board"), "{}", out);
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.starts_with("name: fresh"), "{}", board);
+    assert!(!board.contains(&id), "{}", board);
+    let backups = sandbox.run_ok(&["restore"]);
+    assert!(backups.contains("1 note"), "{}", backups);
+}
+
+#[test]
+fn search_matches_plain_text_and_regexes_across_the_board() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let ticket = sandbox.add(&["fix login", "--body", "see ticket ABC-123"]);
+    let tagged = sandbox.add(&["tidy docs", "--tag", "Backend", "--column", "doing"]);
+    sandbox.add(&["unrelated"]);
+
+    let plain = sandbox.run_ok(&["search", "backend"]);
+    assert!(
+        plain.contains(&tagged) && !plain.contains(&ticket),
+        "{}",
+        plain
+    );
+    let regex = sandbox.run_ok(&["search", "--regex", r"[A-Z]+-\d+"]);
+    assert!(
+        regex.contains(&ticket) && !regex.contains(&tagged),
+        "{}",
+        regex
+    );
+    assert!(sandbox
+        .run_ok(&["search", "nothing here"])
+        .contains("No notes match"));
+
+    let bad = sandbox.run(&["search", "--regex", "("]);
+    assert!(!bad.status.success());
+    assert!(String::from_utf8_lossy(&bad.stderr).contains("invalid regex \"(\""));
+}
+
+#[test]
+fn due_dates_far_in_the_past_warn_but_are_kept() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let typo = sandbox.run(&["add", "renew domain", "--due", "2020.03.01"]);
+    assert!(typo.status.success());
+    let stderr = String::from_utf8_lossy(&typo.stderr);
+    assert!(stderr.contains("days in the past"), "{}", stderr);
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    assert!(board.contains("2020-03-01T00:00:00Z"), "{}", board);
+
+    let id = sandbox.add(&["later"]);
+    let edited = sandbox.run(&["edit", &id, "--title", "later still"]);
+    assert!(
+        edited.stderr.is_empty(),
+        "{}",
+        String::from_utf8_lossy(&edited.stderr)
+    );
+    let edited = sandbox.run(&["edit", &id, "--due", "2019.12.31"]);
+    assert!(String::from_utf8_lossy(&edited.stderr).contains("days in the past"));
+}
+
+#[test]
+fn failures_exit_with_a_code_for_their_kind() {
+    let sandbox = Sandbox::new();
+    let code = |args: &[&str]| sandbox.run(args).status.code();
+    assert_eq!(code(&["list"]), Some(4));
+    assert_eq!(code(&["copy", "abc123", "global"]), Some(4));
+
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["one"]);
+    assert_eq!(code(&["show", "nope"]), Some(3));
+    assert_eq!(code(&["edit", "nope", "--title", "x"]), Some(3));
+    let missing_column = sandbox.run(&["move", &id, "doign"]);
+    assert_eq!(missing_column.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&missing_column.stderr).contains("did you mean `doing`?"));
+    assert_eq!(code(&["merge", &id, &id]), Some(5));
+    let board = String::from_utf8(sandbox.board_bytes()).expect("utf-8 board");
+    let board = board.replacen("- id: doing\n", "- id: doing\n  wip_limit: 1\n", 1);
+    fs::write(sandbox.dir.join(".postit/board.yml"), board).expect("write board");
+    sandbox.run_ok(&["move", &id, "doing"]);
+    let second = sandbox.add(&["two"]);
+    assert_eq!(code(&["move", &second, "doing"]), Some(6));
+    assert_eq!(code(&["search", "--regex", "("]), Some(1));
+    assert_eq!(code(&["frobnicate"]), Some(2));
+}
+
+#[test]
+fn list_groups_by_tag_with_shared_notes_under_each() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let both = sandbox.add(&["wire api", "--tag", "web", "--tag", "api"]);
+    let bare = sandbox.add(&["loose end"]);
+
+    let grouped = sandbox.run_ok(&["list", "--group-by", "tag"]);
+    let section = |tag: &str| {
+        let start = grouped.find(&format!("\n{} (", tag)).expect(tag);
+        grouped[start + 1..]
+            .split("\n\n")
+            .next()
+            .unwrap()
+            .to_string()
+    };
+    assert!(section("api").contains(&both), "{}", grouped);
+    assert!(section("web").contains(&both), "{}", grouped);
+    assert!(section("(untagged)").contains(&bare), "{}", grouped);
+    assert!(!section("web").contains(&bare), "{}", grouped);
+    assert!(sandbox.run_ok(&["list"]).contains("todo ("));
+}
+
+#[test]
+fn list_overdue_shows_past_due_notes_outside_done() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    sandbox.add(&["late report", "--due", "2020.03.01"]);
+    sandbox.add(&["next year", "--due", "2099.01.01"]);
+    let shipped = sandbox.add(&["shipped late", "--due", "2020.03.02"]);
+    sandbox.run_ok(&["move", &shipped, "done"]);
+
+    let out = sandbox.run_ok(&["list", "--overdue"]);
+    assert!(out.contains("late report"), "{}", out);
+    assert!(!out.contains("next year"), "{}", out);
+    assert!(!out.contains("shipped late"), "{}", out);
+    assert!(!out.contains("done ("), "{}", out);
+
+    let out = sandbox.run_ok(&["list", "--overdue", "--group-by", "tag"]);
+    assert!(out.contains("late report"), "{}", out);
+    assert!(!out.contains("next year"), "{}", out);
+}
+
+#[test]
+fn git_autocommit_records_each_save_as_a_commit() {
+    let sandbox = Sandbox::new();
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&sandbox.dir)
+            .env("HOME", &sandbox.dir)
+            .output()
+            .expect("run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+    git(&["init", "-q"]);
+    git(&["config", "user.name", "Test"]);
+    git(&["config", "user.email", "test@example.com"]);
+    let config = sandbox.dir.join("config/postit");
+    fs::create_dir_all(&config).unwrap();
+    fs::write(config.join("config.yml"), "git_autocommit: true\n").unwrap();
+
+    sandbox.run_ok(&["init"]);
+    let id = sandbox.add(&["write tests"]);
+    sandbox.run_ok(&["move", &id, "doing"]);
+
+    let log = git(&["log", "--format=%s"]);
+    let subjects: Vec<&str> = log.lines().collect();
+    assert_eq!(
+        subjects,
+        [
+            format!("postit: move note {}", id),
+            format!("postit: add note {}", id),
+            "postit: create board".to_string(),
+        ]
+    );
+    assert!(git(&["status", "--porcelain", ".postit/board.yml"]).is_empty());
+}
+
+#[test]
+fn branch_tag_adds_the_checked_out_branch() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+
+    let outside = sandbox.run(&["add", "no repo", "--branch-tag", "-t", "x"]);
+    assert!(outside.status.success());
+    assert!(String::from_utf8_lossy(&outside.stderr).contains("not tagging the git branch"));
+
+    let status = Command::new("git")
+        .args(["init", "-q", "-b", "feature-login"])
+        .current_dir(&sandbox.dir)
+        .status()
+        .expect("run git");
+    assert!(status.success());
+    let id = sandbox.add(&["fix redirect", "--branch-tag"]);
+    let shown = sandbox.run_ok(&["show", &id]);
+    assert!(shown.contains("feature-login"), "{}", shown);
+}
+
+#[test]
+fn due_dates_remember_whether_a_time_was_given() {
+    let sandbox = Sandbox::new();
+    sandbox.run_ok(&["init"]);
+    let day = sandbox.add(&["by end of day", "--due", "2030.05.01"]);
+    let timed = sandbox.add(&["at a time", "--due", "2030.05.01@14:30"]);
+
+    assert!(sandbox
+        .run_ok(&["show", &day])
+        .contains("due: 2030.05.01\n"));
+    assert!(sandbox
+        .run_ok(&["show", &timed])
+        .contains("due: 2030.05.01@14:30"));
+    let board = String::from_utf8(sandbox.board_bytes()).unwrap();
+    assert_eq!(board.matches("due_precision: date").count(), 1, "{}", board);
+
+    sandbox.run_ok(&["edit", &day, "--due", "2030.05.02@09:00"]);
+    assert!(sandbox
+        .run_ok(&["show", &day])
+        .contains("due: 2030.05.02@09:00"));
+    assert!(!String::from_utf8(sandbox.board_bytes())
+        .unwrap()
+        .contains("due_precision"));
+
+    let config = sandbox.dir.join("config/postit");
+    fs::create_dir_all(&config).unwrap();
+    fs::write(config.join("config.yml"), "default_due_time: \"17:00\"\n").unwrap();
+    sandbox.add(&["after lunch", "--due", "2030.06.01"]);
+    let board = String::from_utf8(sandbox.board_bytes()).unwrap();
+    assert!(board.contains("2030-06-01T17:00:00Z"), "{}", board);
+}